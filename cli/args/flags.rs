@@ -196,17 +196,144 @@ pub struct UninstallFlags {
   pub root: Option<String>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LintFixFlags {
+  pub enabled: bool,
+  pub fail_on_nonconvergence: bool,
+  pub only: Vec<String>,
+  /// Print the changes of `--fix` as a diff rather than writing them.
+  pub dry_run: bool,
+  /// Number of times `--fix` applies the fixes again after the first pass.
+  pub max_iterations: Option<usize>,
+  /// Fix only the problems whose fixes keep the runtime behavior, then
+  /// report the remaining ones.
+  pub safe_then_report: bool,
+  /// Print how many problems `--fix` would fix per rule, without fixing.
+  pub preview: bool,
+  pub interactive: bool,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LintReporterFlags {
+  pub json: bool,
+  pub json_workspace: bool,
+  pub ndjson: bool,
+  pub checkstyle: bool,
+  pub tap: bool,
+  pub by_rule: bool,
+  pub compact: bool,
+  pub protobuf: bool,
+  /// File to write the `--protobuf` output to rather than stdout.
+  pub output: Option<String>,
+  pub absolute: bool,
+  pub relative: bool,
+  /// Print the end position of the problems in compact output.
+  pub compact_ranges: bool,
+  pub limit: Option<usize>,
+  pub group_by_rule: bool,
+  pub no_snippets: bool,
+  pub no_hints: bool,
+  pub snippet_underline: Option<char>,
+  pub unicode_frame: bool,
+  pub hyperlinks: bool,
+  pub compact_after: Option<usize>,
+  /// Print the number of problems of each rule after the summary.
+  pub stats: bool,
+  pub json_sort: Option<String>,
+  pub enclosing_symbol: bool,
+  pub one_per_file: bool,
+  pub blame: bool,
+  pub db_output: Option<String>,
+  pub svg_dir: Option<String>,
+  pub sidecar: bool,
+  pub file_results: Option<String>,
+  pub file_problem_threshold: Option<usize>,
+  pub verbose: bool,
+  /// Print the time spent on each rule and the slowest files.
+  pub timing: bool,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LintCacheFlags {
+  pub namespace: Option<String>,
+  pub path: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LintFilterFlags {
+  /// Unified diff whose added and changed lines are the only ones linted.
+  pub patch: Option<String>,
+  /// How many directories deep to lint below each include root.
+  pub max_depth: Option<usize>,
+  pub hide_tags: Vec<String>,
+  pub no_tests: bool,
+  pub tests_only: bool,
+  pub generated: Vec<String>,
+  pub skip_generated: bool,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct LintFlags {
   pub files: FileFlags,
+  /// Source code linted instead of files.
+  pub eval: Option<String>,
+  /// Extension that determines the dialect of stdin or `--eval`.
+  pub stdin_ext: Option<String>,
+  /// Paths or globs linted in addition to the files of the config file or
+  /// the positional arguments.
+  pub include_extra: Vec<String>,
+  pub filter: LintFilterFlags,
   pub rules: bool,
-  pub fix: bool,
+  /// Lint with only this rule.
+  pub only: Option<String>,
+  /// JSON file with a shareable rule configuration.
+  pub rule_pack: Option<String>,
+  /// Print the documentation and an example fix of this rule.
+  pub explain: Option<String>,
+  /// Print the hash of the compiled in rules, or check it's the given one.
+  pub rules_hash: Option<Option<String>>,
+  pub print_json_schema: bool,
+  pub fix: LintFixFlags,
   pub maybe_rules_tags: Option<Vec<String>>,
   pub maybe_rules_include: Option<Vec<String>>,
   pub maybe_rules_exclude: Option<Vec<String>>,
-  pub json: bool,
-  pub compact: bool,
+  pub strict_rules: bool,
+  pub reporter: LintReporterFlags,
+  pub compare: Option<String>,
+  pub baseline: Option<String>,
+  /// Write the problems of the run to the `--baseline` file.
+  pub update_baseline: bool,
+  pub success_marker: Option<String>,
+  pub skip_unreadable: bool,
+  pub require_coverage: bool,
+  pub normalize_eol: bool,
+  pub metrics: Option<String>,
+  pub print_config: bool,
+  pub print_file_patterns: bool,
+  pub edit: bool,
+  pub timeout_per_file: Option<u64>,
+  pub warn_changed_files: bool,
+  pub fail_on_regressions: bool,
+  /// Maximum number of files linted at once.
+  pub concurrency: Option<NonZeroUsize>,
+  pub max_warnings: Option<usize>,
+  pub no_error_on_issues: bool,
+  pub max_warnings_delta: Option<usize>,
+  /// Record the number of problems of the run as the `--max-warnings-delta`
+  /// baseline even when it grew.
+  pub update_problem_count: bool,
+  pub cache: LintCacheFlags,
   pub watch: Option<WatchFlags>,
+  pub watch_no_fail: bool,
+  pub inbox: Option<String>,
+  /// Directory the linted files of `--inbox` are moved to.
+  pub inbox_done: Option<String>,
+  /// Delete the linted files of `--inbox`.
+  pub inbox_delete: bool,
+  /// Lint the files whose paths are read from stdin, one per line, rather
+  /// than collecting them.
+  pub stdin_file_list: bool,
+  pub unstable_md: bool,
 }
 
 impl LintFlags {
@@ -849,6 +976,9 @@ static ENV_VARIABLES_HELP: &str = color_print::cstr!(
     <g>DENO_INSTALL_ROOT</>    Set deno install's output directory
                          (defaults to $HOME/.deno/bin)
 
+    <g>DENO_LINT_REPORTER</>   Set the reporter of deno lint, which otherwise
+                         depends on the CI system
+
     <g>DENO_REPL_HISTORY</>    Set REPL history file path
                          History file is disabled when the value is empty
                          (defaults to $DENO_DIR/deno_history.txt)
@@ -1988,6 +2118,23 @@ Read from stdin:
   cat file.ts | deno lint -
   cat file.ts | deno lint --json -
 
+Fix the problems that can be fixed, or print the fixes as a diff:
+
+  deno lint --fix
+  deno lint --fix --dry-run
+
+Report only the problems that aren't in a baseline:
+
+  deno lint --baseline=.lint-baseline.json
+
+Report only the problems on the lines that a diff changes:
+
+  deno lint --patch=changes.diff
+
+Pick the reporter rather than the one of the CI system:
+
+  DENO_LINT_REPORTER=pretty deno lint
+
 List available rules:
 
   deno lint --rules
@@ -2003,6 +2150,14 @@ Names of rules to ignore must be specified after ignore comment.
 Ignore linting a file by adding an ignore comment at the top of the file:
 
   // deno-lint-ignore-file
+
+Adjust the rules used for a single file with a config block at the top of
+the file:
+
+  /* deno-lint-config { \"exclude\": [\"no-explicit-any\"] } */
+
+The other options are described in cli/tools/lint/README.md in the Deno
+repository.
 ",
     )
     .defer(|cmd| {
@@ -2013,12 +2168,87 @@ Ignore linting a file by adding an ignore comment at the top of the file:
             .help("Fix any linting errors for rules that support it")
             .action(ArgAction::SetTrue),
         )
+        .arg(
+          Arg::new("fail-on-fix-nonconvergence")
+            .long("fail-on-fix-nonconvergence")
+            .help("Fail when the fixes of a file still leave fixable problems after the maximum number of --fix iterations")
+            .action(ArgAction::SetTrue)
+            .requires("fix"),
+        )
+        .arg(
+          Arg::new("fix-only")
+            .long("fix-only")
+            .require_equals(true)
+            .num_args(1..)
+            .use_value_delimiter(true)
+            .value_name("RULES")
+            .help("Only fix the problems of these rules with --fix, leaving the other ones to be fixed manually")
+            .requires("fix"),
+        )
+        .arg(
+          Arg::new("dry-run")
+            .long("dry-run")
+            .help("Print the changes of --fix as a unified diff without writing them, failing when any file would change")
+            .action(ArgAction::SetTrue)
+            .requires("fix"),
+        )
+        .arg(
+          Arg::new("fix-max-iterations")
+            .long("fix-max-iterations")
+            .require_equals(true)
+            .value_name("N")
+            .value_parser(value_parser!(usize))
+            .help("Apply the fixes of a file up to N more times after the first pass, as fixes may overlap [default: 5]")
+            .requires("fix"),
+        )
+        .arg(
+          Arg::new("auto-fix-safe-then-report")
+            .long("auto-fix-safe-then-report")
+            .help("Fix only the problems whose fixes keep the runtime behavior, then report the remaining ones")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("fix-preview")
+            .long("fix-preview")
+            .help("Print how many problems of each rule --fix would fix, without fixing them")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all([
+              "fix",
+              "auto-fix-safe-then-report",
+              "json",
+              "compact",
+            ]),
+        )
         .arg(
           Arg::new("rules")
             .long("rules")
             .help("List available rules")
             .action(ArgAction::SetTrue),
         )
+        .arg(
+          Arg::new("explain")
+            .long("explain")
+            .require_equals(true)
+            .value_name("RULE")
+            .help("Explain a rule and show what --fix does to an example of its problems")
+            .conflicts_with("rules"),
+        )
+        .arg(
+          Arg::new("rules-hash")
+            .long("rules-hash")
+            .num_args(0..=1)
+            .require_equals(true)
+            .value_name("HASH")
+            .help("Print a hash of the codes and tags of all the rules, or fail unless it's the given one")
+            .conflicts_with_all(["rules", "explain"]),
+        )
+        .arg(
+          Arg::new("print-json-schema")
+            .long("print-json-schema")
+            .help("Print the JSON Schema of the --json output")
+            .action(ArgAction::SetTrue)
+            .hide(true),
+        )
         .arg(
           Arg::new("rules-tags")
             .long("rules-tags")
@@ -2035,7 +2265,7 @@ Ignore linting a file by adding an ignore comment at the top of the file:
             .num_args(1..)
             .use_value_delimiter(true)
             .conflicts_with("rules")
-            .help("Include lint rules"),
+            .help("Include lint rules, in addition to the ones included by the config file"),
         )
         .arg(
           Arg::new("rules-exclude")
@@ -2044,7 +2274,39 @@ Ignore linting a file by adding an ignore comment at the top of the file:
             .num_args(1..)
             .use_value_delimiter(true)
             .conflicts_with("rules")
-            .help("Exclude lint rules"),
+            .help("Exclude lint rules, in addition to the ones excluded by the config file"),
+        )
+        .arg(
+          Arg::new("strict-rules")
+            .long("strict-rules")
+            .help("Fail when the included or excluded rules have one that doesn't exist, rather than warning")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("only")
+            .long("only")
+            .require_equals(true)
+            .value_name("RULE")
+            .help("Lint with only this rule and without the incremental cache, for quickly iterating on a rule")
+            .conflicts_with_all(["rules", "rules-tags", "rules-include", "rules-exclude"]),
+        )
+        .arg(
+          Arg::new("rule-pack")
+            .long("rule-pack")
+            .require_equals(true)
+            .value_name("PATH")
+            .help("Load the tags, included and excluded rules, and rule severities from a JSON rule pack, which the flags override and which overrides the config file")
+            .conflicts_with("only")
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("patch")
+            .long("patch")
+            .require_equals(true)
+            .value_name("FILE")
+            .help("Lint only the files of a unified diff, reporting only the problems on the lines it adds or changes")
+            .conflicts_with_all(["fix", "interactive", "watch"])
+            .value_hint(ValueHint::FilePath),
         )
         .arg(no_config_arg())
         .arg(config_arg())
@@ -2057,3091 +2319,5201 @@ Ignore linting a file by adding an ignore comment at the top of the file:
             .help("Ignore linting particular source files")
             .value_hint(ValueHint::AnyPath),
         )
+        .arg(
+          Arg::new("eval")
+            .long("eval")
+            .require_equals(true)
+            .value_name("CODE")
+            .help("Lint the provided source code instead of files")
+            .conflicts_with_all(["files", "watch"]),
+        )
+        .arg(
+          Arg::new("stdin-ext")
+            .long("stdin-ext")
+            // the name of the flag of the other subcommands
+            .alias("ext")
+            .require_equals(true)
+            .value_parser(["ts", "tsx", "js", "jsx", "mts", "mjs", "cts", "cjs"])
+            .help("Set the dialect of the code from stdin or --eval, defaults to 'ts'"),
+        )
+        .arg(
+          Arg::new("include-extra")
+            .long("include-extra")
+            .num_args(1..)
+            .use_value_delimiter(true)
+            .require_equals(true)
+            .help("Lint these files in addition to the included ones")
+            .value_hint(ValueHint::AnyPath),
+        )
+        .arg(
+          Arg::new("no-tests")
+            .long("no-tests")
+            .help("Don't lint test files, which are named test.*, *_test.*, *.test.* or *.spec.*")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("tests-only"),
+        )
+        .arg(
+          Arg::new("tests-only")
+            .long("tests-only")
+            .help("Only lint test files, which are named test.*, *_test.*, *.test.* or *.spec.*")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("max-depth")
+            .long("max-depth")
+            .require_equals(true)
+            .value_name("DEPTH")
+            .value_parser(value_parser!(usize))
+            .help("Only lint files up to this many directories deep, 0 to lint only the files directly in each included directory"),
+        )
         .arg(
           Arg::new("json")
             .long("json")
             .help("Output lint result in JSON format")
             .action(ArgAction::SetTrue),
         )
+        .arg(
+          Arg::new("json-workspace")
+            .long("json-workspace")
+            .help("Output lint result in JSON format, grouped by workspace member")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all([
+              "json",
+              "compact",
+              "limit",
+              "group-by",
+              "no-snippets",
+              "no-hints",
+              "compact-after",
+              "edit",
+              "fix-preview",
+              "interactive",
+            ]),
+        )
+        .arg(
+          Arg::new("ndjson")
+            .long("ndjson")
+            .help("Output lint result as JSON Lines, printing each problem as soon as it's found")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["json", "json-workspace"]),
+        )
+        .arg(
+          Arg::new("checkstyle")
+            .long("checkstyle")
+            .help("Output lint result as a Checkstyle XML report")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["json", "json-workspace", "ndjson", "compact"]),
+        )
+        .arg(
+          Arg::new("tap")
+            .long("tap")
+            .help("Output lint result as a TAP version 13 report")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all([
+              "json",
+              "json-workspace",
+              "ndjson",
+              "checkstyle",
+              "compact",
+            ]),
+        )
+        .arg(
+          Arg::new("by-rule")
+            .long("by-rule")
+            .help("Output the locations of the problems grouped by rule, the rules with the most problems first")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all([
+              "json",
+              "json-workspace",
+              "ndjson",
+              "checkstyle",
+              "tap",
+              "compact",
+            ]),
+        )
         .arg(
           Arg::new("compact")
             .long("compact")
-            .help("Output lint result in compact format")
+            .help("Output lint result in compact format, with 1-indexed lines and columns")
             .action(ArgAction::SetTrue)
             .conflicts_with("json"),
         )
         .arg(
-          Arg::new("files")
-            .num_args(1..)
-            .action(ArgAction::Append)
-            .required(false)
-            .value_hint(ValueHint::AnyPath),
+          Arg::new("compact-ranges")
+            .long("compact-ranges")
+            .help("Print the end position of the problems in compact output, like line 3, col 5-10")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("json"),
         )
-        .arg(watch_arg(false))
-        .arg(no_clear_screen_arg())
-    })
-}
-
-fn repl_subcommand() -> Command {
-  Command::new("repl")
-    .about("Read Eval Print Loop")
-    .defer(|cmd| runtime_args(cmd, true, true)
-      .arg(check_arg(false))
-      .arg(
-        Arg::new("eval-file")
-          .long("eval-file")
-          .num_args(1..)
-          .use_value_delimiter(true)
-          .require_equals(true)
-          .help("Evaluates the provided file(s) as scripts when the REPL starts. Accepts file paths and URLs.")
-          .value_hint(ValueHint::AnyPath),
-      )
-      .arg(
-        Arg::new("eval")
-          .long("eval")
-          .help("Evaluates the provided code when the REPL starts.")
-          .value_name("code"),
-      ))
-      .arg(env_file_arg())
-}
-
-fn run_subcommand() -> Command {
-  runtime_args(Command::new("run"), true, true)
-    .arg(check_arg(false))
-    .arg(watch_arg(true))
-    .arg(hmr_arg(true))
-    .arg(no_clear_screen_arg())
-    .arg(executable_ext_arg())
-    .arg(
-      script_arg()
-        .required_unless_present("v8-flags")
-        .trailing_var_arg(true),
-    )
-    .arg(env_file_arg())
-    .about("Run a JavaScript or TypeScript program")
-    .long_about(
-      "Run a JavaScript or TypeScript program
-
-By default all programs are run in sandbox without access to disk, network or
-ability to spawn subprocesses.
-
-  deno run https://examples.deno.land/hello-world.ts
-
-Grant all permissions:
-
-  deno run -A https://deno.land/std/http/file_server.ts
-
-Grant permission to read from disk and listen to network:
-
-  deno run --allow-read --allow-net https://deno.land/std/http/file_server.ts
-
-Grant permission to read allow-listed files from disk:
-
-  deno run --allow-read=/etc https://deno.land/std/http/file_server.ts
-
-Specifying the filename '-' to read the file from stdin.
-
-  curl https://examples.deno.land/hello-world.ts | deno run -",
-    )
-}
-
-fn task_subcommand() -> Command {
-  Command::new("task")
-    .about("Run a task defined in the configuration file")
-    .long_about(
-      "Run a task defined in the configuration file
-
-  deno task build",
-    )
-    .defer(|cmd| {
-      cmd
-        .allow_external_subcommands(true)
-        .subcommand_value_name("TASK")
-        .arg(config_arg())
         .arg(
-          Arg::new("cwd")
-            .long("cwd")
-            .value_name("DIR")
-            .help("Specify the directory to run the task in")
-            .value_hint(ValueHint::DirPath),
-        )
-    })
-}
-
-fn test_subcommand() -> Command {
-  Command::new("test")
-    .about("Run tests")
-    .long_about(
-      "Run tests using Deno's built-in test runner.
-
-Evaluate the given modules, run all tests declared with 'Deno.test()' and
-report results to standard output:
-
-  deno test src/fetch_test.ts src/signal_test.ts
-
-Directory arguments are expanded to all contained files matching the glob
-{*_,*.,}test.{js,mjs,ts,mts,jsx,tsx}:
-
-  deno test src/",
-    )
-  .defer(|cmd| runtime_args(cmd, true, true)
-    .arg(check_arg(true))
-    .arg(
-      Arg::new("ignore")
-        .long("ignore")
-        .num_args(1..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .help("Ignore files")
-        .value_hint(ValueHint::AnyPath),
-    )
-    .arg(
-      Arg::new("no-run")
-        .long("no-run")
-        .help("Cache test modules, but don't run tests")
-        .action(ArgAction::SetTrue),
-    )
-    .arg(
-      Arg::new("trace-ops")
-        .long("trace-ops")
-        .help("Deprecated alias for --trace-leaks.")
-        .hide(true)
-        .action(ArgAction::SetTrue),
-    )
-    .arg(
-      Arg::new("trace-leaks")
-        .long("trace-leaks")
-        .help("Enable tracing of leaks. Useful when debugging leaking ops in test, but impacts test execution time.")
-        .action(ArgAction::SetTrue),
-    )
-    .arg(
-      Arg::new("doc")
-        .long("doc")
-        .help("Type-check code blocks in JSDoc and Markdown")
-        .action(ArgAction::SetTrue),
-    )
-    .arg(
-      Arg::new("fail-fast")
-        .long("fail-fast")
-        .alias("failfast")
-        .help("Stop after N errors. Defaults to stopping after first failure.")
-        .num_args(0..=1)
-        .require_equals(true)
-        .value_name("N")
-        .value_parser(value_parser!(NonZeroUsize)),
-    )
-    .arg(
-      Arg::new("allow-none")
-        .long("allow-none")
-        .help("Don't return error code if no test files are found")
-        .action(ArgAction::SetTrue),
-    )
-    .arg(
-      Arg::new("filter")
-        .allow_hyphen_values(true)
-        .long("filter")
-        .help("Run tests with this string or pattern in the test name"),
-    )
-    .arg(
-      Arg::new("shuffle")
-        .long("shuffle")
-        .value_name("NUMBER")
-        .help("Shuffle the order in which the tests are run")
-        .num_args(0..=1)
-        .require_equals(true)
-        .value_parser(value_parser!(u64)),
-    )
-    .arg(
-      Arg::new("coverage")
-        .long("coverage")
-        .value_name("DIR")
-        .num_args(0..=1)
-        .require_equals(true)
-        .default_missing_value("coverage")
-        .conflicts_with("inspect")
-        .conflicts_with("inspect-wait")
-        .conflicts_with("inspect-brk")
-        .help("Collect coverage profile data into DIR. If DIR is not specified, it uses 'coverage/'."),
-    )
-    .arg(
-      Arg::new("parallel")
-        .long("parallel")
-        .help("Run test modules in parallel. Parallelism defaults to the number of available CPUs or the value in the DENO_JOBS environment variable.")
-        .conflicts_with("jobs")
-        .action(ArgAction::SetTrue)
-    )
-    .arg(
-      Arg::new("jobs")
-        .short('j')
-        .long("jobs")
-        .help("deprecated: The `--jobs` flag is deprecated and will be removed in Deno 2.0. Use the `--parallel` flag with possibly the `DENO_JOBS` environment variable instead.")
-        .hide(true)
-        .num_args(0..=1)
-        .value_parser(value_parser!(NonZeroUsize)),
-    )
-    .arg(
-      Arg::new("files")
-        .help("List of file names to run")
-        .num_args(0..)
-        .action(ArgAction::Append)
-        .value_hint(ValueHint::AnyPath),
-    )
-    .arg(
-      watch_arg(false)
-        .conflicts_with("no-run")
-        .conflicts_with("coverage"),
-    )
-    .arg(no_clear_screen_arg())
-    .arg(script_arg().last(true))
-    .arg(
-      Arg::new("junit-path")
-        .long("junit-path")
-        .value_name("PATH")
-        .value_hint(ValueHint::FilePath)
-        .help("Write a JUnit XML test report to PATH. Use '-' to write to stdout which is the default when PATH is not provided.")
-    )
-    .arg(
-      Arg::new("reporter")
-        .long("reporter")
-        .help("Select reporter to use. Default to 'pretty'.")
-        .value_parser(["pretty", "dot", "junit", "tap"])
-    )
-    .arg(env_file_arg())
-  )
-}
-
-fn types_subcommand() -> Command {
-  Command::new("types")
-    .about("Print runtime TypeScript declarations")
-    .long_about(
-      "Print runtime TypeScript declarations.
-
-  deno types > lib.deno.d.ts
-
-The declaration file could be saved and used for typing information.",
-    )
-}
-
-fn upgrade_subcommand() -> Command {
-  Command::new("upgrade")
-    .about("Upgrade deno executable to given version")
-    .long_about(
-      "Upgrade deno executable to the given version.
-Defaults to latest.
-
-The version is downloaded from
-https://github.com/denoland/deno/releases
-and is used to replace the current executable.
-
-If you want to not replace the current Deno executable but instead download an
-update to a different location, use the --output flag
-
-  deno upgrade --output $HOME/my_deno",
-    )
-    .hide(cfg!(not(feature = "upgrade")))
-    .defer(|cmd| {
-      cmd
-        .arg(
-          Arg::new("version")
-            .long("version")
-            .help("The version to upgrade to"),
+          Arg::new("protobuf")
+            .long("protobuf")
+            .help("Output lint result as length-delimited Protocol Buffers messages, see cli/schemas/lint-output.v1.proto")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all([
+              "json",
+              "json-workspace",
+              "ndjson",
+              "checkstyle",
+              "tap",
+              "by-rule",
+              "compact",
+              "compact-ranges",
+              "fix-preview",
+              "limit",
+              "group-by",
+              "no-snippets",
+              "no-hints",
+              "compact-after",
+            ]),
         )
         .arg(
           Arg::new("output")
             .long("output")
-            .help("The path to output the updated version to")
-            .value_parser(value_parser!(String))
+            .require_equals(true)
+            .value_name("PATH")
+            .help("Write the --protobuf output to this file rather than stdout")
+            .requires("protobuf")
             .value_hint(ValueHint::FilePath),
         )
         .arg(
-          Arg::new("dry-run")
-            .long("dry-run")
-            .help("Perform all checks without replacing old exe")
+          Arg::new("absolute")
+            .long("absolute")
+            .help("Print absolute file paths instead of file URLs in compact and JSON output")
             .action(ArgAction::SetTrue),
         )
         .arg(
-          Arg::new("force")
-            .long("force")
-            .short('f')
-            .help("Replace current exe even if not out-of-date")
+          Arg::new("limit")
+            .long("limit")
+            .require_equals(true)
+            .value_parser(value_parser!(usize))
+            .conflicts_with_all(["json", "compact"])
+            .help("Maximum number of problems to display, 0 to display all"),
+        )
+        .arg(
+          Arg::new("format")
+            .long("format")
+            .alias("relative")
+            .num_args(0..=1)
+            .value_parser(["relative-paths"])
+            .default_missing_value("relative-paths")
+            .require_equals(true)
+            .help("Display file paths relative to the current directory. --relative is short for --format=relative-paths")
+            .conflicts_with("absolute"),
+        )
+        .arg(
+          Arg::new("group-by")
+            .long("group-by")
+            .require_equals(true)
+            .value_parser(["rule"])
+            .help("Display the problems grouped by rule, the rules with the most problems first")
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("no-snippets")
+            .long("no-snippets")
+            .help("Don't display the source code of the problems")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("no-hints")
+            .long("no-hints")
+            .help("Don't display the hints of the problems")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("snippet-underline")
+            .long("snippet-underline")
+            .require_equals(true)
+            .value_name("CHAR")
+            .value_parser(value_parser!(char))
+            .help("Underline the problems in the source code with this character rather than ^")
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("snippet-frame")
+            .long("snippet-frame")
+            .require_equals(true)
+            .value_parser(["ascii", "unicode"])
+            .help("Draw the frame around the source code of the problems with ASCII (default) or Unicode box-drawing characters")
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("hyperlinks")
+            .long("hyperlinks")
+            .help("Make the location of each problem a link that opens the file at its line, in terminals that support them")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("compact-after")
+            .long("compact-after")
+            .require_equals(true)
+            .value_name("N")
+            .value_parser(value_parser!(usize))
+            .help("Display the problems after the first N as compact one-liners rather than with their source code")
+            .conflicts_with_all(["json", "compact"]),
+        )
+        .arg(
+          Arg::new("stats")
+            .long("stats")
+            .help("Print the number of problems of each rule after the summary, most frequent first")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all([
+              "json",
+              "json-workspace",
+              "ndjson",
+              "checkstyle",
+              "tap",
+              "by-rule",
+              "compact",
+              "protobuf",
+              "fix-preview",
+            ]),
+        )
+        .arg(
+          Arg::new("db-output")
+            .long("db-output")
+            .require_equals(true)
+            .value_name("PATH")
+            .help("Record the diagnostics of this run in a SQLite database")
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("svg-dir")
+            .long("svg-dir")
+            .require_equals(true)
+            .value_name("DIR")
+            .help("Render the code frame of each diagnostic as an SVG file in this directory, named by the diagnostic's fingerprint")
+            .value_hint(ValueHint::DirPath),
+        )
+        .arg(
+          Arg::new("skip-unreadable")
+            .long("skip-unreadable")
+            .help("Warn about the files that can't be read, like because of their permissions, rather than failing")
             .action(ArgAction::SetTrue),
         )
         .arg(
-          Arg::new("canary")
-            .long("canary")
-            .help("Upgrade to canary builds")
+          Arg::new("require-coverage")
+            .long("require-coverage")
+            .help("Fail when a script file under the config file's directory isn't selected by the include patterns, unless an exclude pattern skips it")
             .action(ArgAction::SetTrue),
         )
-        .arg(ca_file_arg())
-    })
-}
-
-fn vendor_subcommand() -> Command {
-  Command::new("vendor")
-      .about("Vendor remote modules into a local directory")
-      .long_about(
-        "Vendor remote modules into a local directory.
-
-Analyzes the provided modules along with their dependencies, downloads
-remote modules to the output directory, and produces an import map that
-maps remote specifiers to the downloaded files.
-
-  deno vendor main.ts
-  deno run --import-map vendor/import_map.json main.ts
-
-Remote modules and multiple modules may also be specified:
-
-  deno vendor main.ts test.deps.ts https://deno.land/std/path/mod.ts",
-      )
-    .defer(|cmd| cmd
-      .arg(
-        Arg::new("specifiers")
-          .num_args(1..)
-          .action(ArgAction::Append)
-          .required(true),
-      )
-      .arg(
-        Arg::new("output")
-          .long("output")
-          .help("The directory to output the vendored modules to")
-          .value_parser(value_parser!(String))
-          .value_hint(ValueHint::DirPath),
-      )
-      .arg(
-        Arg::new("force")
-          .long("force")
-          .short('f')
-          .help(
-            "Forcefully overwrite conflicting files in existing output directory",
-          )
-          .action(ArgAction::SetTrue),
-      )
-      .arg(no_config_arg())
-      .arg(config_arg())
-      .arg(import_map_arg())
-      .arg(lock_arg())
-      .arg(node_modules_dir_arg())
-      .arg(vendor_arg())
-      .arg(reload_arg())
-      .arg(ca_file_arg()))
-}
-
-fn publish_subcommand() -> Command {
-  Command::new("publish")
-    .hide(true)
-    .about("Unstable preview feature: Publish the current working directory's package or workspace")
-    // TODO: .long_about()
-    .defer(|cmd| {
-      cmd.arg(
-        Arg::new("token")
-          .long("token")
-          .help("The API token to use when publishing. If unset, interactive authentication is be used")
-      )
-      .arg(config_arg())
-      .arg(no_config_arg())
-      .arg(
-        Arg::new("dry-run")
-          .long("dry-run")
-          .help("Prepare the package for publishing performing all checks and validations without uploading")
-          .action(ArgAction::SetTrue),
-      )
+        .arg(
+          Arg::new("normalize-eol")
+            .long("normalize-eol")
+            .help("Convert CRLF and CR line endings to LF before linting, which --fix writes back to the files")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("success-marker")
+            .long("success-marker")
+            .require_equals(true)
+            .value_name("PATH")
+            .help("Write a file with the time of the run when it passes and remove it when it fails, for build system caches")
+            .value_hint(ValueHint::FilePath)
+            .conflicts_with("watch"),
+        )
+        .arg(
+          Arg::new("compare")
+            .long("compare")
+            .require_equals(true)
+            .value_name("FILE")
+            .help("Print the problems that are new or resolved since the run whose --json output is in FILE, failing only when there are new ones")
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("baseline")
+            .long("baseline")
+            .require_equals(true)
+            .value_name("FILE")
+            .help("Don't report the problems in the baseline FILE, so that only new problems fail the run")
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("update-baseline")
+            .long("update-baseline")
+            .help("Write the problems of the run to the --baseline file instead of reporting them")
+            .action(ArgAction::SetTrue)
+            .requires("baseline")
+            .conflicts_with("fix"),
+        )
+        .arg(
+          Arg::new("metrics")
+            .long("metrics")
+            .require_equals(true)
+            .value_name("URL")
+            .help("Push the problem counts and duration of this run to a StatsD (statsd://host:port) or Prometheus pushgateway (http://...) url")
+            .value_hint(ValueHint::Url),
+        )
+        .arg(
+          Arg::new("print-config")
+            .long("print-config")
+            .help("Print the effective lint rule configuration before linting")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("print-file-patterns")
+            .long("print-file-patterns")
+            .help("Print the resolved base directory, include and exclude patterns the files to lint are collected with, then exit")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("watch"),
+        )
+        .arg(
+          Arg::new("hide-tags")
+            .long("hide-tags")
+            .require_equals(true)
+            .num_args(1..)
+            .use_value_delimiter(true)
+            .help("Don't report the problems of rules with a tag, or of no-banned-imports when it's given, while still running them"),
+        )
+        .arg(
+          Arg::new("edit")
+            .long("edit")
+            .help("Open the first problem in $EDITOR after linting")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("json"),
+        )
+        .arg(
+          Arg::new("timeout-per-file")
+            .long("timeout-per-file")
+            .require_equals(true)
+            .value_name("SECONDS")
+            .value_parser(value_parser!(u64).range(1..))
+            .help("Skip a file with a warning and fail when linting it takes longer than this"),
+        )
+        .arg(
+          Arg::new("warn-changed-files")
+            .long("warn-changed-files")
+            .help("Warn about files that were modified while being linted")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("fail-on-regressions")
+            .long("fail-on-regressions")
+            .help("Fail with a distinct error when files that had no problems in a previous run now have problems")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("verbose")
+            .long("verbose")
+            .help("Print additional information, like the number of enabled rules")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("timing")
+            .long("timing")
+            .help("Print the time spent on each rule and the slowest files to stderr")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("concurrency")
+            .long("concurrency")
+            .require_equals(true)
+            .value_name("N")
+            .value_parser(value_parser!(NonZeroUsize))
+            .help("Lint at most N files at once [default: number of CPUs]"),
+        )
+        .arg(
+          Arg::new("max-warnings")
+            .long("max-warnings")
+            .require_equals(true)
+            .value_name("N")
+            .value_parser(value_parser!(usize))
+            .help("Fail only when there are more than N problems")
+            .conflicts_with("compare"),
+        )
+        .arg(
+          Arg::new("no-error-on-issues")
+            .long("no-error-on-issues")
+            .help("Exit with a zero exit code even when there are problems, which are still reported")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("max-warnings-delta")
+            .long("max-warnings-delta")
+            .require_equals(true)
+            .value_name("N")
+            .value_parser(value_parser!(usize))
+            .help("Fail when there are more than N problems more than recorded by a previous run"),
+        )
+        .arg(
+          Arg::new("update-problem-count")
+            .long("update-problem-count")
+            .help("Record the number of problems of this run for --max-warnings-delta, even when it grew")
+            .action(ArgAction::SetTrue)
+            .requires("max-warnings-delta"),
+        )
+        .arg(
+          Arg::new("sidecar")
+            .long("sidecar")
+            .help("Write the problems of each file in JSON format to a <file>.lint.json next to it")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("file-results")
+            .long("file-results")
+            .require_equals(true)
+            .value_name("FILE")
+            .help("Write whether each checked file passed, and its number of problems, in JSON format to a file")
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("cache-namespace")
+            .long("cache-namespace")
+            .require_equals(true)
+            .value_name("KEY")
+            .help("Only reuse the cached results of runs with the same key, like when sharing a cache between branches"),
+        )
+        .arg(
+          Arg::new("cache-path")
+            .long("cache-path")
+            .require_equals(true)
+            .value_name("FILE")
+            .help("Keep the cached results in a database at this path rather than in the Deno directory")
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("enclosing-symbol")
+            .long("enclosing-symbol")
+            .help("Report the function, class or method each problem is in")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("compact"),
+        )
+        .arg(
+          Arg::new("one-per-file")
+            .long("one-per-file")
+            .help("Report only the first problem of each file, noting how many more it has")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("blame")
+            .long("blame")
+            .help("Group the problems by the author of the last commit that changed their line, using git blame")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("file-problem-threshold")
+            .long("file-problem-threshold")
+            .require_equals(true)
+            .value_name("N")
+            .value_parser(value_parser!(usize))
+            .help("Point out the files with more than N problems after linting, as candidates for refactoring")
+            .conflicts_with("one-per-file"),
+        )
+        .arg(
+          Arg::new("interactive")
+            .long("interactive")
+            .help("Ask whether to apply the fix of each fixable problem, then report the remaining problems")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["fix", "fix-preview", "json", "eval", "watch"]),
+        )
+        .arg(
+          Arg::new("generated")
+            .long("generated")
+            .num_args(1..)
+            .use_value_delimiter(true)
+            .require_equals(true)
+            .help("Lint the matching generated files with a minimal rule set")
+            .value_hint(ValueHint::AnyPath),
+        )
+        .arg(
+          Arg::new("skip-generated")
+            .long("skip-generated")
+            .help("Skip the files matched by --generated rather than linting them")
+            .action(ArgAction::SetTrue)
+            .requires("generated"),
+        )
+        .arg(
+          Arg::new("json-sort")
+            .long("json-sort")
+            .require_equals(true)
+            .value_parser(["position", "code", "severity"])
+            .help("Order of the problems in the JSON output, by file and position unless the rule code or severity is preferred")
+            .conflicts_with("compact"),
+        )
+        .arg(
+          Arg::new("files")
+            .num_args(1..)
+            .action(ArgAction::Append)
+            .required(false)
+            .value_hint(ValueHint::AnyPath),
+        )
+        .arg(watch_arg(false))
+        .arg(no_clear_screen_arg())
+        .arg(
+          Arg::new("watch-no-fail")
+            .requires("watch")
+            .long("watch-no-fail")
+            .help("Only print errors of a watch iteration without marking it as failed")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("inbox")
+            .long("inbox")
+            .require_equals(true)
+            .value_name("DIR")
+            .help("Watch a directory and lint each script file that's created in it once")
+            .value_hint(ValueHint::DirPath)
+            .conflicts_with_all(["files", "eval", "watch", "fix"]),
+        )
+        .arg(
+          Arg::new("inbox-done")
+            .long("inbox-done")
+            .require_equals(true)
+            .value_name("DIR")
+            .help("Move the linted files of --inbox to a directory")
+            .value_hint(ValueHint::DirPath)
+            .requires("inbox"),
+        )
+        .arg(
+          Arg::new("inbox-delete")
+            .long("inbox-delete")
+            .help("Delete the linted files of --inbox")
+            .action(ArgAction::SetTrue)
+            .requires("inbox")
+            .conflicts_with("inbox-done"),
+        )
+        .arg(
+          Arg::new("stdin-file-list")
+            .long("stdin-file-list")
+            .help("Lint the files whose paths are read from stdin, one per line, rather than collecting them")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["files", "eval", "watch", "inbox", "interactive"]),
+        )
+        .arg(
+          Arg::new("unstable-md")
+            .long("unstable-md")
+            .help("Lint the fenced TypeScript and JavaScript code blocks of Markdown files")
+            .action(ArgAction::SetTrue),
+        )
+    })
+}
+
+fn repl_subcommand() -> Command {
+  Command::new("repl")
+    .about("Read Eval Print Loop")
+    .defer(|cmd| runtime_args(cmd, true, true)
+      .arg(check_arg(false))
       .arg(
-        Arg::new("allow-slow-types")
-          .long("allow-slow-types")
-          .help("Allow publishing with slow types")
-          .action(ArgAction::SetTrue),
+        Arg::new("eval-file")
+          .long("eval-file")
+          .num_args(1..)
+          .use_value_delimiter(true)
+          .require_equals(true)
+          .help("Evaluates the provided file(s) as scripts when the REPL starts. Accepts file paths and URLs.")
+          .value_hint(ValueHint::AnyPath),
       )
       .arg(
-        Arg::new("allow-dirty")
-        .long("allow-dirty")
-        .help("Allow publishing if the repository has uncommitted changed")
-        .action(ArgAction::SetTrue),
-      ).arg(
-        Arg::new("no-provenance")
-          .long("no-provenance")
-          .help("Disable provenance attestation. Enabled by default on Github actions, publicly links the package to where it was built and published from.")
-          .action(ArgAction::SetTrue)
-      )
-      .arg(check_arg(/* type checks by default */ true))
-      .arg(no_check_arg())
-    })
+        Arg::new("eval")
+          .long("eval")
+          .help("Evaluates the provided code when the REPL starts.")
+          .value_name("code"),
+      ))
+      .arg(env_file_arg())
 }
 
-fn compile_args(app: Command) -> Command {
-  compile_args_without_check_args(app.arg(no_check_arg()))
-}
+fn run_subcommand() -> Command {
+  runtime_args(Command::new("run"), true, true)
+    .arg(check_arg(false))
+    .arg(watch_arg(true))
+    .arg(hmr_arg(true))
+    .arg(no_clear_screen_arg())
+    .arg(executable_ext_arg())
+    .arg(
+      script_arg()
+        .required_unless_present("v8-flags")
+        .trailing_var_arg(true),
+    )
+    .arg(env_file_arg())
+    .about("Run a JavaScript or TypeScript program")
+    .long_about(
+      "Run a JavaScript or TypeScript program
 
-fn compile_args_without_check_args(app: Command) -> Command {
-  app
-    .arg(import_map_arg())
-    .arg(no_remote_arg())
-    .arg(no_npm_arg())
-    .arg(node_modules_dir_arg())
-    .arg(vendor_arg())
-    .arg(config_arg())
-    .arg(no_config_arg())
-    .arg(reload_arg())
-    .arg(lock_arg())
-    .arg(lock_write_arg())
-    .arg(no_lock_arg())
-    .arg(ca_file_arg())
-}
+By default all programs are run in sandbox without access to disk, network or
+ability to spawn subprocesses.
 
-static ALLOW_READ_HELP: &str = concat!(
-  "Allow file system read access. Optionally specify allowed paths.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-read\n",
-  "  --allow-read=\"/etc,/var/log.txt\""
-);
+  deno run https://examples.deno.land/hello-world.ts
 
-static DENY_READ_HELP: &str = concat!(
-  "Deny file system read access. Optionally specify denied paths.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-read\n",
-  "  --deny-read=\"/etc,/var/log.txt\""
-);
+Grant all permissions:
 
-static ALLOW_WRITE_HELP: &str = concat!(
-  "Allow file system write access. Optionally specify allowed paths.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-write\n",
-  "  --allow-write=\"/etc,/var/log.txt\""
-);
+  deno run -A https://deno.land/std/http/file_server.ts
 
-static DENY_WRITE_HELP: &str = concat!(
-  "Deny file system write access. Optionally specify denied paths.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-write\n",
-  "  --deny-write=\"/etc,/var/log.txt\""
-);
-
-static ALLOW_NET_HELP: &str = concat!(
-  "Allow network access. Optionally specify allowed IP addresses and host names, with ports as necessary.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-net\n",
-  "  --allow-net=\"localhost:8080,deno.land\""
-);
-
-static DENY_NET_HELP: &str = concat!(
-  "Deny network access. Optionally specify denied IP addresses and host names, with ports as necessary.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-net\n",
-  "  --deny-net=\"localhost:8080,deno.land\""
-);
+Grant permission to read from disk and listen to network:
 
-static ALLOW_ENV_HELP: &str = concat!(
-  "Allow access to system environment information. Optionally specify accessible environment variables.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-env\n",
-  "  --allow-env=\"PORT,HOME,PATH\""
-);
+  deno run --allow-read --allow-net https://deno.land/std/http/file_server.ts
 
-static DENY_ENV_HELP: &str = concat!(
-  "Deny access to system environment information. Optionally specify accessible environment variables.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-env\n",
-  "  --deny-env=\"PORT,HOME,PATH\""
-);
+Grant permission to read allow-listed files from disk:
 
-static ALLOW_SYS_HELP: &str = concat!(
-  "Allow access to OS information. Optionally allow specific APIs by function name.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-sys\n",
-  "  --allow-sys=\"systemMemoryInfo,osRelease\""
-);
+  deno run --allow-read=/etc https://deno.land/std/http/file_server.ts
 
-static DENY_SYS_HELP: &str = concat!(
-  "Deny access to OS information. Optionally deny specific APIs by function name.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-sys\n",
-  "  --deny-sys=\"systemMemoryInfo,osRelease\""
-);
+Specifying the filename '-' to read the file from stdin.
 
-static ALLOW_RUN_HELP: &str = concat!(
-  "Allow running subprocesses. Optionally specify allowed runnable program names.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-run\n",
-  "  --allow-run=\"whoami,ps\""
-);
+  curl https://examples.deno.land/hello-world.ts | deno run -",
+    )
+}
 
-static DENY_RUN_HELP: &str = concat!(
-  "Deny running subprocesses. Optionally specify denied runnable program names.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-run\n",
-  "  --deny-run=\"whoami,ps\""
-);
+fn task_subcommand() -> Command {
+  Command::new("task")
+    .about("Run a task defined in the configuration file")
+    .long_about(
+      "Run a task defined in the configuration file
 
-static ALLOW_FFI_HELP: &str = concat!(
-  "(Unstable) Allow loading dynamic libraries. Optionally specify allowed directories or files.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --allow-ffi\n",
-  "  --allow-ffi=\"./libfoo.so\""
-);
+  deno task build",
+    )
+    .defer(|cmd| {
+      cmd
+        .allow_external_subcommands(true)
+        .subcommand_value_name("TASK")
+        .arg(config_arg())
+        .arg(
+          Arg::new("cwd")
+            .long("cwd")
+            .value_name("DIR")
+            .help("Specify the directory to run the task in")
+            .value_hint(ValueHint::DirPath),
+        )
+    })
+}
 
-static DENY_FFI_HELP: &str = concat!(
-  "(Unstable) Deny loading dynamic libraries. Optionally specify denied directories or files.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n",
-  "Examples:\n",
-  "  --deny-ffi\n",
-  "  --deny-ffi=\"./libfoo.so\""
-);
+fn test_subcommand() -> Command {
+  Command::new("test")
+    .about("Run tests")
+    .long_about(
+      "Run tests using Deno's built-in test runner.
 
-static ALLOW_HRTIME_HELP: &str = concat!(
-  "Allow high-resolution time measurement. Note: this can enable timing attacks and fingerprinting.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n"
-);
+Evaluate the given modules, run all tests declared with 'Deno.test()' and
+report results to standard output:
 
-static DENY_HRTIME_HELP: &str = concat!(
-  "Deny high-resolution time measurement. Note: this can prevent timing attacks and fingerprinting.\n",
-  "Docs: https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n"
-);
+  deno test src/fetch_test.ts src/signal_test.ts
 
-static ALLOW_ALL_HELP: &str = concat!(
-  "Allow all permissions. Learn more about permissions in Deno:\n",
-  "https://deno.land/manual@v",
-  env!("CARGO_PKG_VERSION"),
-  "/basics/permissions\n"
-);
+Directory arguments are expanded to all contained files matching the glob
+{*_,*.,}test.{js,mjs,ts,mts,jsx,tsx}:
 
-fn permission_args(app: Command) -> Command {
-  app
-    .arg(
-      Arg::new("allow-read")
-        .long("allow-read")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("PATH")
-        .help(ALLOW_READ_HELP)
-        .value_parser(value_parser!(String))
-        .value_hint(ValueHint::AnyPath),
+  deno test src/",
     )
+  .defer(|cmd| runtime_args(cmd, true, true)
+    .arg(check_arg(true))
     .arg(
-      Arg::new("deny-read")
-        .long("deny-read")
-        .num_args(0..)
+      Arg::new("ignore")
+        .long("ignore")
+        .num_args(1..)
         .use_value_delimiter(true)
         .require_equals(true)
-        .value_name("PATH")
-        .help(DENY_READ_HELP)
-        .value_parser(value_parser!(String))
+        .help("Ignore files")
         .value_hint(ValueHint::AnyPath),
     )
     .arg(
-      Arg::new("allow-write")
-        .long("allow-write")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("PATH")
-        .help(ALLOW_WRITE_HELP)
-        .value_parser(value_parser!(String))
-        .value_hint(ValueHint::AnyPath),
+      Arg::new("no-run")
+        .long("no-run")
+        .help("Cache test modules, but don't run tests")
+        .action(ArgAction::SetTrue),
     )
     .arg(
-      Arg::new("deny-write")
-        .long("deny-write")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("PATH")
-        .help(DENY_WRITE_HELP)
-        .value_parser(value_parser!(String))
-        .value_hint(ValueHint::AnyPath),
+      Arg::new("trace-ops")
+        .long("trace-ops")
+        .help("Deprecated alias for --trace-leaks.")
+        .hide(true)
+        .action(ArgAction::SetTrue),
     )
     .arg(
-      Arg::new("allow-net")
-        .long("allow-net")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("IP_OR_HOSTNAME")
-        .help(ALLOW_NET_HELP)
-        .value_parser(flags_net::validator),
+      Arg::new("trace-leaks")
+        .long("trace-leaks")
+        .help("Enable tracing of leaks. Useful when debugging leaking ops in test, but impacts test execution time.")
+        .action(ArgAction::SetTrue),
     )
     .arg(
-      Arg::new("deny-net")
-        .long("deny-net")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("IP_OR_HOSTNAME")
-        .help(DENY_NET_HELP)
-        .value_parser(flags_net::validator),
+      Arg::new("doc")
+        .long("doc")
+        .help("Type-check code blocks in JSDoc and Markdown")
+        .action(ArgAction::SetTrue),
     )
-    .arg(unsafely_ignore_certificate_errors_arg())
     .arg(
-      Arg::new("allow-env")
-        .long("allow-env")
-        .num_args(0..)
-        .use_value_delimiter(true)
+      Arg::new("fail-fast")
+        .long("fail-fast")
+        .alias("failfast")
+        .help("Stop after N errors. Defaults to stopping after first failure.")
+        .num_args(0..=1)
         .require_equals(true)
-        .value_name("VARIABLE_NAME")
-        .help(ALLOW_ENV_HELP)
-        .value_parser(|key: &str| {
-          if key.is_empty() || key.contains(&['=', '\0'] as &[char]) {
-            return Err(format!("invalid key \"{key}\""));
-          }
-
-          Ok(if cfg!(windows) {
-            key.to_uppercase()
-          } else {
-            key.to_string()
-          })
-        }),
+        .value_name("N")
+        .value_parser(value_parser!(NonZeroUsize)),
     )
     .arg(
-      Arg::new("deny-env")
-        .long("deny-env")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("VARIABLE_NAME")
-        .help(DENY_ENV_HELP)
-        .value_parser(|key: &str| {
-          if key.is_empty() || key.contains(&['=', '\0'] as &[char]) {
-            return Err(format!("invalid key \"{key}\""));
-          }
-
-          Ok(if cfg!(windows) {
-            key.to_uppercase()
-          } else {
-            key.to_string()
-          })
-        }),
+      Arg::new("allow-none")
+        .long("allow-none")
+        .help("Don't return error code if no test files are found")
+        .action(ArgAction::SetTrue),
     )
     .arg(
-      Arg::new("allow-sys")
-        .long("allow-sys")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("API_NAME")
-        .help(ALLOW_SYS_HELP)
-        .value_parser(|key: &str| parse_sys_kind(key).map(ToString::to_string)),
+      Arg::new("filter")
+        .allow_hyphen_values(true)
+        .long("filter")
+        .help("Run tests with this string or pattern in the test name"),
     )
     .arg(
-      Arg::new("deny-sys")
-        .long("deny-sys")
-        .num_args(0..)
-        .use_value_delimiter(true)
+      Arg::new("shuffle")
+        .long("shuffle")
+        .value_name("NUMBER")
+        .help("Shuffle the order in which the tests are run")
+        .num_args(0..=1)
         .require_equals(true)
-        .value_name("API_NAME")
-        .help(DENY_SYS_HELP)
-        .value_parser(|key: &str| parse_sys_kind(key).map(ToString::to_string)),
+        .value_parser(value_parser!(u64)),
     )
     .arg(
-      Arg::new("allow-run")
-        .long("allow-run")
-        .num_args(0..)
-        .use_value_delimiter(true)
+      Arg::new("coverage")
+        .long("coverage")
+        .value_name("DIR")
+        .num_args(0..=1)
         .require_equals(true)
-        .value_name("PROGRAM_NAME")
-        .help(ALLOW_RUN_HELP),
+        .default_missing_value("coverage")
+        .conflicts_with("inspect")
+        .conflicts_with("inspect-wait")
+        .conflicts_with("inspect-brk")
+        .help("Collect coverage profile data into DIR. If DIR is not specified, it uses 'coverage/'."),
     )
     .arg(
-      Arg::new("deny-run")
-        .long("deny-run")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("PROGRAM_NAME")
-        .help(DENY_RUN_HELP),
+      Arg::new("parallel")
+        .long("parallel")
+        .help("Run test modules in parallel. Parallelism defaults to the number of available CPUs or the value in the DENO_JOBS environment variable.")
+        .conflicts_with("jobs")
+        .action(ArgAction::SetTrue)
     )
     .arg(
-      Arg::new("allow-ffi")
-        .long("allow-ffi")
-        .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("PATH")
-        .help(ALLOW_FFI_HELP)
-        .value_parser(value_parser!(String))
-        .value_hint(ValueHint::AnyPath),
+      Arg::new("jobs")
+        .short('j')
+        .long("jobs")
+        .help("deprecated: The `--jobs` flag is deprecated and will be removed in Deno 2.0. Use the `--parallel` flag with possibly the `DENO_JOBS` environment variable instead.")
+        .hide(true)
+        .num_args(0..=1)
+        .value_parser(value_parser!(NonZeroUsize)),
     )
     .arg(
-      Arg::new("deny-ffi")
-        .long("deny-ffi")
+      Arg::new("files")
+        .help("List of file names to run")
         .num_args(0..)
-        .use_value_delimiter(true)
-        .require_equals(true)
-        .value_name("PATH")
-        .help(DENY_FFI_HELP)
-        .value_parser(value_parser!(String))
+        .action(ArgAction::Append)
         .value_hint(ValueHint::AnyPath),
     )
     .arg(
-      Arg::new("allow-hrtime")
-        .long("allow-hrtime")
-        .action(ArgAction::SetTrue)
-        .help(ALLOW_HRTIME_HELP),
-    )
-    .arg(
-      Arg::new("deny-hrtime")
-        .long("deny-hrtime")
-        .action(ArgAction::SetTrue)
-        .help(DENY_HRTIME_HELP),
+      watch_arg(false)
+        .conflicts_with("no-run")
+        .conflicts_with("coverage"),
     )
+    .arg(no_clear_screen_arg())
+    .arg(script_arg().last(true))
     .arg(
-      Arg::new("allow-all")
-        .short('A')
-        .long("allow-all")
-        .action(ArgAction::SetTrue)
-        .help(ALLOW_ALL_HELP),
+      Arg::new("junit-path")
+        .long("junit-path")
+        .value_name("PATH")
+        .value_hint(ValueHint::FilePath)
+        .help("Write a JUnit XML test report to PATH. Use '-' to write to stdout which is the default when PATH is not provided.")
     )
     .arg(
-      Arg::new("no-prompt")
-        .long("no-prompt")
-        .action(ArgAction::SetTrue)
-        .help("Always throw if required permission wasn't passed"),
+      Arg::new("reporter")
+        .long("reporter")
+        .help("Select reporter to use. Default to 'pretty'.")
+        .value_parser(["pretty", "dot", "junit", "tap"])
     )
+    .arg(env_file_arg())
+  )
 }
 
-fn runtime_args(
-  app: Command,
-  include_perms: bool,
-  include_inspector: bool,
-) -> Command {
-  let app = compile_args(app);
-  let app = if include_perms {
-    permission_args(app)
-  } else {
-    app
-  };
-  let app = if include_inspector {
-    inspect_args(app)
-  } else {
-    app
-  };
-  app
-    .arg(cached_only_arg())
-    .arg(location_arg())
-    .arg(v8_flags_arg())
-    .arg(seed_arg())
-    .arg(enable_testing_features_arg())
-    .arg(strace_ops_arg())
-}
+fn types_subcommand() -> Command {
+  Command::new("types")
+    .about("Print runtime TypeScript declarations")
+    .long_about(
+      "Print runtime TypeScript declarations.
 
-fn inspect_args(app: Command) -> Command {
-  app
-    .arg(
-      Arg::new("inspect")
-        .long("inspect")
-        .value_name("HOST_AND_PORT")
-        .help("Activate inspector on host:port (default: 127.0.0.1:9229)")
-        .num_args(0..=1)
-        .require_equals(true)
-        .value_parser(value_parser!(SocketAddr)),
-    )
-    .arg(
-      Arg::new("inspect-brk")
-        .long("inspect-brk")
-        .value_name("HOST_AND_PORT")
-        .help(
-          "Activate inspector on host:port, wait for debugger to connect and break at the start of user script",
-        )
-        .num_args(0..=1)
-        .require_equals(true)
-        .value_parser(value_parser!(SocketAddr)),
-    )
-    .arg(
-      Arg::new("inspect-wait")
-        .long("inspect-wait")
-        .value_name("HOST_AND_PORT")
-        .help(
-          "Activate inspector on host:port and wait for debugger to connect before running user code",
-        )
-        .num_args(0..=1)
-        .require_equals(true)
-        .value_parser(value_parser!(SocketAddr)),
+  deno types > lib.deno.d.ts
+
+The declaration file could be saved and used for typing information.",
     )
 }
 
-static IMPORT_MAP_HELP: &str = concat!(
-  "Load import map file from local file or remote URL.
-Docs: https://docs.deno.com/runtime/manual/basics/import_maps
-Specification: https://wicg.github.io/import-maps/
-Examples: https://github.com/WICG/import-maps#the-import-map",
-);
+fn upgrade_subcommand() -> Command {
+  Command::new("upgrade")
+    .about("Upgrade deno executable to given version")
+    .long_about(
+      "Upgrade deno executable to the given version.
+Defaults to latest.
 
-fn import_map_arg() -> Arg {
-  Arg::new("import-map")
-    .long("import-map")
-    .alias("importmap")
-    .value_name("FILE")
-    .help("Load import map file")
-    .long_help(IMPORT_MAP_HELP)
-    .value_hint(ValueHint::FilePath)
-}
+The version is downloaded from
+https://github.com/denoland/deno/releases
+and is used to replace the current executable.
 
-fn env_file_arg() -> Arg {
-  Arg::new("env")
-    .long("env")
-    .value_name("FILE")
-    .help("Load .env file")
-    .long_help("UNSTABLE: Load environment variables from local file. Only the first environment variable with a given key is used. Existing process environment variables are not overwritten.")
-    .value_hint(ValueHint::FilePath)
-    .default_missing_value(".env")
-    .require_equals(true)
-    .num_args(0..=1)
-}
+If you want to not replace the current Deno executable but instead download an
+update to a different location, use the --output flag
 
-fn reload_arg() -> Arg {
-  Arg::new("reload")
-    .short('r')
-    .num_args(0..)
-    .use_value_delimiter(true)
-    .require_equals(true)
-    .long("reload")
-    .help("Reload source code cache (recompile TypeScript)")
-    .value_name("CACHE_BLOCKLIST")
-    .long_help(
-      "Reload source code cache (recompile TypeScript)
---reload
-  Reload everything
---reload=https://deno.land/std
-  Reload only standard modules
---reload=https://deno.land/std/fs/utils.ts,https://deno.land/std/fmt/colors.ts
-  Reloads specific modules
---reload=npm:
-  Reload all npm modules
---reload=npm:chalk
-  Reload specific npm module",
+  deno upgrade --output $HOME/my_deno",
     )
-    .value_hint(ValueHint::FilePath)
-    .value_parser(reload_arg_validate)
+    .hide(cfg!(not(feature = "upgrade")))
+    .defer(|cmd| {
+      cmd
+        .arg(
+          Arg::new("version")
+            .long("version")
+            .help("The version to upgrade to"),
+        )
+        .arg(
+          Arg::new("output")
+            .long("output")
+            .help("The path to output the updated version to")
+            .value_parser(value_parser!(String))
+            .value_hint(ValueHint::FilePath),
+        )
+        .arg(
+          Arg::new("dry-run")
+            .long("dry-run")
+            .help("Perform all checks without replacing old exe")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("force")
+            .long("force")
+            .short('f')
+            .help("Replace current exe even if not out-of-date")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("canary")
+            .long("canary")
+            .help("Upgrade to canary builds")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(ca_file_arg())
+    })
 }
 
-fn ca_file_arg() -> Arg {
-  Arg::new("cert")
-    .long("cert")
-    .value_name("FILE")
-    .help("Load certificate authority from PEM encoded file")
-    .value_hint(ValueHint::FilePath)
-}
+fn vendor_subcommand() -> Command {
+  Command::new("vendor")
+      .about("Vendor remote modules into a local directory")
+      .long_about(
+        "Vendor remote modules into a local directory.
 
-fn cached_only_arg() -> Arg {
-  Arg::new("cached-only")
-    .long("cached-only")
-    .action(ArgAction::SetTrue)
-    .help("Require that remote dependencies are already cached")
-}
+Analyzes the provided modules along with their dependencies, downloads
+remote modules to the output directory, and produces an import map that
+maps remote specifiers to the downloaded files.
 
-/// Used for subcommands that operate on executable scripts only.
-/// `deno fmt` has its own `--ext` arg because its possible values differ.
-/// If --ext is not provided and the script doesn't have a file extension,
-/// deno_graph::parse_module() defaults to js.
-fn executable_ext_arg() -> Arg {
-  Arg::new("ext")
-    .long("ext")
-    .help("Set content type of the supplied file")
-    .value_parser(["ts", "tsx", "js", "jsx"])
-}
+  deno vendor main.ts
+  deno run --import-map vendor/import_map.json main.ts
 
-fn location_arg() -> Arg {
-  Arg::new("location")
-    .long("location")
-    .value_name("HREF")
-    .value_parser(|href: &str| -> Result<Url, String> {
-      let url = Url::parse(href);
-      if url.is_err() {
-        return Err("Failed to parse URL".to_string());
-      }
-      let mut url = url.unwrap();
-      if !["http", "https"].contains(&url.scheme()) {
-        return Err("Expected protocol \"http\" or \"https\"".to_string());
-      }
-      url.set_username("").unwrap();
-      url.set_password(None).unwrap();
-      Ok(url)
-    })
-    .help("Value of 'globalThis.location' used by some web APIs")
-    .value_hint(ValueHint::Url)
-}
+Remote modules and multiple modules may also be specified:
 
-fn enable_testing_features_arg() -> Arg {
-  Arg::new("enable-testing-features-do-not-use")
-    .long("enable-testing-features-do-not-use")
-    .help("INTERNAL: Enable internal features used during integration testing")
-    .action(ArgAction::SetTrue)
-    .hide(true)
+  deno vendor main.ts test.deps.ts https://deno.land/std/path/mod.ts",
+      )
+    .defer(|cmd| cmd
+      .arg(
+        Arg::new("specifiers")
+          .num_args(1..)
+          .action(ArgAction::Append)
+          .required(true),
+      )
+      .arg(
+        Arg::new("output")
+          .long("output")
+          .help("The directory to output the vendored modules to")
+          .value_parser(value_parser!(String))
+          .value_hint(ValueHint::DirPath),
+      )
+      .arg(
+        Arg::new("force")
+          .long("force")
+          .short('f')
+          .help(
+            "Forcefully overwrite conflicting files in existing output directory",
+          )
+          .action(ArgAction::SetTrue),
+      )
+      .arg(no_config_arg())
+      .arg(config_arg())
+      .arg(import_map_arg())
+      .arg(lock_arg())
+      .arg(node_modules_dir_arg())
+      .arg(vendor_arg())
+      .arg(reload_arg())
+      .arg(ca_file_arg()))
 }
 
-fn strace_ops_arg() -> Arg {
-  Arg::new("strace-ops")
-    .long("strace-ops")
-    .num_args(0..)
-    .use_value_delimiter(true)
-    .require_equals(true)
-    .value_name("OPS")
-    .help("Trace low-level op calls")
+fn publish_subcommand() -> Command {
+  Command::new("publish")
     .hide(true)
+    .about("Unstable preview feature: Publish the current working directory's package or workspace")
+    // TODO: .long_about()
+    .defer(|cmd| {
+      cmd.arg(
+        Arg::new("token")
+          .long("token")
+          .help("The API token to use when publishing. If unset, interactive authentication is be used")
+      )
+      .arg(config_arg())
+      .arg(no_config_arg())
+      .arg(
+        Arg::new("dry-run")
+          .long("dry-run")
+          .help("Prepare the package for publishing performing all checks and validations without uploading")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("allow-slow-types")
+          .long("allow-slow-types")
+          .help("Allow publishing with slow types")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("allow-dirty")
+        .long("allow-dirty")
+        .help("Allow publishing if the repository has uncommitted changed")
+        .action(ArgAction::SetTrue),
+      ).arg(
+        Arg::new("no-provenance")
+          .long("no-provenance")
+          .help("Disable provenance attestation. Enabled by default on Github actions, publicly links the package to where it was built and published from.")
+          .action(ArgAction::SetTrue)
+      )
+      .arg(check_arg(/* type checks by default */ true))
+      .arg(no_check_arg())
+    })
 }
 
-fn v8_flags_arg() -> Arg {
-  Arg::new("v8-flags")
-    .long("v8-flags")
-    .num_args(..)
-    .use_value_delimiter(true)
-    .require_equals(true)
-    .help("Set V8 command line options")
-    .long_help("To see a list of all available flags use --v8-flags=--help.
-    Any flags set with this flag are appended after the DENO_V8_FLAGS environmental variable")
+fn compile_args(app: Command) -> Command {
+  compile_args_without_check_args(app.arg(no_check_arg()))
 }
 
-fn seed_arg() -> Arg {
-  Arg::new("seed")
-    .long("seed")
-    .value_name("NUMBER")
-    .help("Set the random number generator seed")
-    .value_parser(value_parser!(u64))
+fn compile_args_without_check_args(app: Command) -> Command {
+  app
+    .arg(import_map_arg())
+    .arg(no_remote_arg())
+    .arg(no_npm_arg())
+    .arg(node_modules_dir_arg())
+    .arg(vendor_arg())
+    .arg(config_arg())
+    .arg(no_config_arg())
+    .arg(reload_arg())
+    .arg(lock_arg())
+    .arg(lock_write_arg())
+    .arg(no_lock_arg())
+    .arg(ca_file_arg())
 }
 
-fn hmr_arg(takes_files: bool) -> Arg {
-  let arg = Arg::new("hmr")
-    .long("unstable-hmr")
-    .help("UNSTABLE: Watch for file changes and hot replace modules")
-    .conflicts_with("watch");
+static ALLOW_READ_HELP: &str = concat!(
+  "Allow file system read access. Optionally specify allowed paths.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-read\n",
+  "  --allow-read=\"/etc,/var/log.txt\""
+);
 
-  if takes_files {
-    arg
-      .value_name("FILES")
-      .num_args(0..)
-      .value_parser(value_parser!(String))
-      .use_value_delimiter(true)
-      .require_equals(true)
-      .long_help(
-        "Watch for file changes and restart process automatically.
-Local files from entry point module graph are watched by default.
-Additional paths might be watched by passing them as arguments to this flag.",
-      )
-      .value_hint(ValueHint::AnyPath)
-  } else {
-    arg.action(ArgAction::SetTrue).long_help(
-      "Watch for file changes and restart process automatically.
-      Only local files from entry point module graph are watched.",
-    )
-  }
-}
+static DENY_READ_HELP: &str = concat!(
+  "Deny file system read access. Optionally specify denied paths.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-read\n",
+  "  --deny-read=\"/etc,/var/log.txt\""
+);
 
-fn watch_arg(takes_files: bool) -> Arg {
-  let arg = Arg::new("watch")
-    .long("watch")
-    .help("Watch for file changes and restart automatically");
-
-  if takes_files {
-    arg
-      .value_name("FILES")
-      .num_args(0..)
-      .value_parser(value_parser!(String))
-      .use_value_delimiter(true)
-      .require_equals(true)
-      .long_help(
-        "Watch for file changes and restart process automatically.
-Local files from entry point module graph are watched by default.
-Additional paths might be watched by passing them as arguments to this flag.",
-      )
-      .value_hint(ValueHint::AnyPath)
-  } else {
-    arg.action(ArgAction::SetTrue).long_help(
-      "Watch for file changes and restart process automatically.
-      Only local files from entry point module graph are watched.",
-    )
-  }
-}
+static ALLOW_WRITE_HELP: &str = concat!(
+  "Allow file system write access. Optionally specify allowed paths.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-write\n",
+  "  --allow-write=\"/etc,/var/log.txt\""
+);
 
-fn no_clear_screen_arg() -> Arg {
-  Arg::new("no-clear-screen")
-    .requires("watch")
-    .long("no-clear-screen")
-    .action(ArgAction::SetTrue)
-    .help("Do not clear terminal screen when under watch mode")
-}
+static DENY_WRITE_HELP: &str = concat!(
+  "Deny file system write access. Optionally specify denied paths.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-write\n",
+  "  --deny-write=\"/etc,/var/log.txt\""
+);
 
-fn no_check_arg() -> Arg {
-  Arg::new("no-check")
-    .num_args(0..=1)
-    .require_equals(true)
-    .value_name("NO_CHECK_TYPE")
-    .long("no-check")
-    .help("Skip type-checking modules")
-    .long_help(
-      "Skip type-checking. If the value of '--no-check=remote' is supplied,
-diagnostic errors from remote modules will be ignored.",
-    )
-}
+static ALLOW_NET_HELP: &str = concat!(
+  "Allow network access. Optionally specify allowed IP addresses and host names, with ports as necessary.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-net\n",
+  "  --allow-net=\"localhost:8080,deno.land\""
+);
 
-fn check_arg(checks_local_by_default: bool) -> Arg {
-  let arg = Arg::new("check")
-    .conflicts_with("no-check")
-    .long("check")
-    .num_args(0..=1)
-    .require_equals(true)
-    .value_name("CHECK_TYPE")
-    .help("Type-check modules");
+static DENY_NET_HELP: &str = concat!(
+  "Deny network access. Optionally specify denied IP addresses and host names, with ports as necessary.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-net\n",
+  "  --deny-net=\"localhost:8080,deno.land\""
+);
 
-  if checks_local_by_default {
-    arg.long_help(
-      "Set type-checking behavior. This subcommand type-checks local modules by
-default, so adding --check is redundant.
-If the value of '--check=all' is supplied, diagnostic errors from remote modules
-will be included.
+static ALLOW_ENV_HELP: &str = concat!(
+  "Allow access to system environment information. Optionally specify accessible environment variables.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-env\n",
+  "  --allow-env=\"PORT,HOME,PATH\""
+);
 
-Alternatively, the 'deno check' subcommand can be used.",
-    )
-  } else {
-    arg.long_help(
-      "Enable type-checking. This subcommand does not type-check by default.
-If the value of '--check=all' is supplied, diagnostic errors from remote modules
-will be included.
+static DENY_ENV_HELP: &str = concat!(
+  "Deny access to system environment information. Optionally specify accessible environment variables.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-env\n",
+  "  --deny-env=\"PORT,HOME,PATH\""
+);
 
-Alternatively, the 'deno check' subcommand can be used.",
-    )
-  }
-}
+static ALLOW_SYS_HELP: &str = concat!(
+  "Allow access to OS information. Optionally allow specific APIs by function name.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-sys\n",
+  "  --allow-sys=\"systemMemoryInfo,osRelease\""
+);
 
-fn script_arg() -> Arg {
-  Arg::new("script_arg")
-    .num_args(0..)
-    .action(ArgAction::Append)
-    // NOTE: these defaults are provided
-    // so `deno run --v8-flags=--help` works
-    // without specifying file to run.
-    .default_value_ifs([
-      ("v8-flags", "--help", Some("_")),
-      ("v8-flags", "-help", Some("_")),
-    ])
-    .help("Script arg")
-    .value_name("SCRIPT_ARG")
-    .value_hint(ValueHint::FilePath)
-}
+static DENY_SYS_HELP: &str = concat!(
+  "Deny access to OS information. Optionally deny specific APIs by function name.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-sys\n",
+  "  --deny-sys=\"systemMemoryInfo,osRelease\""
+);
 
-fn lock_arg() -> Arg {
-  Arg::new("lock")
-    .long("lock")
-    .value_name("FILE")
-    .help("Check the specified lock file.
+static ALLOW_RUN_HELP: &str = concat!(
+  "Allow running subprocesses. Optionally specify allowed runnable program names.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-run\n",
+  "  --allow-run=\"whoami,ps\""
+);
 
-If value is not provided, defaults to \"deno.lock\" in the current working directory.")
-    .num_args(0..=1)
-    .value_parser(value_parser!(String))
-    .value_hint(ValueHint::FilePath)
-}
+static DENY_RUN_HELP: &str = concat!(
+  "Deny running subprocesses. Optionally specify denied runnable program names.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-run\n",
+  "  --deny-run=\"whoami,ps\""
+);
 
-fn lock_write_arg() -> Arg {
-  Arg::new("lock-write")
-    .action(ArgAction::SetTrue)
-    .long("lock-write")
-    .help("Force overwriting the lock file.")
-    .conflicts_with("no-lock")
-}
+static ALLOW_FFI_HELP: &str = concat!(
+  "(Unstable) Allow loading dynamic libraries. Optionally specify allowed directories or files.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --allow-ffi\n",
+  "  --allow-ffi=\"./libfoo.so\""
+);
 
-fn no_lock_arg() -> Arg {
-  Arg::new("no-lock")
-    .long("no-lock")
-    .action(ArgAction::SetTrue)
-    .help("Disable auto discovery of the lock file.")
-    .conflicts_with("lock")
-}
+static DENY_FFI_HELP: &str = concat!(
+  "(Unstable) Deny loading dynamic libraries. Optionally specify denied directories or files.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n",
+  "Examples:\n",
+  "  --deny-ffi\n",
+  "  --deny-ffi=\"./libfoo.so\""
+);
 
-static CONFIG_HELP: &str = concat!(
-  "The configuration file can be used to configure different aspects of
-deno including TypeScript, linting, and code formatting. Typically the
-configuration file will be called `deno.json` or `deno.jsonc` and
-automatically detected; in that case this flag is not necessary.
-See https://deno.land/manual@v",
+static ALLOW_HRTIME_HELP: &str = concat!(
+  "Allow high-resolution time measurement. Note: this can enable timing attacks and fingerprinting.\n",
+  "Docs: https://deno.land/manual@v",
   env!("CARGO_PKG_VERSION"),
-  "/getting_started/configuration_file"
+  "/basics/permissions\n"
 );
 
-fn config_arg() -> Arg {
-  Arg::new("config")
-    .short('c')
-    .long("config")
-    .value_name("FILE")
-    .help("Specify the configuration file")
-    .long_help(CONFIG_HELP)
-    .value_hint(ValueHint::FilePath)
-}
+static DENY_HRTIME_HELP: &str = concat!(
+  "Deny high-resolution time measurement. Note: this can prevent timing attacks and fingerprinting.\n",
+  "Docs: https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n"
+);
 
-fn no_config_arg() -> Arg {
-  Arg::new("no-config")
-    .long("no-config")
-    .action(ArgAction::SetTrue)
-    .help("Disable automatic loading of the configuration file.")
-    .conflicts_with("config")
-}
+static ALLOW_ALL_HELP: &str = concat!(
+  "Allow all permissions. Learn more about permissions in Deno:\n",
+  "https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/basics/permissions\n"
+);
 
-fn no_remote_arg() -> Arg {
-  Arg::new("no-remote")
-    .long("no-remote")
-    .action(ArgAction::SetTrue)
+fn permission_args(app: Command) -> Command {
+  app
+    .arg(
+      Arg::new("allow-read")
+        .long("allow-read")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PATH")
+        .help(ALLOW_READ_HELP)
+        .value_parser(value_parser!(String))
+        .value_hint(ValueHint::AnyPath),
+    )
+    .arg(
+      Arg::new("deny-read")
+        .long("deny-read")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PATH")
+        .help(DENY_READ_HELP)
+        .value_parser(value_parser!(String))
+        .value_hint(ValueHint::AnyPath),
+    )
+    .arg(
+      Arg::new("allow-write")
+        .long("allow-write")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PATH")
+        .help(ALLOW_WRITE_HELP)
+        .value_parser(value_parser!(String))
+        .value_hint(ValueHint::AnyPath),
+    )
+    .arg(
+      Arg::new("deny-write")
+        .long("deny-write")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PATH")
+        .help(DENY_WRITE_HELP)
+        .value_parser(value_parser!(String))
+        .value_hint(ValueHint::AnyPath),
+    )
+    .arg(
+      Arg::new("allow-net")
+        .long("allow-net")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("IP_OR_HOSTNAME")
+        .help(ALLOW_NET_HELP)
+        .value_parser(flags_net::validator),
+    )
+    .arg(
+      Arg::new("deny-net")
+        .long("deny-net")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("IP_OR_HOSTNAME")
+        .help(DENY_NET_HELP)
+        .value_parser(flags_net::validator),
+    )
+    .arg(unsafely_ignore_certificate_errors_arg())
+    .arg(
+      Arg::new("allow-env")
+        .long("allow-env")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("VARIABLE_NAME")
+        .help(ALLOW_ENV_HELP)
+        .value_parser(|key: &str| {
+          if key.is_empty() || key.contains(&['=', '\0'] as &[char]) {
+            return Err(format!("invalid key \"{key}\""));
+          }
+
+          Ok(if cfg!(windows) {
+            key.to_uppercase()
+          } else {
+            key.to_string()
+          })
+        }),
+    )
+    .arg(
+      Arg::new("deny-env")
+        .long("deny-env")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("VARIABLE_NAME")
+        .help(DENY_ENV_HELP)
+        .value_parser(|key: &str| {
+          if key.is_empty() || key.contains(&['=', '\0'] as &[char]) {
+            return Err(format!("invalid key \"{key}\""));
+          }
+
+          Ok(if cfg!(windows) {
+            key.to_uppercase()
+          } else {
+            key.to_string()
+          })
+        }),
+    )
+    .arg(
+      Arg::new("allow-sys")
+        .long("allow-sys")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("API_NAME")
+        .help(ALLOW_SYS_HELP)
+        .value_parser(|key: &str| parse_sys_kind(key).map(ToString::to_string)),
+    )
+    .arg(
+      Arg::new("deny-sys")
+        .long("deny-sys")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("API_NAME")
+        .help(DENY_SYS_HELP)
+        .value_parser(|key: &str| parse_sys_kind(key).map(ToString::to_string)),
+    )
+    .arg(
+      Arg::new("allow-run")
+        .long("allow-run")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PROGRAM_NAME")
+        .help(ALLOW_RUN_HELP),
+    )
+    .arg(
+      Arg::new("deny-run")
+        .long("deny-run")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PROGRAM_NAME")
+        .help(DENY_RUN_HELP),
+    )
+    .arg(
+      Arg::new("allow-ffi")
+        .long("allow-ffi")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PATH")
+        .help(ALLOW_FFI_HELP)
+        .value_parser(value_parser!(String))
+        .value_hint(ValueHint::AnyPath),
+    )
+    .arg(
+      Arg::new("deny-ffi")
+        .long("deny-ffi")
+        .num_args(0..)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .value_name("PATH")
+        .help(DENY_FFI_HELP)
+        .value_parser(value_parser!(String))
+        .value_hint(ValueHint::AnyPath),
+    )
+    .arg(
+      Arg::new("allow-hrtime")
+        .long("allow-hrtime")
+        .action(ArgAction::SetTrue)
+        .help(ALLOW_HRTIME_HELP),
+    )
+    .arg(
+      Arg::new("deny-hrtime")
+        .long("deny-hrtime")
+        .action(ArgAction::SetTrue)
+        .help(DENY_HRTIME_HELP),
+    )
+    .arg(
+      Arg::new("allow-all")
+        .short('A')
+        .long("allow-all")
+        .action(ArgAction::SetTrue)
+        .help(ALLOW_ALL_HELP),
+    )
+    .arg(
+      Arg::new("no-prompt")
+        .long("no-prompt")
+        .action(ArgAction::SetTrue)
+        .help("Always throw if required permission wasn't passed"),
+    )
+}
+
+fn runtime_args(
+  app: Command,
+  include_perms: bool,
+  include_inspector: bool,
+) -> Command {
+  let app = compile_args(app);
+  let app = if include_perms {
+    permission_args(app)
+  } else {
+    app
+  };
+  let app = if include_inspector {
+    inspect_args(app)
+  } else {
+    app
+  };
+  app
+    .arg(cached_only_arg())
+    .arg(location_arg())
+    .arg(v8_flags_arg())
+    .arg(seed_arg())
+    .arg(enable_testing_features_arg())
+    .arg(strace_ops_arg())
+}
+
+fn inspect_args(app: Command) -> Command {
+  app
+    .arg(
+      Arg::new("inspect")
+        .long("inspect")
+        .value_name("HOST_AND_PORT")
+        .help("Activate inspector on host:port (default: 127.0.0.1:9229)")
+        .num_args(0..=1)
+        .require_equals(true)
+        .value_parser(value_parser!(SocketAddr)),
+    )
+    .arg(
+      Arg::new("inspect-brk")
+        .long("inspect-brk")
+        .value_name("HOST_AND_PORT")
+        .help(
+          "Activate inspector on host:port, wait for debugger to connect and break at the start of user script",
+        )
+        .num_args(0..=1)
+        .require_equals(true)
+        .value_parser(value_parser!(SocketAddr)),
+    )
+    .arg(
+      Arg::new("inspect-wait")
+        .long("inspect-wait")
+        .value_name("HOST_AND_PORT")
+        .help(
+          "Activate inspector on host:port and wait for debugger to connect before running user code",
+        )
+        .num_args(0..=1)
+        .require_equals(true)
+        .value_parser(value_parser!(SocketAddr)),
+    )
+}
+
+static IMPORT_MAP_HELP: &str = concat!(
+  "Load import map file from local file or remote URL.
+Docs: https://docs.deno.com/runtime/manual/basics/import_maps
+Specification: https://wicg.github.io/import-maps/
+Examples: https://github.com/WICG/import-maps#the-import-map",
+);
+
+fn import_map_arg() -> Arg {
+  Arg::new("import-map")
+    .long("import-map")
+    .alias("importmap")
+    .value_name("FILE")
+    .help("Load import map file")
+    .long_help(IMPORT_MAP_HELP)
+    .value_hint(ValueHint::FilePath)
+}
+
+fn env_file_arg() -> Arg {
+  Arg::new("env")
+    .long("env")
+    .value_name("FILE")
+    .help("Load .env file")
+    .long_help("UNSTABLE: Load environment variables from local file. Only the first environment variable with a given key is used. Existing process environment variables are not overwritten.")
+    .value_hint(ValueHint::FilePath)
+    .default_missing_value(".env")
+    .require_equals(true)
+    .num_args(0..=1)
+}
+
+fn reload_arg() -> Arg {
+  Arg::new("reload")
+    .short('r')
+    .num_args(0..)
+    .use_value_delimiter(true)
+    .require_equals(true)
+    .long("reload")
+    .help("Reload source code cache (recompile TypeScript)")
+    .value_name("CACHE_BLOCKLIST")
+    .long_help(
+      "Reload source code cache (recompile TypeScript)
+--reload
+  Reload everything
+--reload=https://deno.land/std
+  Reload only standard modules
+--reload=https://deno.land/std/fs/utils.ts,https://deno.land/std/fmt/colors.ts
+  Reloads specific modules
+--reload=npm:
+  Reload all npm modules
+--reload=npm:chalk
+  Reload specific npm module",
+    )
+    .value_hint(ValueHint::FilePath)
+    .value_parser(reload_arg_validate)
+}
+
+fn ca_file_arg() -> Arg {
+  Arg::new("cert")
+    .long("cert")
+    .value_name("FILE")
+    .help("Load certificate authority from PEM encoded file")
+    .value_hint(ValueHint::FilePath)
+}
+
+fn cached_only_arg() -> Arg {
+  Arg::new("cached-only")
+    .long("cached-only")
+    .action(ArgAction::SetTrue)
+    .help("Require that remote dependencies are already cached")
+}
+
+/// Used for subcommands that operate on executable scripts only.
+/// `deno fmt` has its own `--ext` arg because its possible values differ.
+/// If --ext is not provided and the script doesn't have a file extension,
+/// deno_graph::parse_module() defaults to js.
+fn executable_ext_arg() -> Arg {
+  Arg::new("ext")
+    .long("ext")
+    .help("Set content type of the supplied file")
+    .value_parser(["ts", "tsx", "js", "jsx"])
+}
+
+fn location_arg() -> Arg {
+  Arg::new("location")
+    .long("location")
+    .value_name("HREF")
+    .value_parser(|href: &str| -> Result<Url, String> {
+      let url = Url::parse(href);
+      if url.is_err() {
+        return Err("Failed to parse URL".to_string());
+      }
+      let mut url = url.unwrap();
+      if !["http", "https"].contains(&url.scheme()) {
+        return Err("Expected protocol \"http\" or \"https\"".to_string());
+      }
+      url.set_username("").unwrap();
+      url.set_password(None).unwrap();
+      Ok(url)
+    })
+    .help("Value of 'globalThis.location' used by some web APIs")
+    .value_hint(ValueHint::Url)
+}
+
+fn enable_testing_features_arg() -> Arg {
+  Arg::new("enable-testing-features-do-not-use")
+    .long("enable-testing-features-do-not-use")
+    .help("INTERNAL: Enable internal features used during integration testing")
+    .action(ArgAction::SetTrue)
+    .hide(true)
+}
+
+fn strace_ops_arg() -> Arg {
+  Arg::new("strace-ops")
+    .long("strace-ops")
+    .num_args(0..)
+    .use_value_delimiter(true)
+    .require_equals(true)
+    .value_name("OPS")
+    .help("Trace low-level op calls")
+    .hide(true)
+}
+
+fn v8_flags_arg() -> Arg {
+  Arg::new("v8-flags")
+    .long("v8-flags")
+    .num_args(..)
+    .use_value_delimiter(true)
+    .require_equals(true)
+    .help("Set V8 command line options")
+    .long_help("To see a list of all available flags use --v8-flags=--help.
+    Any flags set with this flag are appended after the DENO_V8_FLAGS environmental variable")
+}
+
+fn seed_arg() -> Arg {
+  Arg::new("seed")
+    .long("seed")
+    .value_name("NUMBER")
+    .help("Set the random number generator seed")
+    .value_parser(value_parser!(u64))
+}
+
+fn hmr_arg(takes_files: bool) -> Arg {
+  let arg = Arg::new("hmr")
+    .long("unstable-hmr")
+    .help("UNSTABLE: Watch for file changes and hot replace modules")
+    .conflicts_with("watch");
+
+  if takes_files {
+    arg
+      .value_name("FILES")
+      .num_args(0..)
+      .value_parser(value_parser!(String))
+      .use_value_delimiter(true)
+      .require_equals(true)
+      .long_help(
+        "Watch for file changes and restart process automatically.
+Local files from entry point module graph are watched by default.
+Additional paths might be watched by passing them as arguments to this flag.",
+      )
+      .value_hint(ValueHint::AnyPath)
+  } else {
+    arg.action(ArgAction::SetTrue).long_help(
+      "Watch for file changes and restart process automatically.
+      Only local files from entry point module graph are watched.",
+    )
+  }
+}
+
+fn watch_arg(takes_files: bool) -> Arg {
+  let arg = Arg::new("watch")
+    .long("watch")
+    .help("Watch for file changes and restart automatically");
+
+  if takes_files {
+    arg
+      .value_name("FILES")
+      .num_args(0..)
+      .value_parser(value_parser!(String))
+      .use_value_delimiter(true)
+      .require_equals(true)
+      .long_help(
+        "Watch for file changes and restart process automatically.
+Local files from entry point module graph are watched by default.
+Additional paths might be watched by passing them as arguments to this flag.",
+      )
+      .value_hint(ValueHint::AnyPath)
+  } else {
+    arg.action(ArgAction::SetTrue).long_help(
+      "Watch for file changes and restart process automatically.
+      Only local files from entry point module graph are watched.",
+    )
+  }
+}
+
+fn no_clear_screen_arg() -> Arg {
+  Arg::new("no-clear-screen")
+    .requires("watch")
+    .long("no-clear-screen")
+    .action(ArgAction::SetTrue)
+    .help("Do not clear terminal screen when under watch mode")
+}
+
+fn no_check_arg() -> Arg {
+  Arg::new("no-check")
+    .num_args(0..=1)
+    .require_equals(true)
+    .value_name("NO_CHECK_TYPE")
+    .long("no-check")
+    .help("Skip type-checking modules")
+    .long_help(
+      "Skip type-checking. If the value of '--no-check=remote' is supplied,
+diagnostic errors from remote modules will be ignored.",
+    )
+}
+
+fn check_arg(checks_local_by_default: bool) -> Arg {
+  let arg = Arg::new("check")
+    .conflicts_with("no-check")
+    .long("check")
+    .num_args(0..=1)
+    .require_equals(true)
+    .value_name("CHECK_TYPE")
+    .help("Type-check modules");
+
+  if checks_local_by_default {
+    arg.long_help(
+      "Set type-checking behavior. This subcommand type-checks local modules by
+default, so adding --check is redundant.
+If the value of '--check=all' is supplied, diagnostic errors from remote modules
+will be included.
+
+Alternatively, the 'deno check' subcommand can be used.",
+    )
+  } else {
+    arg.long_help(
+      "Enable type-checking. This subcommand does not type-check by default.
+If the value of '--check=all' is supplied, diagnostic errors from remote modules
+will be included.
+
+Alternatively, the 'deno check' subcommand can be used.",
+    )
+  }
+}
+
+fn script_arg() -> Arg {
+  Arg::new("script_arg")
+    .num_args(0..)
+    .action(ArgAction::Append)
+    // NOTE: these defaults are provided
+    // so `deno run --v8-flags=--help` works
+    // without specifying file to run.
+    .default_value_ifs([
+      ("v8-flags", "--help", Some("_")),
+      ("v8-flags", "-help", Some("_")),
+    ])
+    .help("Script arg")
+    .value_name("SCRIPT_ARG")
+    .value_hint(ValueHint::FilePath)
+}
+
+fn lock_arg() -> Arg {
+  Arg::new("lock")
+    .long("lock")
+    .value_name("FILE")
+    .help("Check the specified lock file.
+
+If value is not provided, defaults to \"deno.lock\" in the current working directory.")
+    .num_args(0..=1)
+    .value_parser(value_parser!(String))
+    .value_hint(ValueHint::FilePath)
+}
+
+fn lock_write_arg() -> Arg {
+  Arg::new("lock-write")
+    .action(ArgAction::SetTrue)
+    .long("lock-write")
+    .help("Force overwriting the lock file.")
+    .conflicts_with("no-lock")
+}
+
+fn no_lock_arg() -> Arg {
+  Arg::new("no-lock")
+    .long("no-lock")
+    .action(ArgAction::SetTrue)
+    .help("Disable auto discovery of the lock file.")
+    .conflicts_with("lock")
+}
+
+static CONFIG_HELP: &str = concat!(
+  "The configuration file can be used to configure different aspects of
+deno including TypeScript, linting, and code formatting. Typically the
+configuration file will be called `deno.json` or `deno.jsonc` and
+automatically detected; in that case this flag is not necessary.
+See https://deno.land/manual@v",
+  env!("CARGO_PKG_VERSION"),
+  "/getting_started/configuration_file"
+);
+
+fn config_arg() -> Arg {
+  Arg::new("config")
+    .short('c')
+    .long("config")
+    .value_name("FILE")
+    .help("Specify the configuration file")
+    .long_help(CONFIG_HELP)
+    .value_hint(ValueHint::FilePath)
+}
+
+fn no_config_arg() -> Arg {
+  Arg::new("no-config")
+    .long("no-config")
+    .action(ArgAction::SetTrue)
+    .help("Disable automatic loading of the configuration file.")
+    .conflicts_with("config")
+}
+
+fn no_remote_arg() -> Arg {
+  Arg::new("no-remote")
+    .long("no-remote")
+    .action(ArgAction::SetTrue)
     .help("Do not resolve remote modules")
 }
 
-fn no_npm_arg() -> Arg {
-  Arg::new("no-npm")
-    .long("no-npm")
-    .action(ArgAction::SetTrue)
-    .help("Do not resolve npm modules")
+fn no_npm_arg() -> Arg {
+  Arg::new("no-npm")
+    .long("no-npm")
+    .action(ArgAction::SetTrue)
+    .help("Do not resolve npm modules")
+}
+
+fn node_modules_dir_arg() -> Arg {
+  Arg::new("node-modules-dir")
+    .long("node-modules-dir")
+    .num_args(0..=1)
+    .value_parser(value_parser!(bool))
+    .default_missing_value("true")
+    .require_equals(true)
+    .help("Enables or disables the use of a local node_modules folder for npm packages")
+}
+
+fn vendor_arg() -> Arg {
+  Arg::new("vendor")
+    .long("vendor")
+    .num_args(0..=1)
+    .value_parser(value_parser!(bool))
+    .default_missing_value("true")
+    .require_equals(true)
+    .help("UNSTABLE: Enables or disables the use of a local vendor folder for remote modules and node_modules folder for npm packages")
+}
+
+fn unsafely_ignore_certificate_errors_arg() -> Arg {
+  Arg::new("unsafely-ignore-certificate-errors")
+    .long("unsafely-ignore-certificate-errors")
+    .num_args(0..)
+    .use_value_delimiter(true)
+    .require_equals(true)
+    .value_name("HOSTNAMES")
+    .help("DANGER: Disables verification of TLS certificates")
+    .value_parser(flags_net::validator)
+}
+
+fn add_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let packages = matches.remove_many::<String>("packages").unwrap().collect();
+  flags.subcommand = DenoSubcommand::Add(AddFlags { packages });
+}
+
+fn bench_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.type_check_mode = TypeCheckMode::Local;
+
+  runtime_args_parse(flags, matches, true, false);
+
+  // NOTE: `deno bench` always uses `--no-prompt`, tests shouldn't ever do
+  // interactive prompts, unless done by user code
+  flags.no_prompt = true;
+
+  let json = matches.get_flag("json");
+
+  let ignore = match matches.remove_many::<String>("ignore") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+
+  let filter = matches.remove_one::<String>("filter");
+
+  if matches.contains_id("script_arg") {
+    flags
+      .argv
+      .extend(matches.remove_many::<String>("script_arg").unwrap());
+  }
+
+  let include = if let Some(files) = matches.remove_many::<String>("files") {
+    files.collect()
+  } else {
+    Vec::new()
+  };
+
+  let no_run = matches.get_flag("no-run");
+
+  flags.subcommand = DenoSubcommand::Bench(BenchFlags {
+    files: FileFlags { include, ignore },
+    filter,
+    json,
+    no_run,
+    watch: watch_arg_parse(matches),
+  });
+}
+
+fn bundle_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.type_check_mode = TypeCheckMode::Local;
+
+  compile_args_parse(flags, matches);
+
+  let source_file = matches.remove_one::<String>("source_file").unwrap();
+
+  let out_file =
+    if let Some(out_file) = matches.remove_one::<String>("out_file") {
+      flags.allow_write = Some(vec![]);
+      Some(out_file)
+    } else {
+      None
+    };
+
+  ext_arg_parse(flags, matches);
+
+  flags.subcommand = DenoSubcommand::Bundle(BundleFlags {
+    source_file,
+    out_file,
+    watch: watch_arg_parse(matches),
+  });
+}
+
+fn cache_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  compile_args_parse(flags, matches);
+  let files = matches.remove_many::<String>("file").unwrap().collect();
+  flags.subcommand = DenoSubcommand::Cache(CacheFlags { files });
+}
+
+fn check_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.type_check_mode = TypeCheckMode::Local;
+  compile_args_without_check_parse(flags, matches);
+  let files = matches.remove_many::<String>("file").unwrap().collect();
+  if matches.get_flag("all") || matches.get_flag("remote") {
+    flags.type_check_mode = TypeCheckMode::All;
+  }
+  flags.subcommand = DenoSubcommand::Check(CheckFlags { files });
+}
+
+fn compile_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.type_check_mode = TypeCheckMode::Local;
+  runtime_args_parse(flags, matches, true, false);
+
+  let mut script = matches.remove_many::<String>("script_arg").unwrap();
+  let source_file = script.next().unwrap();
+  let args = script.collect();
+  let output = matches.remove_one::<String>("output");
+  let target = matches.remove_one::<String>("target");
+  let no_terminal = matches.get_flag("no-terminal");
+  let include = match matches.remove_many::<String>("include") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  ext_arg_parse(flags, matches);
+
+  flags.subcommand = DenoSubcommand::Compile(CompileFlags {
+    source_file,
+    output,
+    args,
+    target,
+    no_terminal,
+    include,
+  });
+}
+
+fn completions_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+  mut app: Command,
+) {
+  use clap_complete::generate;
+  use clap_complete::shells::Bash;
+  use clap_complete::shells::Fish;
+  use clap_complete::shells::PowerShell;
+  use clap_complete::shells::Zsh;
+  use clap_complete_fig::Fig;
+
+  let mut buf: Vec<u8> = vec![];
+  let name = "deno";
+
+  match matches.get_one::<String>("shell").unwrap().as_str() {
+    "bash" => generate(Bash, &mut app, name, &mut buf),
+    "fish" => generate(Fish, &mut app, name, &mut buf),
+    "powershell" => generate(PowerShell, &mut app, name, &mut buf),
+    "zsh" => generate(Zsh, &mut app, name, &mut buf),
+    "fig" => generate(Fig, &mut app, name, &mut buf),
+    _ => unreachable!(),
+  }
+
+  flags.subcommand = DenoSubcommand::Completions(CompletionsFlags {
+    buf: buf.into_boxed_slice(),
+  });
+}
+
+fn coverage_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let files = match matches.remove_many::<String>("files") {
+    Some(f) => f.collect(),
+    None => vec!["coverage".to_string()], // default
+  };
+  let ignore = match matches.remove_many::<String>("ignore") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let include = match matches.remove_many::<String>("include") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let exclude = match matches.remove_many::<String>("exclude") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let r#type = if matches.get_flag("lcov") {
+    CoverageType::Lcov
+  } else if matches.get_flag("html") {
+    CoverageType::Html
+  } else if matches.get_flag("detailed") {
+    CoverageType::Detailed
+  } else {
+    CoverageType::Summary
+  };
+  let output = matches.remove_one::<String>("output");
+  flags.subcommand = DenoSubcommand::Coverage(CoverageFlags {
+    files: FileFlags {
+      include: files,
+      ignore,
+    },
+    output,
+    include,
+    exclude,
+    r#type,
+  });
+}
+
+fn doc_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  import_map_arg_parse(flags, matches);
+  reload_arg_parse(flags, matches);
+  lock_arg_parse(flags, matches);
+  no_lock_arg_parse(flags, matches);
+  no_npm_arg_parse(flags, matches);
+  no_remote_arg_parse(flags, matches);
+
+  let source_files_val = matches.remove_many::<String>("source_file");
+  let source_files = if let Some(val) = source_files_val {
+    let vals: Vec<String> = val.collect();
+
+    if vals.len() == 1 {
+      if vals[0] == "--builtin" {
+        DocSourceFileFlag::Builtin
+      } else {
+        DocSourceFileFlag::Paths(vec![vals[0].to_string()])
+      }
+    } else {
+      DocSourceFileFlag::Paths(
+        vals.into_iter().filter(|v| v != "--builtin").collect(),
+      )
+    }
+  } else {
+    DocSourceFileFlag::Builtin
+  };
+  let private = matches.get_flag("private");
+  let lint = matches.get_flag("lint");
+  let json = matches.get_flag("json");
+  let filter = matches.remove_one::<String>("filter");
+  let html = if matches.get_flag("html") {
+    let name = matches.remove_one::<String>("name").unwrap();
+    let output = matches
+      .remove_one::<String>("output")
+      .unwrap_or(String::from("./docs/"));
+    Some(DocHtmlFlag { name, output })
+  } else {
+    None
+  };
+
+  flags.subcommand = DenoSubcommand::Doc(DocFlags {
+    source_files,
+    json,
+    lint,
+    html,
+    filter,
+    private,
+  });
+}
+
+fn eval_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  runtime_args_parse(flags, matches, false, true);
+  flags.allow_all();
+
+  ext_arg_parse(flags, matches);
+
+  // TODO(@satyarohith): remove this flag in 2.0.
+  let as_typescript = matches.get_flag("ts");
+
+  if as_typescript {
+    eprintln!(
+      "⚠️ {}",
+      crate::colors::yellow(
+        "Use `--ext=ts` instead. The `--ts` and `-T` flags are deprecated and will be removed in Deno 2.0."
+      ),
+    );
+
+    flags.ext = Some("ts".to_string());
+  }
+
+  let print = matches.get_flag("print");
+  let mut code_args = matches.remove_many::<String>("code_arg").unwrap();
+  let code = code_args.next().unwrap();
+  flags.argv.extend(code_args);
+
+  flags.subcommand = DenoSubcommand::Eval(EvalFlags { print, code });
+}
+
+fn fmt_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  config_args_parse(flags, matches);
+  ext_arg_parse(flags, matches);
+
+  let include = match matches.remove_many::<String>("files") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let ignore = match matches.remove_many::<String>("ignore") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+
+  let use_tabs = matches.remove_one::<bool>("use-tabs");
+  let line_width = matches.remove_one::<NonZeroU32>("line-width");
+  let indent_width = matches.remove_one::<NonZeroU8>("indent-width");
+  let single_quote = matches.remove_one::<bool>("single-quote");
+  let prose_wrap = matches.remove_one::<String>("prose-wrap");
+  let no_semicolons = matches.remove_one::<bool>("no-semicolons");
+
+  flags.subcommand = DenoSubcommand::Fmt(FmtFlags {
+    check: matches.get_flag("check"),
+    files: FileFlags { include, ignore },
+    use_tabs,
+    line_width,
+    indent_width,
+    single_quote,
+    prose_wrap,
+    no_semicolons,
+    watch: watch_arg_parse(matches),
+  });
+}
+
+fn init_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.subcommand = DenoSubcommand::Init(InitFlags {
+    dir: matches.remove_one::<String>("dir"),
+  });
+}
+
+fn info_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  reload_arg_parse(flags, matches);
+  config_args_parse(flags, matches);
+  import_map_arg_parse(flags, matches);
+  location_arg_parse(flags, matches);
+  ca_file_arg_parse(flags, matches);
+  node_modules_and_vendor_dir_arg_parse(flags, matches);
+  lock_args_parse(flags, matches);
+  no_remote_arg_parse(flags, matches);
+  no_npm_arg_parse(flags, matches);
+  let json = matches.get_flag("json");
+  flags.subcommand = DenoSubcommand::Info(InfoFlags {
+    file: matches.remove_one::<String>("file"),
+    json,
+  });
+}
+
+fn install_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  runtime_args_parse(flags, matches, true, true);
+
+  let root = matches.remove_one::<String>("root");
+
+  let force = matches.get_flag("force");
+  let name = matches.remove_one::<String>("name");
+  let mut cmd_values = matches.remove_many::<String>("cmd").unwrap();
+
+  let module_url = cmd_values.next().unwrap();
+  let args = cmd_values.collect();
+
+  flags.subcommand = DenoSubcommand::Install(InstallFlags {
+    name,
+    module_url,
+    args,
+    root,
+    force,
+  });
+}
+
+fn jupyter_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let conn_file = matches.remove_one::<String>("conn");
+  let kernel = matches.get_flag("kernel");
+  let install = matches.get_flag("install");
+
+  flags.subcommand = DenoSubcommand::Jupyter(JupyterFlags {
+    install,
+    kernel,
+    conn_file,
+  });
+}
+
+fn uninstall_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let root = matches.remove_one::<String>("root");
+
+  let name = matches.remove_one::<String>("name").unwrap();
+  flags.subcommand = DenoSubcommand::Uninstall(UninstallFlags { name, root });
+}
+
+fn lsp_parse(flags: &mut Flags, _matches: &mut ArgMatches) {
+  flags.subcommand = DenoSubcommand::Lsp;
+}
+
+fn lint_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  config_args_parse(flags, matches);
+  let files = match matches.remove_many::<String>("files") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let ignore = match matches.remove_many::<String>("ignore") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let eval = matches.remove_one::<String>("eval");
+  let stdin_ext = matches.remove_one::<String>("stdin-ext");
+  let include_extra = match matches.remove_many::<String>("include-extra") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let max_depth = matches.remove_one::<usize>("max-depth");
+  let fix = matches.get_flag("fix");
+  let fail_on_fix_nonconvergence =
+    matches.get_flag("fail-on-fix-nonconvergence");
+  let fix_only = match matches.remove_many::<String>("fix-only") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let dry_run = matches.get_flag("dry-run");
+  let fix_max_iterations = matches.remove_one::<usize>("fix-max-iterations");
+  let auto_fix_safe_then_report = matches.get_flag("auto-fix-safe-then-report");
+  let fix_preview = matches.get_flag("fix-preview");
+  let rules = matches.get_flag("rules");
+  let print_json_schema = matches.get_flag("print-json-schema");
+  let only = matches.remove_one::<String>("only");
+  let rule_pack = matches.remove_one::<String>("rule-pack");
+  let patch = matches.remove_one::<String>("patch");
+  let explain = matches.remove_one::<String>("explain");
+  let rules_hash = if matches.contains_id("rules-hash") {
+    Some(matches.remove_one::<String>("rules-hash"))
+  } else {
+    None
+  };
+  let maybe_rules_tags = matches
+    .remove_many::<String>("rules-tags")
+    .map(|f| f.collect());
+
+  let maybe_rules_include = matches
+    .remove_many::<String>("rules-include")
+    .map(|f| f.collect());
+
+  let maybe_rules_exclude = matches
+    .remove_many::<String>("rules-exclude")
+    .map(|f| f.collect());
+  let strict_rules = matches.get_flag("strict-rules");
+
+  let json = matches.get_flag("json");
+  let json_workspace = matches.get_flag("json-workspace");
+  let ndjson = matches.get_flag("ndjson");
+  let checkstyle = matches.get_flag("checkstyle");
+  let tap = matches.get_flag("tap");
+  let by_rule = matches.get_flag("by-rule");
+  let compact = matches.get_flag("compact");
+  let compact_ranges = matches.get_flag("compact-ranges");
+  let protobuf = matches.get_flag("protobuf");
+  let output = matches.remove_one::<String>("output");
+  let absolute = matches.get_flag("absolute");
+  let limit = matches.remove_one::<usize>("limit");
+  let relative = matches
+    .remove_one::<String>("format")
+    .is_some_and(|format| format == "relative-paths");
+  let group_by_rule = matches
+    .remove_one::<String>("group-by")
+    .is_some_and(|group_by| group_by == "rule");
+  let no_snippets = matches.get_flag("no-snippets");
+  let no_hints = matches.get_flag("no-hints");
+  let snippet_underline = matches.remove_one::<char>("snippet-underline");
+  let unicode_frame =
+    matches.remove_one::<String>("snippet-frame").as_deref() == Some("unicode");
+  let hyperlinks = matches.get_flag("hyperlinks");
+  let compact_after = matches.remove_one::<usize>("compact-after");
+  let stats = matches.get_flag("stats");
+  let db_output = matches.remove_one::<String>("db-output");
+  let svg_dir = matches.remove_one::<String>("svg-dir");
+  let compare = matches.remove_one::<String>("compare");
+  let baseline = matches.remove_one::<String>("baseline");
+  let update_baseline = matches.get_flag("update-baseline");
+  let success_marker = matches.remove_one::<String>("success-marker");
+  let skip_unreadable = matches.get_flag("skip-unreadable");
+  let require_coverage = matches.get_flag("require-coverage");
+  let normalize_eol = matches.get_flag("normalize-eol");
+  let metrics = matches.remove_one::<String>("metrics");
+  let print_config = matches.get_flag("print-config");
+  let print_file_patterns = matches.get_flag("print-file-patterns");
+  let edit = matches.get_flag("edit");
+  let hide_tags = match matches.remove_many::<String>("hide-tags") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let timeout_per_file = matches.remove_one::<u64>("timeout-per-file");
+  let warn_changed_files = matches.get_flag("warn-changed-files");
+  let fail_on_regressions = matches.get_flag("fail-on-regressions");
+  let verbose = matches.get_flag("verbose");
+  let timing = matches.get_flag("timing");
+  let concurrency = matches.remove_one::<NonZeroUsize>("concurrency");
+  let max_warnings = matches.remove_one::<usize>("max-warnings");
+  let no_error_on_issues = matches.get_flag("no-error-on-issues");
+  let max_warnings_delta = matches.remove_one::<usize>("max-warnings-delta");
+  let update_problem_count = matches.get_flag("update-problem-count");
+  let sidecar = matches.get_flag("sidecar");
+  let file_results = matches.remove_one::<String>("file-results");
+  let cache_namespace = matches.remove_one::<String>("cache-namespace");
+  let cache_path = matches.remove_one::<String>("cache-path");
+  let enclosing_symbol = matches.get_flag("enclosing-symbol");
+  let one_per_file = matches.get_flag("one-per-file");
+  let blame = matches.get_flag("blame");
+  let file_problem_threshold =
+    matches.remove_one::<usize>("file-problem-threshold");
+  let interactive = matches.get_flag("interactive");
+  let no_tests = matches.get_flag("no-tests");
+  let tests_only = matches.get_flag("tests-only");
+  let json_sort = matches.remove_one::<String>("json-sort");
+  let generated = match matches.remove_many::<String>("generated") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+  let skip_generated = matches.get_flag("skip-generated");
+  flags.subcommand = DenoSubcommand::Lint(LintFlags {
+    files: FileFlags {
+      include: files,
+      ignore,
+    },
+    eval,
+    stdin_ext,
+    include_extra,
+    filter: LintFilterFlags {
+      patch,
+      max_depth,
+      hide_tags,
+      no_tests,
+      tests_only,
+      generated,
+      skip_generated,
+    },
+    fix: LintFixFlags {
+      enabled: fix,
+      fail_on_nonconvergence: fail_on_fix_nonconvergence,
+      only: fix_only,
+      dry_run,
+      max_iterations: fix_max_iterations,
+      safe_then_report: auto_fix_safe_then_report,
+      preview: fix_preview,
+      interactive,
+    },
+    rules,
+    only,
+    rule_pack,
+    explain,
+    rules_hash,
+    print_json_schema,
+    maybe_rules_tags,
+    maybe_rules_include,
+    maybe_rules_exclude,
+    strict_rules,
+    reporter: LintReporterFlags {
+      json,
+      json_workspace,
+      ndjson,
+      checkstyle,
+      tap,
+      by_rule,
+      compact,
+      protobuf,
+      output,
+      absolute,
+      relative,
+      compact_ranges,
+      limit,
+      group_by_rule,
+      no_snippets,
+      no_hints,
+      snippet_underline,
+      unicode_frame,
+      hyperlinks,
+      compact_after,
+      stats,
+      json_sort,
+      enclosing_symbol,
+      one_per_file,
+      blame,
+      db_output,
+      svg_dir,
+      sidecar,
+      file_results,
+      file_problem_threshold,
+      verbose,
+      timing,
+    },
+    compare,
+    baseline,
+    update_baseline,
+    success_marker,
+    skip_unreadable,
+    require_coverage,
+    normalize_eol,
+    metrics,
+    print_config,
+    print_file_patterns,
+    edit,
+    timeout_per_file,
+    warn_changed_files,
+    fail_on_regressions,
+    concurrency,
+    max_warnings,
+    no_error_on_issues,
+    max_warnings_delta,
+    update_problem_count,
+    cache: LintCacheFlags {
+      namespace: cache_namespace,
+      path: cache_path,
+    },
+    watch: watch_arg_parse(matches),
+    watch_no_fail: matches.get_flag("watch-no-fail"),
+    inbox: matches.remove_one::<String>("inbox"),
+    inbox_done: matches.remove_one::<String>("inbox-done"),
+    inbox_delete: matches.get_flag("inbox-delete"),
+    stdin_file_list: matches.get_flag("stdin-file-list"),
+    unstable_md: matches.get_flag("unstable-md"),
+  });
+}
+
+fn repl_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  runtime_args_parse(flags, matches, true, true);
+  unsafely_ignore_certificate_errors_parse(flags, matches);
+
+  let eval_files = matches
+    .remove_many::<String>("eval-file")
+    .map(|values| values.collect());
+
+  handle_repl_flags(
+    flags,
+    ReplFlags {
+      eval_files,
+      eval: matches.remove_one::<String>("eval"),
+      is_default_command: false,
+    },
+  );
+}
+
+fn run_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+  app: Command,
+) -> clap::error::Result<()> {
+  runtime_args_parse(flags, matches, true, true);
+
+  let mut script_arg =
+    matches.remove_many::<String>("script_arg").ok_or_else(|| {
+      let mut app = app;
+      let subcommand = &mut app.find_subcommand_mut("run").unwrap();
+      subcommand.error(
+        clap::error::ErrorKind::MissingRequiredArgument,
+        "[SCRIPT_ARG] may only be omitted with --v8-flags=--help",
+      )
+    })?;
+
+  let script = script_arg.next().unwrap();
+  flags.argv.extend(script_arg);
+
+  ext_arg_parse(flags, matches);
+
+  flags.subcommand = DenoSubcommand::Run(RunFlags {
+    script,
+    watch: watch_arg_parse_with_paths(matches),
+  });
+
+  Ok(())
+}
+
+fn task_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.config_flag = matches
+    .remove_one::<String>("config")
+    .map(ConfigFlag::Path)
+    .unwrap_or(ConfigFlag::Discover);
+
+  let mut task_flags = TaskFlags {
+    cwd: matches.remove_one::<String>("cwd"),
+    task: None,
+  };
+
+  if let Some((task, mut matches)) = matches.remove_subcommand() {
+    task_flags.task = Some(task);
+
+    flags.argv.extend(
+      matches
+        .remove_many::<std::ffi::OsString>("")
+        .into_iter()
+        .flatten()
+        .filter_map(|arg| arg.into_string().ok()),
+    );
+  }
+
+  flags.subcommand = DenoSubcommand::Task(task_flags);
+}
+
+fn test_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.type_check_mode = TypeCheckMode::Local;
+  runtime_args_parse(flags, matches, true, true);
+  // NOTE: `deno test` always uses `--no-prompt`, tests shouldn't ever do
+  // interactive prompts, unless done by user code
+  flags.no_prompt = true;
+
+  let ignore = match matches.remove_many::<String>("ignore") {
+    Some(f) => f.collect(),
+    None => vec![],
+  };
+
+  let no_run = matches.get_flag("no-run");
+  let trace_leaks =
+    matches.get_flag("trace-ops") || matches.get_flag("trace-leaks");
+  if trace_leaks && matches.get_flag("trace-ops") {
+    // We can't change this to use the log crate because its not configured
+    // yet at this point since the flags haven't been parsed. This flag is
+    // deprecated though so it's not worth changing the code to use the log
+    // crate here and this is only done for testing anyway.
+    eprintln!(
+      "⚠️ {}",
+      crate::colors::yellow("The `--trace-ops` flag is deprecated and will be removed in Deno 2.0.\nUse the `--trace-leaks` flag instead."),
+    );
+  }
+  let doc = matches.get_flag("doc");
+  let allow_none = matches.get_flag("allow-none");
+  let filter = matches.remove_one::<String>("filter");
+
+  let fail_fast = if matches.contains_id("fail-fast") {
+    Some(
+      matches
+        .remove_one::<NonZeroUsize>("fail-fast")
+        .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
+    )
+  } else {
+    None
+  };
+
+  let shuffle = if matches.contains_id("shuffle") {
+    Some(
+      matches
+        .remove_one::<u64>("shuffle")
+        .unwrap_or_else(rand::random),
+    )
+  } else {
+    None
+  };
+
+  if let Some(script_arg) = matches.remove_many::<String>("script_arg") {
+    flags.argv.extend(script_arg);
+  }
+
+  let concurrent_jobs = if matches.get_flag("parallel") {
+    if let Ok(value) = env::var("DENO_JOBS") {
+      value.parse::<NonZeroUsize>().ok()
+    } else {
+      std::thread::available_parallelism().ok()
+    }
+  } else if matches.contains_id("jobs") {
+    // We can't change this to use the log crate because its not configured
+    // yet at this point since the flags haven't been parsed. This flag is
+    // deprecated though so it's not worth changing the code to use the log
+    // crate here and this is only done for testing anyway.
+    eprintln!(
+      "⚠️ {}",
+      crate::colors::yellow("The `--jobs` flag is deprecated and will be removed in Deno 2.0.\nUse the `--parallel` flag with possibly the `DENO_JOBS` environment variable instead.\nLearn more at: https://docs.deno.com/runtime/manual/basics/env_variables"),
+    );
+    if let Some(value) = matches.remove_one::<NonZeroUsize>("jobs") {
+      Some(value)
+    } else {
+      std::thread::available_parallelism().ok()
+    }
+  } else {
+    None
+  };
+
+  let include = if let Some(files) = matches.remove_many::<String>("files") {
+    files.collect()
+  } else {
+    Vec::new()
+  };
+
+  let junit_path = matches.remove_one::<String>("junit-path");
+
+  let reporter =
+    if let Some(reporter) = matches.remove_one::<String>("reporter") {
+      match reporter.as_str() {
+        "pretty" => TestReporterConfig::Pretty,
+        "junit" => TestReporterConfig::Junit,
+        "dot" => TestReporterConfig::Dot,
+        "tap" => TestReporterConfig::Tap,
+        _ => unreachable!(),
+      }
+    } else {
+      TestReporterConfig::Pretty
+    };
+
+  if matches!(reporter, TestReporterConfig::Dot | TestReporterConfig::Tap) {
+    flags.log_level = Some(Level::Error);
+  }
+
+  flags.subcommand = DenoSubcommand::Test(TestFlags {
+    no_run,
+    doc,
+    coverage_dir: matches.remove_one::<String>("coverage"),
+    fail_fast,
+    files: FileFlags { include, ignore },
+    filter,
+    shuffle,
+    allow_none,
+    concurrent_jobs,
+    trace_leaks,
+    watch: watch_arg_parse(matches),
+    reporter,
+    junit_path,
+  });
+}
+
+fn types_parse(flags: &mut Flags, _matches: &mut ArgMatches) {
+  flags.subcommand = DenoSubcommand::Types;
 }
 
-fn node_modules_dir_arg() -> Arg {
-  Arg::new("node-modules-dir")
-    .long("node-modules-dir")
-    .num_args(0..=1)
-    .value_parser(value_parser!(bool))
-    .default_missing_value("true")
-    .require_equals(true)
-    .help("Enables or disables the use of a local node_modules folder for npm packages")
+fn upgrade_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  ca_file_arg_parse(flags, matches);
+
+  let dry_run = matches.get_flag("dry-run");
+  let force = matches.get_flag("force");
+  let canary = matches.get_flag("canary");
+  let version = matches.remove_one::<String>("version");
+  let output = matches.remove_one::<String>("output");
+  flags.subcommand = DenoSubcommand::Upgrade(UpgradeFlags {
+    dry_run,
+    force,
+    canary,
+    version,
+    output,
+  });
 }
 
-fn vendor_arg() -> Arg {
-  Arg::new("vendor")
-    .long("vendor")
-    .num_args(0..=1)
-    .value_parser(value_parser!(bool))
-    .default_missing_value("true")
-    .require_equals(true)
-    .help("UNSTABLE: Enables or disables the use of a local vendor folder for remote modules and node_modules folder for npm packages")
+fn vendor_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  ca_file_arg_parse(flags, matches);
+  config_args_parse(flags, matches);
+  import_map_arg_parse(flags, matches);
+  lock_arg_parse(flags, matches);
+  node_modules_and_vendor_dir_arg_parse(flags, matches);
+  reload_arg_parse(flags, matches);
+
+  flags.subcommand = DenoSubcommand::Vendor(VendorFlags {
+    specifiers: matches
+      .remove_many::<String>("specifiers")
+      .map(|p| p.collect())
+      .unwrap_or_default(),
+    output_path: matches.remove_one::<String>("output"),
+    force: matches.get_flag("force"),
+  });
 }
 
-fn unsafely_ignore_certificate_errors_arg() -> Arg {
-  Arg::new("unsafely-ignore-certificate-errors")
-    .long("unsafely-ignore-certificate-errors")
-    .num_args(0..)
-    .use_value_delimiter(true)
-    .require_equals(true)
-    .value_name("HOSTNAMES")
-    .help("DANGER: Disables verification of TLS certificates")
-    .value_parser(flags_net::validator)
+fn publish_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.type_check_mode = TypeCheckMode::Local; // local by default
+  no_check_arg_parse(flags, matches);
+  check_arg_parse(flags, matches);
+  config_args_parse(flags, matches);
+
+  flags.subcommand = DenoSubcommand::Publish(PublishFlags {
+    token: matches.remove_one("token"),
+    dry_run: matches.get_flag("dry-run"),
+    allow_slow_types: matches.get_flag("allow-slow-types"),
+    allow_dirty: matches.get_flag("allow-dirty"),
+    no_provenance: matches.get_flag("no-provenance"),
+  });
 }
 
-fn add_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  let packages = matches.remove_many::<String>("packages").unwrap().collect();
-  flags.subcommand = DenoSubcommand::Add(AddFlags { packages });
+fn compile_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  compile_args_without_check_parse(flags, matches);
+  no_check_arg_parse(flags, matches);
+  check_arg_parse(flags, matches);
 }
 
-fn bench_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.type_check_mode = TypeCheckMode::Local;
+fn compile_args_without_check_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+) {
+  import_map_arg_parse(flags, matches);
+  no_remote_arg_parse(flags, matches);
+  no_npm_arg_parse(flags, matches);
+  node_modules_and_vendor_dir_arg_parse(flags, matches);
+  config_args_parse(flags, matches);
+  reload_arg_parse(flags, matches);
+  lock_args_parse(flags, matches);
+  ca_file_arg_parse(flags, matches);
+}
 
-  runtime_args_parse(flags, matches, true, false);
+fn permission_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  unsafely_ignore_certificate_errors_parse(flags, matches);
+  if let Some(read_wl) = matches.remove_many::<String>("allow-read") {
+    flags.allow_read = Some(read_wl.collect());
+  }
 
-  // NOTE: `deno bench` always uses `--no-prompt`, tests shouldn't ever do
-  // interactive prompts, unless done by user code
-  flags.no_prompt = true;
+  if let Some(read_wl) = matches.remove_many::<String>("deny-read") {
+    flags.deny_read = Some(read_wl.collect());
+  }
 
-  let json = matches.get_flag("json");
+  if let Some(write_wl) = matches.remove_many::<String>("allow-write") {
+    flags.allow_write = Some(write_wl.collect());
+  }
 
-  let ignore = match matches.remove_many::<String>("ignore") {
-    Some(f) => f.collect(),
-    None => vec![],
+  if let Some(write_wl) = matches.remove_many::<String>("deny-write") {
+    flags.deny_write = Some(write_wl.collect());
+  }
+
+  if let Some(net_wl) = matches.remove_many::<String>("allow-net") {
+    let net_allowlist = flags_net::parse(net_wl.collect()).unwrap();
+    flags.allow_net = Some(net_allowlist);
+  }
+
+  if let Some(net_wl) = matches.remove_many::<String>("deny-net") {
+    let net_denylist = flags_net::parse(net_wl.collect()).unwrap();
+    flags.deny_net = Some(net_denylist);
+  }
+
+  if let Some(env_wl) = matches.remove_many::<String>("allow-env") {
+    flags.allow_env = Some(env_wl.collect());
+    debug!("env allowlist: {:#?}", &flags.allow_env);
+  }
+
+  if let Some(env_wl) = matches.remove_many::<String>("deny-env") {
+    flags.deny_env = Some(env_wl.collect());
+    debug!("env denylist: {:#?}", &flags.deny_env);
+  }
+
+  if let Some(run_wl) = matches.remove_many::<String>("allow-run") {
+    flags.allow_run = Some(run_wl.collect());
+    debug!("run allowlist: {:#?}", &flags.allow_run);
+  }
+
+  if let Some(run_wl) = matches.remove_many::<String>("deny-run") {
+    flags.deny_run = Some(run_wl.collect());
+    debug!("run denylist: {:#?}", &flags.deny_run);
+  }
+
+  if let Some(sys_wl) = matches.remove_many::<String>("allow-sys") {
+    flags.allow_sys = Some(sys_wl.collect());
+    debug!("sys info allowlist: {:#?}", &flags.allow_sys);
+  }
+
+  if let Some(sys_wl) = matches.remove_many::<String>("deny-sys") {
+    flags.deny_sys = Some(sys_wl.collect());
+    debug!("sys info denylist: {:#?}", &flags.deny_sys);
+  }
+
+  if let Some(ffi_wl) = matches.remove_many::<String>("allow-ffi") {
+    flags.allow_ffi = Some(ffi_wl.collect());
+    debug!("ffi allowlist: {:#?}", &flags.allow_ffi);
+  }
+
+  if let Some(ffi_wl) = matches.remove_many::<String>("deny-ffi") {
+    flags.deny_ffi = Some(ffi_wl.collect());
+    debug!("ffi denylist: {:#?}", &flags.deny_ffi);
+  }
+
+  if matches.get_flag("allow-hrtime") {
+    flags.allow_hrtime = true;
+  }
+
+  if matches.get_flag("deny-hrtime") {
+    flags.deny_hrtime = true;
+  }
+
+  if matches.get_flag("allow-all") {
+    flags.allow_all();
+  }
+
+  if matches.get_flag("no-prompt") {
+    flags.no_prompt = true;
+  }
+}
+
+fn unsafely_ignore_certificate_errors_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+) {
+  if let Some(ic_wl) =
+    matches.remove_many::<String>("unsafely-ignore-certificate-errors")
+  {
+    let ic_allowlist = flags_net::parse(ic_wl.collect()).unwrap();
+    flags.unsafely_ignore_certificate_errors = Some(ic_allowlist);
+  }
+}
+
+fn runtime_args_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+  include_perms: bool,
+  include_inspector: bool,
+) {
+  compile_args_parse(flags, matches);
+  cached_only_arg_parse(flags, matches);
+  if include_perms {
+    permission_args_parse(flags, matches);
+  }
+  if include_inspector {
+    inspect_arg_parse(flags, matches);
+  }
+  location_arg_parse(flags, matches);
+  v8_flags_arg_parse(flags, matches);
+  seed_arg_parse(flags, matches);
+  enable_testing_features_arg_parse(flags, matches);
+  env_file_arg_parse(flags, matches);
+  strace_ops_parse(flags, matches);
+}
+
+fn inspect_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let default = || "127.0.0.1:9229".parse::<SocketAddr>().unwrap();
+  flags.inspect = if matches.contains_id("inspect") {
+    Some(
+      matches
+        .remove_one::<SocketAddr>("inspect")
+        .unwrap_or_else(default),
+    )
+  } else {
+    None
+  };
+  flags.inspect_brk = if matches.contains_id("inspect-brk") {
+    Some(
+      matches
+        .remove_one::<SocketAddr>("inspect-brk")
+        .unwrap_or_else(default),
+    )
+  } else {
+    None
   };
-
-  let filter = matches.remove_one::<String>("filter");
-
-  if matches.contains_id("script_arg") {
-    flags
-      .argv
-      .extend(matches.remove_many::<String>("script_arg").unwrap());
-  }
-
-  let include = if let Some(files) = matches.remove_many::<String>("files") {
-    files.collect()
+  flags.inspect_wait = if matches.contains_id("inspect-wait") {
+    Some(
+      matches
+        .remove_one::<SocketAddr>("inspect-wait")
+        .unwrap_or_else(default),
+    )
   } else {
-    Vec::new()
+    None
   };
-
-  let no_run = matches.get_flag("no-run");
-
-  flags.subcommand = DenoSubcommand::Bench(BenchFlags {
-    files: FileFlags { include, ignore },
-    filter,
-    json,
-    no_run,
-    watch: watch_arg_parse(matches),
-  });
 }
 
-fn bundle_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.type_check_mode = TypeCheckMode::Local;
-
-  compile_args_parse(flags, matches);
-
-  let source_file = matches.remove_one::<String>("source_file").unwrap();
-
-  let out_file =
-    if let Some(out_file) = matches.remove_one::<String>("out_file") {
-      flags.allow_write = Some(vec![]);
-      Some(out_file)
-    } else {
-      None
-    };
-
-  ext_arg_parse(flags, matches);
-
-  flags.subcommand = DenoSubcommand::Bundle(BundleFlags {
-    source_file,
-    out_file,
-    watch: watch_arg_parse(matches),
-  });
+fn import_map_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.import_map_path = matches.remove_one::<String>("import-map");
 }
 
-fn cache_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  compile_args_parse(flags, matches);
-  let files = matches.remove_many::<String>("file").unwrap().collect();
-  flags.subcommand = DenoSubcommand::Cache(CacheFlags { files });
+fn env_file_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.env_file = matches.remove_one::<String>("env");
 }
 
-fn check_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.type_check_mode = TypeCheckMode::Local;
-  compile_args_without_check_parse(flags, matches);
-  let files = matches.remove_many::<String>("file").unwrap().collect();
-  if matches.get_flag("all") || matches.get_flag("remote") {
-    flags.type_check_mode = TypeCheckMode::All;
+fn reload_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if let Some(cache_bl) = matches.remove_many::<String>("reload") {
+    let raw_cache_blocklist: Vec<String> = cache_bl.collect();
+    if raw_cache_blocklist.is_empty() {
+      flags.reload = true;
+    } else {
+      flags.cache_blocklist = resolve_urls(raw_cache_blocklist);
+      debug!("cache blocklist: {:#?}", &flags.cache_blocklist);
+      flags.reload = false;
+    }
   }
-  flags.subcommand = DenoSubcommand::Check(CheckFlags { files });
 }
 
-fn compile_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.type_check_mode = TypeCheckMode::Local;
-  runtime_args_parse(flags, matches, true, false);
-
-  let mut script = matches.remove_many::<String>("script_arg").unwrap();
-  let source_file = script.next().unwrap();
-  let args = script.collect();
-  let output = matches.remove_one::<String>("output");
-  let target = matches.remove_one::<String>("target");
-  let no_terminal = matches.get_flag("no-terminal");
-  let include = match matches.remove_many::<String>("include") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  ext_arg_parse(flags, matches);
-
-  flags.subcommand = DenoSubcommand::Compile(CompileFlags {
-    source_file,
-    output,
-    args,
-    target,
-    no_terminal,
-    include,
-  });
+fn ca_file_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.ca_data = matches.remove_one::<String>("cert").map(CaData::File);
 }
 
-fn completions_parse(
+fn enable_testing_features_arg_parse(
   flags: &mut Flags,
   matches: &mut ArgMatches,
-  mut app: Command,
 ) {
-  use clap_complete::generate;
-  use clap_complete::shells::Bash;
-  use clap_complete::shells::Fish;
-  use clap_complete::shells::PowerShell;
-  use clap_complete::shells::Zsh;
-  use clap_complete_fig::Fig;
-
-  let mut buf: Vec<u8> = vec![];
-  let name = "deno";
-
-  match matches.get_one::<String>("shell").unwrap().as_str() {
-    "bash" => generate(Bash, &mut app, name, &mut buf),
-    "fish" => generate(Fish, &mut app, name, &mut buf),
-    "powershell" => generate(PowerShell, &mut app, name, &mut buf),
-    "zsh" => generate(Zsh, &mut app, name, &mut buf),
-    "fig" => generate(Fig, &mut app, name, &mut buf),
-    _ => unreachable!(),
+  if matches.get_flag("enable-testing-features-do-not-use") {
+    flags.enable_testing_features = true
   }
-
-  flags.subcommand = DenoSubcommand::Completions(CompletionsFlags {
-    buf: buf.into_boxed_slice(),
-  });
 }
 
-fn coverage_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  let files = match matches.remove_many::<String>("files") {
-    Some(f) => f.collect(),
-    None => vec!["coverage".to_string()], // default
-  };
-  let ignore = match matches.remove_many::<String>("ignore") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  let include = match matches.remove_many::<String>("include") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  let exclude = match matches.remove_many::<String>("exclude") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  let r#type = if matches.get_flag("lcov") {
-    CoverageType::Lcov
-  } else if matches.get_flag("html") {
-    CoverageType::Html
-  } else if matches.get_flag("detailed") {
-    CoverageType::Detailed
-  } else {
-    CoverageType::Summary
-  };
-  let output = matches.remove_one::<String>("output");
-  flags.subcommand = DenoSubcommand::Coverage(CoverageFlags {
-    files: FileFlags {
-      include: files,
-      ignore,
-    },
-    output,
-    include,
-    exclude,
-    r#type,
-  });
+fn strace_ops_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if let Some(patterns) = matches.remove_many::<String>("strace-ops") {
+    flags.strace_ops = Some(patterns.collect());
+  }
 }
 
-fn doc_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  import_map_arg_parse(flags, matches);
-  reload_arg_parse(flags, matches);
-  lock_arg_parse(flags, matches);
-  no_lock_arg_parse(flags, matches);
-  no_npm_arg_parse(flags, matches);
-  no_remote_arg_parse(flags, matches);
-
-  let source_files_val = matches.remove_many::<String>("source_file");
-  let source_files = if let Some(val) = source_files_val {
-    let vals: Vec<String> = val.collect();
+fn cached_only_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if matches.get_flag("cached-only") {
+    flags.cached_only = true;
+  }
+}
 
-    if vals.len() == 1 {
-      if vals[0] == "--builtin" {
-        DocSourceFileFlag::Builtin
-      } else {
-        DocSourceFileFlag::Paths(vec![vals[0].to_string()])
-      }
-    } else {
-      DocSourceFileFlag::Paths(
-        vals.into_iter().filter(|v| v != "--builtin").collect(),
-      )
-    }
-  } else {
-    DocSourceFileFlag::Builtin
-  };
-  let private = matches.get_flag("private");
-  let lint = matches.get_flag("lint");
-  let json = matches.get_flag("json");
-  let filter = matches.remove_one::<String>("filter");
-  let html = if matches.get_flag("html") {
-    let name = matches.remove_one::<String>("name").unwrap();
-    let output = matches
-      .remove_one::<String>("output")
-      .unwrap_or(String::from("./docs/"));
-    Some(DocHtmlFlag { name, output })
-  } else {
-    None
-  };
+fn ext_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.ext = matches.remove_one::<String>("ext");
+}
 
-  flags.subcommand = DenoSubcommand::Doc(DocFlags {
-    source_files,
-    json,
-    lint,
-    html,
-    filter,
-    private,
-  });
+fn location_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.location = matches.remove_one::<Url>("location");
 }
 
-fn eval_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  runtime_args_parse(flags, matches, false, true);
-  flags.allow_all();
+fn v8_flags_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if let Some(v8_flags) = matches.remove_many::<String>("v8-flags") {
+    flags.v8_flags = v8_flags.collect();
+  }
+}
 
-  ext_arg_parse(flags, matches);
+fn seed_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if let Some(seed) = matches.remove_one::<u64>("seed") {
+    flags.seed = Some(seed);
 
-  // TODO(@satyarohith): remove this flag in 2.0.
-  let as_typescript = matches.get_flag("ts");
+    flags.v8_flags.push(format!("--random-seed={seed}"));
+  }
+}
 
-  if as_typescript {
-    eprintln!(
-      "⚠️ {}",
-      crate::colors::yellow(
-        "Use `--ext=ts` instead. The `--ts` and `-T` flags are deprecated and will be removed in Deno 2.0."
+fn no_check_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if let Some(cache_type) = matches.get_one::<String>("no-check") {
+    match cache_type.as_str() {
+      "remote" => flags.type_check_mode = TypeCheckMode::Local,
+      _ => debug!(
+        "invalid value for 'no-check' of '{}' using default",
+        cache_type
       ),
-    );
+    }
+  } else if matches.contains_id("no-check") {
+    flags.type_check_mode = TypeCheckMode::None;
+  }
+}
 
-    flags.ext = Some("ts".to_string());
+fn check_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if let Some(cache_type) = matches.get_one::<String>("check") {
+    match cache_type.as_str() {
+      "all" => flags.type_check_mode = TypeCheckMode::All,
+      _ => debug!(
+        "invalid value for 'check' of '{}' using default",
+        cache_type
+      ),
+    }
+  } else if matches.contains_id("check") {
+    flags.type_check_mode = TypeCheckMode::Local;
   }
+}
 
-  let print = matches.get_flag("print");
-  let mut code_args = matches.remove_many::<String>("code_arg").unwrap();
-  let code = code_args.next().unwrap();
-  flags.argv.extend(code_args);
+fn lock_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  lock_arg_parse(flags, matches);
+  no_lock_arg_parse(flags, matches);
+  if matches.get_flag("lock-write") {
+    flags.lock_write = true;
+  }
+}
 
-  flags.subcommand = DenoSubcommand::Eval(EvalFlags { print, code });
+fn lock_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if matches.contains_id("lock") {
+    let lockfile = matches
+      .remove_one::<String>("lock")
+      .unwrap_or_else(|| String::from("./deno.lock"));
+    flags.lock = Some(lockfile);
+  }
 }
 
-fn fmt_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  config_args_parse(flags, matches);
-  ext_arg_parse(flags, matches);
+fn no_lock_arg_parse(flags: &mut Flags, matches: &ArgMatches) {
+  if matches.get_flag("no-lock") {
+    flags.no_lock = true;
+  }
+}
 
-  let include = match matches.remove_many::<String>("files") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  let ignore = match matches.remove_many::<String>("ignore") {
-    Some(f) => f.collect(),
-    None => vec![],
+fn config_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  flags.config_flag = if matches.get_flag("no-config") {
+    ConfigFlag::Disabled
+  } else if let Some(config) = matches.remove_one::<String>("config") {
+    ConfigFlag::Path(config)
+  } else {
+    ConfigFlag::Discover
   };
+}
 
-  let use_tabs = matches.remove_one::<bool>("use-tabs");
-  let line_width = matches.remove_one::<NonZeroU32>("line-width");
-  let indent_width = matches.remove_one::<NonZeroU8>("indent-width");
-  let single_quote = matches.remove_one::<bool>("single-quote");
-  let prose_wrap = matches.remove_one::<String>("prose-wrap");
-  let no_semicolons = matches.remove_one::<bool>("no-semicolons");
-
-  flags.subcommand = DenoSubcommand::Fmt(FmtFlags {
-    check: matches.get_flag("check"),
-    files: FileFlags { include, ignore },
-    use_tabs,
-    line_width,
-    indent_width,
-    single_quote,
-    prose_wrap,
-    no_semicolons,
-    watch: watch_arg_parse(matches),
-  });
+fn no_remote_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if matches.get_flag("no-remote") {
+    flags.no_remote = true;
+  }
 }
 
-fn init_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.subcommand = DenoSubcommand::Init(InitFlags {
-    dir: matches.remove_one::<String>("dir"),
-  });
+fn no_npm_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if matches.get_flag("no-npm") {
+    flags.no_npm = true;
+  }
 }
 
-fn info_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  reload_arg_parse(flags, matches);
-  config_args_parse(flags, matches);
-  import_map_arg_parse(flags, matches);
-  location_arg_parse(flags, matches);
-  ca_file_arg_parse(flags, matches);
-  node_modules_and_vendor_dir_arg_parse(flags, matches);
-  lock_args_parse(flags, matches);
-  no_remote_arg_parse(flags, matches);
-  no_npm_arg_parse(flags, matches);
-  let json = matches.get_flag("json");
-  flags.subcommand = DenoSubcommand::Info(InfoFlags {
-    file: matches.remove_one::<String>("file"),
-    json,
-  });
+fn node_modules_and_vendor_dir_arg_parse(
+  flags: &mut Flags,
+  matches: &mut ArgMatches,
+) {
+  flags.node_modules_dir = matches.remove_one::<bool>("node-modules-dir");
+  flags.vendor = matches.remove_one::<bool>("vendor");
 }
 
-fn install_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  runtime_args_parse(flags, matches, true, true);
+fn reload_arg_validate(urlstr: &str) -> Result<String, String> {
+  if urlstr.is_empty() {
+    return Err(String::from("Missing url. Check for extra commas."));
+  }
+  match Url::from_str(urlstr) {
+    Ok(_) => Ok(urlstr.to_string()),
+    Err(e) => Err(e.to_string()),
+  }
+}
 
-  let root = matches.remove_one::<String>("root");
+fn watch_arg_parse(matches: &mut ArgMatches) -> Option<WatchFlags> {
+  if matches.get_flag("watch") {
+    Some(WatchFlags {
+      hmr: false,
+      no_clear_screen: matches.get_flag("no-clear-screen"),
+    })
+  } else {
+    None
+  }
+}
 
-  let force = matches.get_flag("force");
-  let name = matches.remove_one::<String>("name");
-  let mut cmd_values = matches.remove_many::<String>("cmd").unwrap();
+fn watch_arg_parse_with_paths(
+  matches: &mut ArgMatches,
+) -> Option<WatchFlagsWithPaths> {
+  if let Some(paths) = matches.remove_many::<String>("watch") {
+    return Some(WatchFlagsWithPaths {
+      paths: paths.collect(),
+      hmr: false,
+      no_clear_screen: matches.get_flag("no-clear-screen"),
+    });
+  }
 
-  let module_url = cmd_values.next().unwrap();
-  let args = cmd_values.collect();
+  matches
+    .remove_many::<String>("hmr")
+    .map(|paths| WatchFlagsWithPaths {
+      paths: paths.collect(),
+      hmr: true,
+      no_clear_screen: matches.get_flag("no-clear-screen"),
+    })
+}
 
-  flags.subcommand = DenoSubcommand::Install(InstallFlags {
-    name,
-    module_url,
-    args,
-    root,
-    force,
-  });
+// TODO(ry) move this to utility module and add test.
+/// Strips fragment part of URL. Panics on bad URL.
+pub fn resolve_urls(urls: Vec<String>) -> Vec<String> {
+  let mut out: Vec<String> = vec![];
+  for urlstr in urls.iter() {
+    if let Ok(mut url) = Url::from_str(urlstr) {
+      url.set_fragment(None);
+      let mut full_url = String::from(url.as_str());
+      if full_url.len() > 1 && full_url.ends_with('/') {
+        full_url.pop();
+      }
+      out.push(full_url);
+    } else {
+      panic!("Bad Url: {urlstr}");
+    }
+  }
+  out
 }
 
-fn jupyter_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  let conn_file = matches.remove_one::<String>("conn");
-  let kernel = matches.get_flag("kernel");
-  let install = matches.get_flag("install");
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
 
-  flags.subcommand = DenoSubcommand::Jupyter(JupyterFlags {
-    install,
-    kernel,
-    conn_file,
-  });
-}
+  /// Creates vector of strings, Vec<String>
+  macro_rules! svec {
+    ($($x:expr),* $(,)?) => (vec![$($x.to_string().into()),*]);
+  }
 
-fn uninstall_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  let root = matches.remove_one::<String>("root");
+  #[test]
+  fn global_flags() {
+    #[rustfmt::skip]
+    let r = flags_from_vec(svec!["deno", "--unstable", "--log-level", "debug", "--quiet", "run", "script.ts"]);
 
-  let name = matches.remove_one::<String>("name").unwrap();
-  flags.subcommand = DenoSubcommand::Uninstall(UninstallFlags { name, root });
-}
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string()
+        )),
+        unstable_config: UnstableConfig {
+          legacy_flag_enabled: true,
+          ..Default::default()
+        },
+        log_level: Some(Level::Error),
+        ..Flags::default()
+      }
+    );
+    #[rustfmt::skip]
+    let r2 = flags_from_vec(svec!["deno", "run", "--unstable", "--log-level", "debug", "--quiet", "script.ts"]);
+    let flags2 = r2.unwrap();
+    assert_eq!(flags2, flags);
+  }
 
-fn lsp_parse(flags: &mut Flags, _matches: &mut ArgMatches) {
-  flags.subcommand = DenoSubcommand::Lsp;
-}
+  #[test]
+  fn upgrade() {
+    let r = flags_from_vec(svec!["deno", "upgrade", "--dry-run", "--force"]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
+          force: true,
+          dry_run: true,
+          canary: false,
+          version: None,
+          output: None,
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn upgrade_with_output_flag() {
+    let r = flags_from_vec(svec!["deno", "upgrade", "--output", "example.txt"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
+          force: false,
+          dry_run: false,
+          canary: false,
+          version: None,
+          output: Some(String::from("example.txt")),
+        }),
+        ..Flags::default()
+      }
+    );
+  }
 
-fn lint_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  config_args_parse(flags, matches);
-  let files = match matches.remove_many::<String>("files") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  let ignore = match matches.remove_many::<String>("ignore") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
-  let fix = matches.get_flag("fix");
-  let rules = matches.get_flag("rules");
-  let maybe_rules_tags = matches
-    .remove_many::<String>("rules-tags")
-    .map(|f| f.collect());
+  #[test]
+  fn version() {
+    let r = flags_from_vec(svec!["deno", "--version"]);
+    assert_eq!(
+      r.unwrap_err().kind(),
+      clap::error::ErrorKind::DisplayVersion
+    );
+    let r = flags_from_vec(svec!["deno", "-V"]);
+    assert_eq!(
+      r.unwrap_err().kind(),
+      clap::error::ErrorKind::DisplayVersion
+    );
+  }
 
-  let maybe_rules_include = matches
-    .remove_many::<String>("rules-include")
-    .map(|f| f.collect());
+  #[test]
+  fn run_reload() {
+    let r = flags_from_vec(svec!["deno", "run", "-r", "script.ts"]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string()
+        )),
+        reload: true,
+        ..Flags::default()
+      }
+    );
+  }
 
-  let maybe_rules_exclude = matches
-    .remove_many::<String>("rules-exclude")
-    .map(|f| f.collect());
+  #[test]
+  fn run_watch() {
+    let r = flags_from_vec(svec!["deno", "run", "--watch", "script.ts"]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "script.ts".to_string(),
+          watch: Some(WatchFlagsWithPaths {
+            hmr: false,
+            paths: vec![],
+            no_clear_screen: false,
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
 
-  let json = matches.get_flag("json");
-  let compact = matches.get_flag("compact");
-  flags.subcommand = DenoSubcommand::Lint(LintFlags {
-    files: FileFlags {
-      include: files,
-      ignore,
-    },
-    fix,
-    rules,
-    maybe_rules_tags,
-    maybe_rules_include,
-    maybe_rules_exclude,
-    json,
-    compact,
-    watch: watch_arg_parse(matches),
-  });
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--watch",
+      "--no-clear-screen",
+      "script.ts"
+    ]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "script.ts".to_string(),
+          watch: Some(WatchFlagsWithPaths {
+            hmr: false,
+            paths: vec![],
+            no_clear_screen: true,
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn repl_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  runtime_args_parse(flags, matches, true, true);
-  unsafely_ignore_certificate_errors_parse(flags, matches);
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--unstable-hmr",
+      "--no-clear-screen",
+      "script.ts"
+    ]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "script.ts".to_string(),
+          watch: Some(WatchFlagsWithPaths {
+            hmr: true,
+            paths: vec![],
+            no_clear_screen: true,
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
 
-  let eval_files = matches
-    .remove_many::<String>("eval-file")
-    .map(|values| values.collect());
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--unstable-hmr=foo.txt",
+      "--no-clear-screen",
+      "script.ts"
+    ]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "script.ts".to_string(),
+          watch: Some(WatchFlagsWithPaths {
+            hmr: true,
+            paths: vec![String::from("foo.txt")],
+            no_clear_screen: true,
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
 
-  handle_repl_flags(
-    flags,
-    ReplFlags {
-      eval_files,
-      eval: matches.remove_one::<String>("eval"),
-      is_default_command: false,
-    },
-  );
-}
+    let r =
+      flags_from_vec(svec!["deno", "run", "--hmr", "--watch", "script.ts"]);
+    assert!(r.is_err());
+  }
 
-fn run_parse(
-  flags: &mut Flags,
-  matches: &mut ArgMatches,
-  app: Command,
-) -> clap::error::Result<()> {
-  runtime_args_parse(flags, matches, true, true);
+  #[test]
+  fn run_watch_with_external() {
+    let r =
+      flags_from_vec(svec!["deno", "run", "--watch=file1,file2", "script.ts"]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "script.ts".to_string(),
+          watch: Some(WatchFlagsWithPaths {
+            hmr: false,
+            paths: vec![String::from("file1"), String::from("file2")],
+            no_clear_screen: false,
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
+  }
 
-  let mut script_arg =
-    matches.remove_many::<String>("script_arg").ok_or_else(|| {
-      let mut app = app;
-      let subcommand = &mut app.find_subcommand_mut("run").unwrap();
-      subcommand.error(
-        clap::error::ErrorKind::MissingRequiredArgument,
-        "[SCRIPT_ARG] may only be omitted with --v8-flags=--help",
-      )
-    })?;
+  #[test]
+  fn run_watch_with_no_clear_screen() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--watch",
+      "--no-clear-screen",
+      "script.ts"
+    ]);
 
-  let script = script_arg.next().unwrap();
-  flags.argv.extend(script_arg);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "script.ts".to_string(),
+          watch: Some(WatchFlagsWithPaths {
+            hmr: false,
+            paths: vec![],
+            no_clear_screen: true,
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
+  }
 
-  ext_arg_parse(flags, matches);
+  #[test]
+  fn run_reload_allow_write() {
+    let r =
+      flags_from_vec(svec!["deno", "run", "-r", "--allow-write", "script.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        reload: true,
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string()
+        )),
+        allow_write: Some(vec![]),
+        ..Flags::default()
+      }
+    );
+  }
 
-  flags.subcommand = DenoSubcommand::Run(RunFlags {
-    script,
-    watch: watch_arg_parse_with_paths(matches),
-  });
+  #[test]
+  fn run_v8_flags() {
+    let r = flags_from_vec(svec!["deno", "run", "--v8-flags=--help"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default("_".to_string())),
+        v8_flags: svec!["--help"],
+        ..Flags::default()
+      }
+    );
 
-  Ok(())
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--v8-flags=--expose-gc,--gc-stats=1",
+      "script.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string()
+        )),
+        v8_flags: svec!["--expose-gc", "--gc-stats=1"],
+        ..Flags::default()
+      }
+    );
 
-fn task_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.config_flag = matches
-    .remove_one::<String>("config")
-    .map(ConfigFlag::Path)
-    .unwrap_or(ConfigFlag::Discover);
+    let r = flags_from_vec(svec!["deno", "run", "--v8-flags=--expose-gc"]);
+    assert!(r
+      .unwrap_err()
+      .to_string()
+      .contains("[SCRIPT_ARG] may only be omitted with --v8-flags=--help"));
+  }
 
-  let mut task_flags = TaskFlags {
-    cwd: matches.remove_one::<String>("cwd"),
-    task: None,
-  };
+  #[test]
+  fn has_permission() {
+    let r = flags_from_vec(svec!["deno", "run", "--allow-read", "x.ts"]);
+    assert_eq!(r.unwrap().has_permission(), true);
 
-  if let Some((task, mut matches)) = matches.remove_subcommand() {
-    task_flags.task = Some(task);
+    let r = flags_from_vec(svec!["deno", "run", "--deny-read", "x.ts"]);
+    assert_eq!(r.unwrap().has_permission(), true);
 
-    flags.argv.extend(
-      matches
-        .remove_many::<std::ffi::OsString>("")
-        .into_iter()
-        .flatten()
-        .filter_map(|arg| arg.into_string().ok()),
-    );
+    let r = flags_from_vec(svec!["deno", "run", "x.ts"]);
+    assert_eq!(r.unwrap().has_permission(), false);
   }
 
-  flags.subcommand = DenoSubcommand::Task(task_flags);
-}
+  #[test]
+  fn has_permission_in_argv() {
+    let r = flags_from_vec(svec!["deno", "run", "x.ts", "--allow-read"]);
+    assert_eq!(r.unwrap().has_permission_in_argv(), true);
 
-fn test_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.type_check_mode = TypeCheckMode::Local;
-  runtime_args_parse(flags, matches, true, true);
-  // NOTE: `deno test` always uses `--no-prompt`, tests shouldn't ever do
-  // interactive prompts, unless done by user code
-  flags.no_prompt = true;
+    let r = flags_from_vec(svec!["deno", "run", "x.ts", "--deny-read"]);
+    assert_eq!(r.unwrap().has_permission_in_argv(), true);
 
-  let ignore = match matches.remove_many::<String>("ignore") {
-    Some(f) => f.collect(),
-    None => vec![],
-  };
+    let r = flags_from_vec(svec!["deno", "run", "x.ts"]);
+    assert_eq!(r.unwrap().has_permission_in_argv(), false);
+  }
 
-  let no_run = matches.get_flag("no-run");
-  let trace_leaks =
-    matches.get_flag("trace-ops") || matches.get_flag("trace-leaks");
-  if trace_leaks && matches.get_flag("trace-ops") {
-    // We can't change this to use the log crate because its not configured
-    // yet at this point since the flags haven't been parsed. This flag is
-    // deprecated though so it's not worth changing the code to use the log
-    // crate here and this is only done for testing anyway.
-    eprintln!(
-      "⚠️ {}",
-      crate::colors::yellow("The `--trace-ops` flag is deprecated and will be removed in Deno 2.0.\nUse the `--trace-leaks` flag instead."),
+  #[test]
+  fn script_args() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--allow-net",
+      "gist.ts",
+      "--title",
+      "X"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        argv: svec!["--title", "X"],
+        allow_net: Some(vec![]),
+        ..Flags::default()
+      }
     );
   }
-  let doc = matches.get_flag("doc");
-  let allow_none = matches.get_flag("allow-none");
-  let filter = matches.remove_one::<String>("filter");
-
-  let fail_fast = if matches.contains_id("fail-fast") {
-    Some(
-      matches
-        .remove_one::<NonZeroUsize>("fail-fast")
-        .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
-    )
-  } else {
-    None
-  };
-
-  let shuffle = if matches.contains_id("shuffle") {
-    Some(
-      matches
-        .remove_one::<u64>("shuffle")
-        .unwrap_or_else(rand::random),
-    )
-  } else {
-    None
-  };
 
-  if let Some(script_arg) = matches.remove_many::<String>("script_arg") {
-    flags.argv.extend(script_arg);
+  #[test]
+  fn allow_all() {
+    let r = flags_from_vec(svec!["deno", "run", "--allow-all", "gist.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        allow_all: true,
+        allow_net: Some(vec![]),
+        allow_env: Some(vec![]),
+        allow_run: Some(vec![]),
+        allow_read: Some(vec![]),
+        allow_sys: Some(vec![]),
+        allow_write: Some(vec![]),
+        allow_ffi: Some(vec![]),
+        allow_hrtime: true,
+        ..Flags::default()
+      }
+    );
   }
 
-  let concurrent_jobs = if matches.get_flag("parallel") {
-    if let Ok(value) = env::var("DENO_JOBS") {
-      value.parse::<NonZeroUsize>().ok()
-    } else {
-      std::thread::available_parallelism().ok()
-    }
-  } else if matches.contains_id("jobs") {
-    // We can't change this to use the log crate because its not configured
-    // yet at this point since the flags haven't been parsed. This flag is
-    // deprecated though so it's not worth changing the code to use the log
-    // crate here and this is only done for testing anyway.
-    eprintln!(
-      "⚠️ {}",
-      crate::colors::yellow("The `--jobs` flag is deprecated and will be removed in Deno 2.0.\nUse the `--parallel` flag with possibly the `DENO_JOBS` environment variable instead.\nLearn more at: https://docs.deno.com/runtime/manual/basics/env_variables"),
+  #[test]
+  fn allow_read() {
+    let r = flags_from_vec(svec!["deno", "run", "--allow-read", "gist.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        allow_read: Some(vec![]),
+        ..Flags::default()
+      }
     );
-    if let Some(value) = matches.remove_one::<NonZeroUsize>("jobs") {
-      Some(value)
-    } else {
-      std::thread::available_parallelism().ok()
-    }
-  } else {
-    None
-  };
-
-  let include = if let Some(files) = matches.remove_many::<String>("files") {
-    files.collect()
-  } else {
-    Vec::new()
-  };
+  }
 
-  let junit_path = matches.remove_one::<String>("junit-path");
+  #[test]
+  fn deny_read() {
+    let r = flags_from_vec(svec!["deno", "run", "--deny-read", "gist.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        deny_read: Some(vec![]),
+        ..Flags::default()
+      }
+    );
+  }
 
-  let reporter =
-    if let Some(reporter) = matches.remove_one::<String>("reporter") {
-      match reporter.as_str() {
-        "pretty" => TestReporterConfig::Pretty,
-        "junit" => TestReporterConfig::Junit,
-        "dot" => TestReporterConfig::Dot,
-        "tap" => TestReporterConfig::Tap,
-        _ => unreachable!(),
+  #[test]
+  fn allow_hrtime() {
+    let r = flags_from_vec(svec!["deno", "run", "--allow-hrtime", "gist.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string(),
+        )),
+        allow_hrtime: true,
+        ..Flags::default()
       }
-    } else {
-      TestReporterConfig::Pretty
-    };
+    );
+  }
 
-  if matches!(reporter, TestReporterConfig::Dot | TestReporterConfig::Tap) {
-    flags.log_level = Some(Level::Error);
+  #[test]
+  fn deny_hrtime() {
+    let r = flags_from_vec(svec!["deno", "run", "--deny-hrtime", "gist.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string(),
+        )),
+        deny_hrtime: true,
+        ..Flags::default()
+      }
+    );
   }
 
-  flags.subcommand = DenoSubcommand::Test(TestFlags {
-    no_run,
-    doc,
-    coverage_dir: matches.remove_one::<String>("coverage"),
-    fail_fast,
-    files: FileFlags { include, ignore },
-    filter,
-    shuffle,
-    allow_none,
-    concurrent_jobs,
-    trace_leaks,
-    watch: watch_arg_parse(matches),
-    reporter,
-    junit_path,
-  });
-}
+  #[test]
+  fn double_hyphen() {
+    // notice that flags passed after double dash will not
+    // be parsed to Flags but instead forwarded to
+    // script args as Deno.args
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--allow-write",
+      "script.ts",
+      "--",
+      "-D",
+      "--allow-net"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string(),
+        )),
+        argv: svec!["--", "-D", "--allow-net"],
+        allow_write: Some(vec![]),
+        ..Flags::default()
+      }
+    );
+  }
 
-fn types_parse(flags: &mut Flags, _matches: &mut ArgMatches) {
-  flags.subcommand = DenoSubcommand::Types;
-}
+  #[test]
+  fn fmt() {
+    let r = flags_from_vec(svec!["deno", "fmt", "script_1.ts", "script_2.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Default::default(),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-fn upgrade_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  ca_file_arg_parse(flags, matches);
+    let r = flags_from_vec(svec!["deno", "fmt", "--check"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: true,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Default::default(),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-  let dry_run = matches.get_flag("dry-run");
-  let force = matches.get_flag("force");
-  let canary = matches.get_flag("canary");
-  let version = matches.remove_one::<String>("version");
-  let output = matches.remove_one::<String>("output");
-  flags.subcommand = DenoSubcommand::Upgrade(UpgradeFlags {
-    dry_run,
-    force,
-    canary,
-    version,
-    output,
-  });
-}
+    let r = flags_from_vec(svec!["deno", "fmt"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Default::default(),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-fn vendor_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  ca_file_arg_parse(flags, matches);
-  config_args_parse(flags, matches);
-  import_map_arg_parse(flags, matches);
-  lock_arg_parse(flags, matches);
-  node_modules_and_vendor_dir_arg_parse(flags, matches);
-  reload_arg_parse(flags, matches);
+    let r = flags_from_vec(svec!["deno", "fmt", "--watch"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Some(Default::default()),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-  flags.subcommand = DenoSubcommand::Vendor(VendorFlags {
-    specifiers: matches
-      .remove_many::<String>("specifiers")
-      .map(|p| p.collect())
-      .unwrap_or_default(),
-    output_path: matches.remove_one::<String>("output"),
-    force: matches.get_flag("force"),
-  });
-}
+    let r =
+      flags_from_vec(svec!["deno", "fmt", "--watch", "--no-clear-screen"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Some(WatchFlags {
+            hmr: false,
+            no_clear_screen: true,
+          })
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-fn publish_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.type_check_mode = TypeCheckMode::Local; // local by default
-  no_check_arg_parse(flags, matches);
-  check_arg_parse(flags, matches);
-  config_args_parse(flags, matches);
+    let r = flags_from_vec(svec![
+      "deno",
+      "fmt",
+      "--check",
+      "--watch",
+      "foo.ts",
+      "--ignore=bar.js"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: true,
+          files: FileFlags {
+            include: vec!["foo.ts".to_string()],
+            ignore: vec!["bar.js".to_string()],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Some(Default::default()),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-  flags.subcommand = DenoSubcommand::Publish(PublishFlags {
-    token: matches.remove_one("token"),
-    dry_run: matches.get_flag("dry-run"),
-    allow_slow_types: matches.get_flag("allow-slow-types"),
-    allow_dirty: matches.get_flag("allow-dirty"),
-    no_provenance: matches.get_flag("no-provenance"),
-  });
-}
+    let r = flags_from_vec(svec!["deno", "fmt", "--config", "deno.jsonc"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Default::default(),
+        }),
+        ext: Some("ts".to_string()),
+        config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
+        ..Flags::default()
+      }
+    );
 
-fn compile_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  compile_args_without_check_parse(flags, matches);
-  no_check_arg_parse(flags, matches);
-  check_arg_parse(flags, matches);
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "fmt",
+      "--config",
+      "deno.jsonc",
+      "--watch",
+      "foo.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec!["foo.ts".to_string()],
+            ignore: vec![],
+          },
+          use_tabs: None,
+          line_width: None,
+          indent_width: None,
+          single_quote: None,
+          prose_wrap: None,
+          no_semicolons: None,
+          watch: Some(Default::default()),
+        }),
+        config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-fn compile_args_without_check_parse(
-  flags: &mut Flags,
-  matches: &mut ArgMatches,
-) {
-  import_map_arg_parse(flags, matches);
-  no_remote_arg_parse(flags, matches);
-  no_npm_arg_parse(flags, matches);
-  node_modules_and_vendor_dir_arg_parse(flags, matches);
-  config_args_parse(flags, matches);
-  reload_arg_parse(flags, matches);
-  lock_args_parse(flags, matches);
-  ca_file_arg_parse(flags, matches);
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "fmt",
+      "--use-tabs",
+      "--line-width",
+      "60",
+      "--indent-width",
+      "4",
+      "--single-quote",
+      "--prose-wrap",
+      "never",
+      "--no-semicolons",
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: Some(true),
+          line_width: Some(NonZeroU32::new(60).unwrap()),
+          indent_width: Some(NonZeroU8::new(4).unwrap()),
+          single_quote: Some(true),
+          prose_wrap: Some("never".to_string()),
+          no_semicolons: Some(true),
+          watch: Default::default(),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
 
-fn permission_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  unsafely_ignore_certificate_errors_parse(flags, matches);
-  if let Some(read_wl) = matches.remove_many::<String>("allow-read") {
-    flags.allow_read = Some(read_wl.collect());
+    // try providing =false to the booleans
+    let r = flags_from_vec(svec![
+      "deno",
+      "fmt",
+      "--use-tabs=false",
+      "--single-quote=false",
+      "--no-semicolons=false",
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Fmt(FmtFlags {
+          check: false,
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          use_tabs: Some(false),
+          line_width: None,
+          indent_width: None,
+          single_quote: Some(false),
+          prose_wrap: None,
+          no_semicolons: Some(false),
+          watch: Default::default(),
+        }),
+        ext: Some("ts".to_string()),
+        ..Flags::default()
+      }
+    );
   }
 
-  if let Some(read_wl) = matches.remove_many::<String>("deny-read") {
-    flags.deny_read = Some(read_wl.collect());
-  }
+  #[test]
+  fn lint() {
+    let r = flags_from_vec(svec!["deno", "lint", "script_1.ts", "script_2.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string(),],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(write_wl) = matches.remove_many::<String>("allow-write") {
-    flags.allow_write = Some(write_wl.collect());
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--watch",
+      "script_1.ts",
+      "script_2.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Some(Default::default()),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(write_wl) = matches.remove_many::<String>("deny-write") {
-    flags.deny_write = Some(write_wl.collect());
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--watch",
+      "--no-clear-screen",
+      "script_1.ts",
+      "script_2.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Some(WatchFlags {
+            hmr: false,
+            no_clear_screen: true,
+          }),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(net_wl) = matches.remove_many::<String>("allow-net") {
-    let net_allowlist = flags_net::parse(net_wl.collect()).unwrap();
-    flags.allow_net = Some(net_allowlist);
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--fix",
+      "--ignore=script_1.ts,script_2.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec![],
+            ignore: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
+          },
+          fix: LintFixFlags {
+            enabled: true,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--rules"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: true,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(net_wl) = matches.remove_many::<String>("deny-net") {
-    let net_denylist = flags_net::parse(net_wl.collect()).unwrap();
-    flags.deny_net = Some(net_denylist);
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--rules",
+      "--rules-tags=recommended"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: true,
+          maybe_rules_tags: Some(svec!["recommended"]),
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(env_wl) = matches.remove_many::<String>("allow-env") {
-    flags.allow_env = Some(env_wl.collect());
-    debug!("env allowlist: {:#?}", &flags.allow_env);
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--rules-tags=",
+      "--rules-include=ban-untagged-todo,no-undef",
+      "--rules-exclude=no-const-assign"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec![],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: Some(svec![""]),
+          maybe_rules_include: Some(svec!["ban-untagged-todo", "no-undef"]),
+          maybe_rules_exclude: Some(svec!["no-const-assign"]),
+          reporter: LintReporterFlags {
+            json: false,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(env_wl) = matches.remove_many::<String>("deny-env") {
-    flags.deny_env = Some(env_wl.collect());
-    debug!("env denylist: {:#?}", &flags.deny_env);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--strict-rules"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          strict_rules: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(run_wl) = matches.remove_many::<String>("allow-run") {
-    flags.allow_run = Some(run_wl.collect());
-    debug!("run allowlist: {:#?}", &flags.allow_run);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--json", "script_1.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: true,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(run_wl) = matches.remove_many::<String>("deny-run") {
-    flags.deny_run = Some(run_wl.collect());
-    debug!("run denylist: {:#?}", &flags.deny_run);
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--config",
+      "Deno.jsonc",
+      "--json",
+      "script_1.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: true,
+            compact: false,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(sys_wl) = matches.remove_many::<String>("allow-sys") {
-    flags.allow_sys = Some(sys_wl.collect());
-    debug!("sys info allowlist: {:#?}", &flags.allow_sys);
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--config",
+      "Deno.jsonc",
+      "--compact",
+      "script_1.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          fix: LintFixFlags {
+            enabled: false,
+            ..LintFixFlags::default()
+          },
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          reporter: LintReporterFlags {
+            json: false,
+            compact: true,
+            ..LintReporterFlags::default()
+          },
+          watch: Default::default(),
+          ..LintFlags::default()
+        }),
+        config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(sys_wl) = matches.remove_many::<String>("deny-sys") {
-    flags.deny_sys = Some(sys_wl.collect());
-    debug!("sys info denylist: {:#?}", &flags.deny_sys);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--limit=10", "script_1.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          reporter: LintReporterFlags {
+            limit: Some(10),
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(ffi_wl) = matches.remove_many::<String>("allow-ffi") {
-    flags.allow_ffi = Some(ffi_wl.collect());
-    debug!("ffi allowlist: {:#?}", &flags.allow_ffi);
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--db-output=lint.db",
+      "script_1.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          reporter: LintReporterFlags {
+            db_output: Some("lint.db".to_string()),
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if let Some(ffi_wl) = matches.remove_many::<String>("deny-ffi") {
-    flags.deny_ffi = Some(ffi_wl.collect());
-    debug!("ffi denylist: {:#?}", &flags.deny_ffi);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--print-file-patterns"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          print_file_patterns: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if matches.get_flag("allow-hrtime") {
-    flags.allow_hrtime = true;
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--print-config"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          print_config: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if matches.get_flag("deny-hrtime") {
-    flags.deny_hrtime = true;
-  }
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--timeout-per-file=30",
+      "script_1.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          timeout_per_file: Some(30),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if matches.get_flag("allow-all") {
-    flags.allow_all();
-  }
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--timeout-per-file=0", "a.ts"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--compact",
+      "--absolute",
+      "script_1.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          reporter: LintReporterFlags {
+            compact: true,
+            absolute: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  if matches.get_flag("no-prompt") {
-    flags.no_prompt = true;
-  }
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--include-extra=scripts/,tools/**/*.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          include_extra: svec!["scripts/", "tools/**/*.ts"],
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn unsafely_ignore_certificate_errors_parse(
-  flags: &mut Flags,
-  matches: &mut ArgMatches,
-) {
-  if let Some(ic_wl) =
-    matches.remove_many::<String>("unsafely-ignore-certificate-errors")
-  {
-    let ic_allowlist = flags_net::parse(ic_wl.collect()).unwrap();
-    flags.unsafely_ignore_certificate_errors = Some(ic_allowlist);
-  }
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--relative",
+      "--group-by=rule",
+      "--no-snippets",
+      "--no-hints"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            relative: true,
+            group_by_rule: true,
+            no_snippets: true,
+            no_hints: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn runtime_args_parse(
-  flags: &mut Flags,
-  matches: &mut ArgMatches,
-  include_perms: bool,
-  include_inspector: bool,
-) {
-  compile_args_parse(flags, matches);
-  cached_only_arg_parse(flags, matches);
-  if include_perms {
-    permission_args_parse(flags, matches);
-  }
-  if include_inspector {
-    inspect_arg_parse(flags, matches);
-  }
-  location_arg_parse(flags, matches);
-  v8_flags_arg_parse(flags, matches);
-  seed_arg_parse(flags, matches);
-  enable_testing_features_arg_parse(flags, matches);
-  env_file_arg_parse(flags, matches);
-  strace_ops_parse(flags, matches);
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--compact",
+      "--format=relative-paths"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            compact: true,
+            relative: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn inspect_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  let default = || "127.0.0.1:9229".parse::<SocketAddr>().unwrap();
-  flags.inspect = if matches.contains_id("inspect") {
-    Some(
-      matches
-        .remove_one::<SocketAddr>("inspect")
-        .unwrap_or_else(default),
-    )
-  } else {
-    None
-  };
-  flags.inspect_brk = if matches.contains_id("inspect-brk") {
-    Some(
-      matches
-        .remove_one::<SocketAddr>("inspect-brk")
-        .unwrap_or_else(default),
-    )
-  } else {
-    None
-  };
-  flags.inspect_wait = if matches.contains_id("inspect-wait") {
-    Some(
-      matches
-        .remove_one::<SocketAddr>("inspect-wait")
-        .unwrap_or_else(default),
-    )
-  } else {
-    None
-  };
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--format=absolute-paths"]);
+    assert!(r.is_err());
+    // --relative is the short form of --format=relative-paths
+    let r = flags_from_vec(svec!["deno", "lint", "--relative", "--absolute"]);
+    assert!(r.is_err());
 
-fn import_map_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.import_map_path = matches.remove_one::<String>("import-map");
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--watch", "--watch-no-fail"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          watch: Some(Default::default()),
+          watch_no_fail: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn env_file_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.env_file = matches.remove_one::<String>("env");
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--watch-no-fail"]);
+    assert!(r.is_err());
 
-fn reload_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if let Some(cache_bl) = matches.remove_many::<String>("reload") {
-    let raw_cache_blocklist: Vec<String> = cache_bl.collect();
-    if raw_cache_blocklist.is_empty() {
-      flags.reload = true;
-    } else {
-      flags.cache_blocklist = resolve_urls(raw_cache_blocklist);
-      debug!("cache blocklist: {:#?}", &flags.cache_blocklist);
-      flags.reload = false;
-    }
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--warn-changed-files"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          warn_changed_files: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn ca_file_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.ca_data = matches.remove_one::<String>("cert").map(CaData::File);
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--metrics=statsd://localhost:8125"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          metrics: Some("statsd://localhost:8125".to_string()),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn enable_testing_features_arg_parse(
-  flags: &mut Flags,
-  matches: &mut ArgMatches,
-) {
-  if matches.get_flag("enable-testing-features-do-not-use") {
-    flags.enable_testing_features = true
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--edit"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          edit: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn strace_ops_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if let Some(patterns) = matches.remove_many::<String>("strace-ops") {
-    flags.strace_ops = Some(patterns.collect());
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--edit", "--json"]);
+    assert!(r.is_err());
 
-fn cached_only_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if matches.get_flag("cached-only") {
-    flags.cached_only = true;
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--hide-tags=fresh,jsr"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          filter: LintFilterFlags {
+            hide_tags: svec!["fresh", "jsr"],
+            ..LintFilterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn ext_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.ext = matches.remove_one::<String>("ext");
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--eval=const x: any = 1;",
+      "--stdin-ext=tsx"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          eval: Some("const x: any = 1;".to_string()),
+          stdin_ext: Some("tsx".to_string()),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn location_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.location = matches.remove_one::<Url>("location");
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--ext=jsx", "-"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["-".to_string()],
+            ignore: vec![],
+          },
+          stdin_ext: Some("jsx".to_string()),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn v8_flags_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if let Some(v8_flags) = matches.remove_many::<String>("v8-flags") {
-    flags.v8_flags = v8_flags.collect();
-  }
-}
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--eval=const x = 1;", "a.ts"]);
+    assert!(r.is_err());
 
-fn seed_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if let Some(seed) = matches.remove_one::<u64>("seed") {
-    flags.seed = Some(seed);
+    let r = flags_from_vec(svec!["deno", "lint", "--fix-preview"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            preview: true,
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-    flags.v8_flags.push(format!("--random-seed={seed}"));
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--fix-preview", "--fix"]);
+    assert!(r.is_err());
 
-fn no_check_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if let Some(cache_type) = matches.get_one::<String>("no-check") {
-    match cache_type.as_str() {
-      "remote" => flags.type_check_mode = TypeCheckMode::Local,
-      _ => debug!(
-        "invalid value for 'no-check' of '{}' using default",
-        cache_type
-      ),
-    }
-  } else if matches.contains_id("no-check") {
-    flags.type_check_mode = TypeCheckMode::None;
-  }
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--fix",
+      "--fail-on-fix-nonconvergence"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            enabled: true,
+            fail_on_nonconvergence: true,
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn check_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if let Some(cache_type) = matches.get_one::<String>("check") {
-    match cache_type.as_str() {
-      "all" => flags.type_check_mode = TypeCheckMode::All,
-      _ => debug!(
-        "invalid value for 'check' of '{}' using default",
-        cache_type
-      ),
-    }
-  } else if matches.contains_id("check") {
-    flags.type_check_mode = TypeCheckMode::Local;
-  }
-}
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--fail-on-fix-nonconvergence"]);
+    assert!(r.is_err());
 
-fn lock_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  lock_arg_parse(flags, matches);
-  no_lock_arg_parse(flags, matches);
-  if matches.get_flag("lock-write") {
-    flags.lock_write = true;
-  }
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--fix",
+      "--fix-only=prefer-const,no-var"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            enabled: true,
+            only: svec!["prefer-const", "no-var"],
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn lock_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if matches.contains_id("lock") {
-    let lockfile = matches
-      .remove_one::<String>("lock")
-      .unwrap_or_else(|| String::from("./deno.lock"));
-    flags.lock = Some(lockfile);
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--fix-only=prefer-const"]);
+    assert!(r.is_err());
 
-fn no_lock_arg_parse(flags: &mut Flags, matches: &ArgMatches) {
-  if matches.get_flag("no-lock") {
-    flags.no_lock = true;
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--fix", "--dry-run"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            enabled: true,
+            dry_run: true,
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn config_args_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  flags.config_flag = if matches.get_flag("no-config") {
-    ConfigFlag::Disabled
-  } else if let Some(config) = matches.remove_one::<String>("config") {
-    ConfigFlag::Path(config)
-  } else {
-    ConfigFlag::Discover
-  };
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--dry-run"]);
+    assert!(r.is_err());
 
-fn no_remote_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if matches.get_flag("no-remote") {
-    flags.no_remote = true;
-  }
-}
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--fix", "--fix-max-iterations=0"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            enabled: true,
+            max_iterations: Some(0),
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn no_npm_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
-  if matches.get_flag("no-npm") {
-    flags.no_npm = true;
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--fix-max-iterations=10"]);
+    assert!(r.is_err());
 
-fn node_modules_and_vendor_dir_arg_parse(
-  flags: &mut Flags,
-  matches: &mut ArgMatches,
-) {
-  flags.node_modules_dir = matches.remove_one::<bool>("node-modules-dir");
-  flags.vendor = matches.remove_one::<bool>("vendor");
-}
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--auto-fix-safe-then-report"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            safe_then_report: true,
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn reload_arg_validate(urlstr: &str) -> Result<String, String> {
-  if urlstr.is_empty() {
-    return Err(String::from("Missing url. Check for extra commas."));
-  }
-  match Url::from_str(urlstr) {
-    Ok(_) => Ok(urlstr.to_string()),
-    Err(e) => Err(e.to_string()),
-  }
-}
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--auto-fix-safe-then-report",
+      "--fix-preview"
+    ]);
+    assert!(r.is_err());
 
-fn watch_arg_parse(matches: &mut ArgMatches) -> Option<WatchFlags> {
-  if matches.get_flag("watch") {
-    Some(WatchFlags {
-      hmr: false,
-      no_clear_screen: matches.get_flag("no-clear-screen"),
-    })
-  } else {
-    None
-  }
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--max-depth=2"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          filter: LintFilterFlags {
+            max_depth: Some(2),
+            ..LintFilterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-fn watch_arg_parse_with_paths(
-  matches: &mut ArgMatches,
-) -> Option<WatchFlagsWithPaths> {
-  if let Some(paths) = matches.remove_many::<String>("watch") {
-    return Some(WatchFlagsWithPaths {
-      paths: paths.collect(),
-      hmr: false,
-      no_clear_screen: matches.get_flag("no-clear-screen"),
-    });
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--fail-on-regressions"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fail_on_regressions: true,
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  matches
-    .remove_many::<String>("hmr")
-    .map(|paths| WatchFlagsWithPaths {
-      paths: paths.collect(),
-      hmr: true,
-      no_clear_screen: matches.get_flag("no-clear-screen"),
-    })
-}
+    let r = flags_from_vec(svec!["deno", "lint", "--verbose"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            verbose: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-// TODO(ry) move this to utility module and add test.
-/// Strips fragment part of URL. Panics on bad URL.
-pub fn resolve_urls(urls: Vec<String>) -> Vec<String> {
-  let mut out: Vec<String> = vec![];
-  for urlstr in urls.iter() {
-    if let Ok(mut url) = Url::from_str(urlstr) {
-      url.set_fragment(None);
-      let mut full_url = String::from(url.as_str());
-      if full_url.len() > 1 && full_url.ends_with('/') {
-        full_url.pop();
+    let r = flags_from_vec(svec!["deno", "lint", "--timing"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            timing: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
       }
-      out.push(full_url);
-    } else {
-      panic!("Bad Url: {urlstr}");
-    }
-  }
-  out
-}
+    );
 
-#[cfg(test)]
-mod tests {
-  use super::*;
-  use pretty_assertions::assert_eq;
+    let r = flags_from_vec(svec!["deno", "lint", "--concurrency=1"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          concurrency: Some(NonZeroUsize::new(1).unwrap()),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-  /// Creates vector of strings, Vec<String>
-  macro_rules! svec {
-    ($($x:expr),* $(,)?) => (vec![$($x.to_string().into()),*]);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--concurrency=0"]);
+    assert!(r.is_err());
 
-  #[test]
-  fn global_flags() {
-    #[rustfmt::skip]
-    let r = flags_from_vec(svec!["deno", "--unstable", "--log-level", "debug", "--quiet", "run", "script.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--max-warnings=10"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          max_warnings: Some(10),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--no-error-on-issues"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string()
-        )),
-        unstable_config: UnstableConfig {
-          legacy_flag_enabled: true,
-          ..Default::default()
-        },
-        log_level: Some(Level::Error),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          no_error_on_issues: true,
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-    #[rustfmt::skip]
-    let r2 = flags_from_vec(svec!["deno", "run", "--unstable", "--log-level", "debug", "--quiet", "script.ts"]);
-    let flags2 = r2.unwrap();
-    assert_eq!(flags2, flags);
-  }
 
-  #[test]
-  fn upgrade() {
-    let r = flags_from_vec(svec!["deno", "upgrade", "--dry-run", "--force"]);
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--max-warnings-delta=5"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
-          force: true,
-          dry_run: true,
-          canary: false,
-          version: None,
-          output: None,
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          max_warnings_delta: Some(5),
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn upgrade_with_output_flag() {
-    let r = flags_from_vec(svec!["deno", "upgrade", "--output", "example.txt"]);
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--max-warnings-delta=5",
+      "--update-problem-count"
+    ]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
-          force: false,
-          dry_run: false,
-          canary: false,
-          version: None,
-          output: Some(String::from("example.txt")),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          max_warnings_delta: Some(5),
+          update_problem_count: true,
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn version() {
-    let r = flags_from_vec(svec!["deno", "--version"]);
-    assert_eq!(
-      r.unwrap_err().kind(),
-      clap::error::ErrorKind::DisplayVersion
-    );
-    let r = flags_from_vec(svec!["deno", "-V"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--update-problem-count"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--sidecar"]);
     assert_eq!(
-      r.unwrap_err().kind(),
-      clap::error::ErrorKind::DisplayVersion
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            sidecar: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
     );
-  }
 
-  #[test]
-  fn run_reload() {
-    let r = flags_from_vec(svec!["deno", "run", "-r", "script.ts"]);
-    let flags = r.unwrap();
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--file-results=results.json"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string()
-        )),
-        reload: true,
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            file_results: Some("results.json".to_string()),
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn run_watch() {
-    let r = flags_from_vec(svec!["deno", "run", "--watch", "script.ts"]);
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--cache-namespace=main"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "script.ts".to_string(),
-          watch: Some(WatchFlagsWithPaths {
-            hmr: false,
-            paths: vec![],
-            no_clear_screen: false,
-          }),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          cache: LintCacheFlags {
+            namespace: Some("main".to_string()),
+            ..LintCacheFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "run",
-      "--watch",
-      "--no-clear-screen",
-      "script.ts"
-    ]);
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--cache-path=lint.db"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "script.ts".to_string(),
-          watch: Some(WatchFlagsWithPaths {
-            hmr: false,
-            paths: vec![],
-            no_clear_screen: true,
-          }),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          cache: LintCacheFlags {
+            path: Some("lint.db".to_string()),
+            ..LintCacheFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "run",
-      "--unstable-hmr",
-      "--no-clear-screen",
-      "script.ts"
-    ]);
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--enclosing-symbol"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "script.ts".to_string(),
-          watch: Some(WatchFlagsWithPaths {
-            hmr: true,
-            paths: vec![],
-            no_clear_screen: true,
-          }),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            enclosing_symbol: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "run",
-      "--unstable-hmr=foo.txt",
-      "--no-clear-screen",
-      "script.ts"
-    ]);
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--interactive"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "script.ts".to_string(),
-          watch: Some(WatchFlagsWithPaths {
-            hmr: true,
-            paths: vec![String::from("foo.txt")],
-            no_clear_screen: true,
-          }),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          fix: LintFixFlags {
+            interactive: true,
+            ..LintFixFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r =
-      flags_from_vec(svec!["deno", "run", "--hmr", "--watch", "script.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--interactive", "--json"]);
     assert!(r.is_err());
-  }
 
-  #[test]
-  fn run_watch_with_external() {
-    let r =
-      flags_from_vec(svec!["deno", "run", "--watch=file1,file2", "script.ts"]);
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--json-workspace"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "script.ts".to_string(),
-          watch: Some(WatchFlagsWithPaths {
-            hmr: false,
-            paths: vec![String::from("file1"), String::from("file2")],
-            no_clear_screen: false,
-          }),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            json_workspace: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
-  }
-
-  #[test]
-  fn run_watch_with_no_clear_screen() {
-    let r = flags_from_vec(svec![
-      "deno",
-      "run",
-      "--watch",
-      "--no-clear-screen",
-      "script.ts"
-    ]);
 
-    let flags = r.unwrap();
+    let r = flags_from_vec(svec!["deno", "lint", "--ndjson"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "script.ts".to_string(),
-          watch: Some(WatchFlagsWithPaths {
-            hmr: false,
-            paths: vec![],
-            no_clear_screen: true,
-          }),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            ndjson: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn run_reload_allow_write() {
-    let r =
-      flags_from_vec(svec!["deno", "run", "-r", "--allow-write", "script.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--ndjson", "--json"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--checkstyle"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        reload: true,
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string()
-        )),
-        allow_write: Some(vec![]),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            checkstyle: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn run_v8_flags() {
-    let r = flags_from_vec(svec!["deno", "run", "--v8-flags=--help"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--checkstyle", "--compact"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--tap"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default("_".to_string())),
-        v8_flags: svec!["--help"],
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            tap: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "run",
-      "--v8-flags=--expose-gc,--gc-stats=1",
-      "script.ts"
-    ]);
+    let r = flags_from_vec(svec!["deno", "lint", "--tap", "--checkstyle"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--by-rule"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string()
-        )),
-        v8_flags: svec!["--expose-gc", "--gc-stats=1"],
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            by_rule: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "run", "--v8-flags=--expose-gc"]);
-    assert!(r
-      .unwrap_err()
-      .to_string()
-      .contains("[SCRIPT_ARG] may only be omitted with --v8-flags=--help"));
-  }
-
-  #[test]
-  fn has_permission() {
-    let r = flags_from_vec(svec!["deno", "run", "--allow-read", "x.ts"]);
-    assert_eq!(r.unwrap().has_permission(), true);
-
-    let r = flags_from_vec(svec!["deno", "run", "--deny-read", "x.ts"]);
-    assert_eq!(r.unwrap().has_permission(), true);
-
-    let r = flags_from_vec(svec!["deno", "run", "x.ts"]);
-    assert_eq!(r.unwrap().has_permission(), false);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--by-rule", "--json"]);
+    assert!(r.is_err());
 
-  #[test]
-  fn has_permission_in_argv() {
-    let r = flags_from_vec(svec!["deno", "run", "x.ts", "--allow-read"]);
-    assert_eq!(r.unwrap().has_permission_in_argv(), true);
+    let r = flags_from_vec(svec!["deno", "lint", "--no-tests"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          filter: LintFilterFlags {
+            no_tests: true,
+            ..LintFilterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
 
-    let r = flags_from_vec(svec!["deno", "run", "x.ts", "--deny-read"]);
-    assert_eq!(r.unwrap().has_permission_in_argv(), true);
+    let r = flags_from_vec(svec!["deno", "lint", "--no-tests", "--tests-only"]);
+    assert!(r.is_err());
 
-    let r = flags_from_vec(svec!["deno", "run", "x.ts"]);
-    assert_eq!(r.unwrap().has_permission_in_argv(), false);
-  }
+    let r = flags_from_vec(svec!["deno", "lint", "--json", "--json-sort=code"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            json: true,
+            json_sort: Some("code".to_string()),
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--json-sort=name"]);
+    assert!(r.is_err());
 
-  #[test]
-  fn script_args() {
     let r = flags_from_vec(svec![
       "deno",
-      "run",
-      "--allow-net",
-      "gist.ts",
-      "--title",
-      "X"
+      "lint",
+      "--generated=gen/,src/**/*.pb.ts",
+      "--skip-generated"
     ]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "gist.ts".to_string()
-        )),
-        argv: svec!["--title", "X"],
-        allow_net: Some(vec![]),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          filter: LintFilterFlags {
+            generated: svec!["gen/", "src/**/*.pb.ts"],
+            skip_generated: true,
+            ..LintFilterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn allow_all() {
-    let r = flags_from_vec(svec!["deno", "run", "--allow-all", "gist.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--skip-generated"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--compare=old.json"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "gist.ts".to_string()
-        )),
-        allow_all: true,
-        allow_net: Some(vec![]),
-        allow_env: Some(vec![]),
-        allow_run: Some(vec![]),
-        allow_read: Some(vec![]),
-        allow_sys: Some(vec![]),
-        allow_write: Some(vec![]),
-        allow_ffi: Some(vec![]),
-        allow_hrtime: true,
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          compare: Some("old.json".to_string()),
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn allow_read() {
-    let r = flags_from_vec(svec!["deno", "run", "--allow-read", "gist.ts"]);
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--baseline=.lint-baseline.json",
+      "--update-baseline"
+    ]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "gist.ts".to_string()
-        )),
-        allow_read: Some(vec![]),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          baseline: Some(".lint-baseline.json".to_string()),
+          update_baseline: true,
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn deny_read() {
-    let r = flags_from_vec(svec!["deno", "run", "--deny-read", "gist.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--update-baseline"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--print-json-schema"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "gist.ts".to_string()
-        )),
-        deny_read: Some(vec![]),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          print_json_schema: true,
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn allow_hrtime() {
-    let r = flags_from_vec(svec!["deno", "run", "--allow-hrtime", "gist.ts"]);
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--compact", "--compact-ranges"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "gist.ts".to_string(),
-        )),
-        allow_hrtime: true,
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            compact: true,
+            compact_ranges: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn deny_hrtime() {
-    let r = flags_from_vec(svec!["deno", "run", "--deny-hrtime", "gist.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--only=no-debugger"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "gist.ts".to_string(),
-        )),
-        deny_hrtime: true,
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          only: Some("no-debugger".to_string()),
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn double_hyphen() {
-    // notice that flags passed after double dash will not
-    // be parsed to Flags but instead forwarded to
-    // script args as Deno.args
     let r = flags_from_vec(svec![
       "deno",
-      "run",
-      "--allow-write",
-      "script.ts",
-      "--",
-      "-D",
-      "--allow-net"
+      "lint",
+      "--only=no-debugger",
+      "--rules-include=no-eval"
     ]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--success-marker=.lint-ok"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string(),
-        )),
-        argv: svec!["--", "-D", "--allow-net"],
-        allow_write: Some(vec![]),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          success_marker: Some(".lint-ok".to_string()),
+          ..LintFlags::default()
+        }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn fmt() {
-    let r = flags_from_vec(svec!["deno", "fmt", "script_1.ts", "script_2.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--explain=no-window-prefix"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
-            ignore: vec![],
-          },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Default::default(),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          explain: Some("no-window-prefix".to_string()),
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "fmt", "--check"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--rules-hash"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: true,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
-          },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Default::default(),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          rules_hash: Some(None),
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "fmt"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--rules-hash=abc"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
-          },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Default::default(),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          rules_hash: Some(Some("abc".to_string())),
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "fmt", "--watch"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--skip-unreadable"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
-          },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Some(Default::default()),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          skip_unreadable: true,
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
     let r =
-      flags_from_vec(svec!["deno", "fmt", "--watch", "--no-clear-screen"]);
+      flags_from_vec(svec!["deno", "lint", "--protobuf", "--output=lint.pb"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            protobuf: true,
+            output: Some("lint.pb".to_string()),
+            ..LintReporterFlags::default()
           },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Some(WatchFlags {
-            hmr: false,
-            no_clear_screen: true,
-          })
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "fmt",
-      "--check",
-      "--watch",
-      "foo.ts",
-      "--ignore=bar.js"
-    ]);
+    let r = flags_from_vec(svec!["deno", "lint", "--output=lint.pb"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "lint", "--protobuf", "--json"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--compact-after=20"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: true,
-          files: FileFlags {
-            include: vec!["foo.ts".to_string()],
-            ignore: vec!["bar.js".to_string()],
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            compact_after: Some(20),
+            ..LintReporterFlags::default()
           },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Some(Default::default()),
+          ..LintFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--compact-after=20", "--json"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--stats"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          reporter: LintReporterFlags {
+            stats: true,
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "fmt", "--config", "deno.jsonc"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--stats", "--json"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--normalize-eol"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
-          },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Default::default(),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          normalize_eol: true,
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
-        config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "fmt",
-      "--config",
-      "deno.jsonc",
-      "--watch",
-      "foo.ts"
-    ]);
+    let r = flags_from_vec(svec!["deno", "lint", "--rule-pack=pack.json"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec!["foo.ts".to_string()],
-            ignore: vec![],
-          },
-          use_tabs: None,
-          line_width: None,
-          indent_width: None,
-          single_quote: None,
-          prose_wrap: None,
-          no_semicolons: None,
-          watch: Some(Default::default()),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          rule_pack: Some("pack.json".to_string()),
+          ..LintFlags::default()
         }),
-        config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "fmt",
-      "--use-tabs",
-      "--line-width",
-      "60",
-      "--indent-width",
-      "4",
-      "--single-quote",
-      "--prose-wrap",
-      "never",
-      "--no-semicolons",
-    ]);
+    let r = flags_from_vec(svec!["deno", "lint", "--patch=changes.diff"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          filter: LintFilterFlags {
+            patch: Some("changes.diff".to_string()),
+            ..LintFilterFlags::default()
           },
-          use_tabs: Some(true),
-          line_width: Some(NonZeroU32::new(60).unwrap()),
-          indent_width: Some(NonZeroU8::new(4).unwrap()),
-          single_quote: Some(true),
-          prose_wrap: Some("never".to_string()),
-          no_semicolons: Some(true),
-          watch: Default::default(),
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
 
-    // try providing =false to the booleans
     let r = flags_from_vec(svec![
       "deno",
-      "fmt",
-      "--use-tabs=false",
-      "--single-quote=false",
-      "--no-semicolons=false",
+      "lint",
+      "--inbox=incoming",
+      "--inbox-done=delete"
     ]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Fmt(FmtFlags {
-          check: false,
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
-          },
-          use_tabs: Some(false),
-          line_width: None,
-          indent_width: None,
-          single_quote: Some(false),
-          prose_wrap: None,
-          no_semicolons: Some(false),
-          watch: Default::default(),
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          inbox: Some("incoming".to_string()),
+          inbox_done: Some("delete".to_string()),
+          ..LintFlags::default()
         }),
-        ext: Some("ts".to_string()),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn lint() {
-    let r = flags_from_vec(svec!["deno", "lint", "script_1.ts", "script_2.ts"]);
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--inbox=incoming",
+      "--inbox-delete"
+    ]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string(),],
-            ignore: vec![],
-          },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: false,
-          watch: Default::default(),
+          inbox: Some("incoming".to_string()),
+          inbox_delete: true,
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
+    let r = flags_from_vec(svec!["deno", "lint", "--inbox-done=validated"]);
+    assert!(r.is_err());
     let r = flags_from_vec(svec![
       "deno",
       "lint",
-      "--watch",
-      "script_1.ts",
-      "script_2.ts"
+      "--inbox=incoming",
+      "--inbox-done=validated",
+      "--inbox-delete"
     ]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--stdin-file-list"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
-            ignore: vec![],
-          },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: false,
-          watch: Some(Default::default()),
+          stdin_file_list: true,
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "lint",
-      "--watch",
-      "--no-clear-screen",
-      "script_1.ts",
-      "script_2.ts"
-    ]);
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--stdin-file-list", "main.ts"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--unstable-md"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
-            ignore: vec![],
-          },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: false,
-          watch: Some(WatchFlags {
-            hmr: false,
-            no_clear_screen: true,
-          })
+          unstable_md: true,
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--inbox=incoming", "script.ts"]);
+    assert!(r.is_err());
+
     let r = flags_from_vec(svec![
       "deno",
       "lint",
-      "--fix",
-      "--ignore=script_1.ts,script_2.ts"
+      "--rule-pack=pack.json",
+      "--only=no-debugger"
     ]);
+    assert!(r.is_err());
+
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--svg-dir=frames", "script_1.ts"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
           files: FileFlags {
-            include: vec![],
-            ignore: vec!["script_1.ts".to_string(), "script_2.ts".to_string()],
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
           },
-          fix: true,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: false,
-          watch: Default::default(),
+          reporter: LintReporterFlags {
+            svg_dir: Some("frames".to_string()),
+            ..LintReporterFlags::default()
+          },
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "lint", "--rules"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--require-coverage"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
-          },
-          fix: false,
-          rules: true,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: false,
-          watch: Default::default(),
+          require_coverage: true,
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
@@ -5150,136 +7522,81 @@ mod tests {
     let r = flags_from_vec(svec![
       "deno",
       "lint",
-      "--rules",
-      "--rules-tags=recommended"
+      "--snippet-underline=~",
+      "--snippet-frame=unicode"
     ]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
+          reporter: LintReporterFlags {
+            snippet_underline: Some('~'),
+            unicode_frame: true,
+            ..LintReporterFlags::default()
           },
-          fix: false,
-          rules: true,
-          maybe_rules_tags: Some(svec!["recommended"]),
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: false,
-          watch: Default::default(),
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "lint",
-      "--rules-tags=",
-      "--rules-include=ban-untagged-todo,no-undef",
-      "--rules-exclude=no-const-assign"
-    ]);
+    let r = flags_from_vec(svec!["deno", "lint", "--hyperlinks"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec![],
-            ignore: vec![],
+          reporter: LintReporterFlags {
+            hyperlinks: true,
+            ..LintReporterFlags::default()
           },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: Some(svec![""]),
-          maybe_rules_include: Some(svec!["ban-untagged-todo", "no-undef"]),
-          maybe_rules_exclude: Some(svec!["no-const-assign"]),
-          json: false,
-          compact: false,
-          watch: Default::default(),
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec!["deno", "lint", "--json", "script_1.ts"]);
+    let r = flags_from_vec(svec!["deno", "lint", "--one-per-file"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string()],
-            ignore: vec![],
+          reporter: LintReporterFlags {
+            one_per_file: true,
+            ..LintReporterFlags::default()
           },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: true,
-          compact: false,
-          watch: Default::default(),
+          ..LintFlags::default()
         }),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "lint",
-      "--config",
-      "Deno.jsonc",
-      "--json",
-      "script_1.ts"
-    ]);
+    let r = flags_from_vec(svec!["deno", "lint", "--blame"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string()],
-            ignore: vec![],
+          reporter: LintReporterFlags {
+            blame: true,
+            ..LintReporterFlags::default()
           },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: true,
-          compact: false,
-          watch: Default::default(),
+          ..LintFlags::default()
         }),
-        config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
         ..Flags::default()
       }
     );
 
-    let r = flags_from_vec(svec![
-      "deno",
-      "lint",
-      "--config",
-      "Deno.jsonc",
-      "--compact",
-      "script_1.ts"
-    ]);
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--file-problem-threshold=100"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Lint(LintFlags {
-          files: FileFlags {
-            include: vec!["script_1.ts".to_string()],
-            ignore: vec![],
+          reporter: LintReporterFlags {
+            file_problem_threshold: Some(100),
+            ..LintReporterFlags::default()
           },
-          fix: false,
-          rules: false,
-          maybe_rules_tags: None,
-          maybe_rules_include: None,
-          maybe_rules_exclude: None,
-          json: false,
-          compact: true,
-          watch: Default::default(),
+          ..LintFlags::default()
         }),
-        config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
         ..Flags::default()
       }
     );
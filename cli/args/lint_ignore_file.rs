@@ -0,0 +1,79 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::path::Path;
+
+use deno_config::glob::PathOrPatternSet;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+
+/// Name of the file at the root of the project with the globs of the files
+/// that aren't linted, one per line like in a `.gitignore`.
+pub const LINT_IGNORE_FILE_NAME: &str = ".denolintignore";
+
+/// Loads the exclude patterns of the `.denolintignore` file in the directory,
+/// which are relative to it, or `None` when there's no such file.
+pub fn load_lint_ignore_file(
+  dir: &Path,
+) -> Result<Option<PathOrPatternSet>, AnyError> {
+  let path = dir.join(LINT_IGNORE_FILE_NAME);
+  let text = match std::fs::read_to_string(&path) {
+    Ok(text) => text,
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+    Err(err) => {
+      return Err(err)
+        .with_context(|| format!("Failed reading '{}'.", path.display()))
+    }
+  };
+  let patterns = PathOrPatternSet::from_exclude_relative_path_or_patterns(
+    dir,
+    &parse_lint_ignore_file(&text),
+  )
+  .with_context(|| format!("Invalid pattern in '{}'.", path.display()))?;
+  Ok(Some(patterns))
+}
+
+/// Converts the lines of an ignore file to exclude patterns. Like in a
+/// `.gitignore`, a pattern without a slash other than a trailing one matches
+/// at any depth, a leading slash anchors it to the directory of the file and
+/// a leading `!` includes the files that an earlier pattern excludes.
+/// Empty lines and `#` comments are skipped.
+fn parse_lint_ignore_file(text: &str) -> Vec<String> {
+  text
+    .lines()
+    .map(|line| line.trim())
+    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    .map(|line| {
+      let (negation, pattern) = match line.strip_prefix('!') {
+        Some(pattern) => ("!", pattern),
+        None => ("", line),
+      };
+      let pattern = pattern.trim_end_matches('/');
+      match pattern.strip_prefix('/') {
+        Some(pattern) => format!("{}{}", negation, pattern),
+        None if pattern.contains('/') => format!("{}{}", negation, pattern),
+        None => format!("{}**/{}", negation, pattern),
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parses_lint_ignore_file() {
+    let text = "\
+# generated code
+/gen/
+*.pb.ts
+
+src/vendor
+!/gen/keep.ts
+";
+    assert_eq!(
+      parse_lint_ignore_file(text),
+      vec!["gen", "**/*.pb.ts", "src/vendor", "!gen/keep.ts"]
+    );
+  }
+}
@@ -0,0 +1,181 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+
+/// The 1-indexed lines that a patch adds or changes, by resolved file path.
+pub type ChangedLines = HashMap<PathBuf, Vec<RangeInclusive<usize>>>;
+
+/// Loads the lines that the unified diff of `--patch` adds or changes. The
+/// paths of the diff are relative to the directory it was made in, which is
+/// expected to be the current directory.
+pub fn load_patch(
+  path: &Path,
+  initial_cwd: &Path,
+) -> Result<ChangedLines, AnyError> {
+  let text = std::fs::read_to_string(path)
+    .with_context(|| format!("Failed reading patch '{}'.", path.display()))?;
+  let files = parse_patch(&text)
+    .with_context(|| format!("Failed parsing patch '{}'.", path.display()))?;
+  let mut changed_lines = ChangedLines::new();
+  for (file, lines) in files {
+    changed_lines
+      .entry(deno_core::normalize_path(initial_cwd.join(file)))
+      .or_default()
+      .extend(lines);
+  }
+  Ok(changed_lines)
+}
+
+/// Gets the path of a `--- ` or `+++ ` header line, without the `a/` or `b/`
+/// prefix of git diffs and the timestamp of `diff -u`, or `None` for
+/// `/dev/null`.
+fn header_path(header: &str) -> Option<&str> {
+  let path = header.split('\t').next().unwrap_or(header).trim_end();
+  if path == "/dev/null" {
+    return None;
+  }
+  Some(
+    path
+      .strip_prefix("a/")
+      .or_else(|| path.strip_prefix("b/"))
+      .unwrap_or(path),
+  )
+}
+
+/// Parses the counts of a `-start,count` or `+start,count` hunk range, where
+/// the count defaults to 1.
+fn parse_hunk_range(range: &str) -> Option<(usize, usize)> {
+  let range = range.get(1..)?;
+  match range.split_once(',') {
+    Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+    None => Some((range.parse().ok()?, 1)),
+  }
+}
+
+/// Gets the lines that the diff adds or changes in each file it doesn't
+/// delete. The whole of a new file is changed.
+fn parse_patch(
+  text: &str,
+) -> Result<Vec<(String, Vec<RangeInclusive<usize>>)>, AnyError> {
+  let mut files: Vec<(String, Vec<RangeInclusive<usize>>)> = Vec::new();
+  let mut is_new_file = false;
+  // the file of the hunks, which is `None` when it's deleted
+  let mut file_index = None;
+  let mut lines = text.lines().enumerate();
+  while let Some((index, line)) = lines.next() {
+    if let Some(header) = line.strip_prefix("--- ") {
+      is_new_file = header_path(header).is_none();
+    } else if let Some(header) = line.strip_prefix("+++ ") {
+      file_index = header_path(header).map(|path| {
+        let changed = if is_new_file {
+          vec![1..=usize::MAX]
+        } else {
+          Vec::new()
+        };
+        files.push((path.to_string(), changed));
+        files.len() - 1
+      });
+    } else if let Some(hunk) = line.strip_prefix("@@ ") {
+      let mut ranges = hunk.split(' ');
+      let old = ranges.next().and_then(parse_hunk_range);
+      let new = ranges.next().and_then(parse_hunk_range);
+      let (Some((_, mut old_count)), Some((mut line_number, mut new_count))) =
+        (old, new)
+      else {
+        bail!("Invalid hunk header on line {}: {}", index + 1, line);
+      };
+      // the body is read by its counts, as removed lines can look like
+      // headers
+      while old_count > 0 || new_count > 0 {
+        let Some((_, body_line)) = lines.next() else {
+          bail!("Unexpected end of the hunk on line {}.", index + 1);
+        };
+        match body_line.chars().next() {
+          Some('+') => {
+            if let Some(file_index) = file_index {
+              let changed = &mut files[file_index].1;
+              // a new file's whole range already covers its lines
+              match changed.last_mut() {
+                Some(last) if last.end().saturating_add(1) >= line_number => {
+                  *last = *last.start()..=line_number.max(*last.end());
+                }
+                _ => changed.push(line_number..=line_number),
+              }
+            }
+            line_number += 1;
+            new_count = new_count.saturating_sub(1);
+          }
+          Some('-') => old_count = old_count.saturating_sub(1),
+          // a note like "\ No newline at end of file"
+          Some('\\') => {}
+          // context lines, which are empty when trailing whitespace is trimmed
+          _ => {
+            line_number += 1;
+            old_count = old_count.saturating_sub(1);
+            new_count = new_count.saturating_sub(1);
+          }
+        }
+      }
+    }
+  }
+  Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parses_patch() {
+    let patch = "\
+diff --git a/src/main.ts b/src/main.ts
+index 1111111..2222222 100644
+--- a/src/main.ts
++++ b/src/main.ts
+@@ -1,4 +1,5 @@
+ import { a } from \"./a.ts\";
+--- removed line that looks like a header
++const b = 1;
++const c = 2;
+ a();
+
+@@ -10,2 +11,2 @@ function f() {
+-  return 1;
++  return 2;
+ }
+diff --git a/new.ts b/new.ts
+new file mode 100644
+--- /dev/null
++++ b/new.ts
+@@ -0,0 +1 @@
++debugger;
+diff --git a/old.ts b/old.ts
+deleted file mode 100644
+--- a/old.ts
++++ /dev/null
+@@ -1 +0,0 @@
+-debugger;
+\\ No newline at end of file
+";
+    assert_eq!(
+      parse_patch(patch).unwrap(),
+      vec![
+        ("src/main.ts".to_string(), vec![2..=3, 11..=11]),
+        ("new.ts".to_string(), vec![1..=usize::MAX]),
+      ]
+    );
+
+    let err = parse_patch("--- a/a.ts\n+++ b/a.ts\n@@ -1 +x @@\n").unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Invalid hunk header on line 3: @@ -1 +x @@"
+    );
+  }
+}
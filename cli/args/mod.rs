@@ -4,11 +4,18 @@ pub mod deno_json;
 mod flags;
 mod flags_net;
 mod import_map;
+mod lint_ignore_file;
+mod lint_patch;
 mod lockfile;
 pub mod package_json;
+mod response_file;
+mod rule_pack;
 
 pub use self::import_map::resolve_import_map;
+use self::lint_ignore_file::load_lint_ignore_file;
+pub use self::lint_patch::ChangedLines;
 use self::package_json::PackageJsonDeps;
+use self::rule_pack::RulePack;
 use ::import_map::ImportMap;
 use deno_core::resolve_url_or_path;
 use deno_npm::resolution::ValidSerializedNpmResolutionSnapshot;
@@ -56,20 +63,24 @@ use once_cell::sync::OnceCell;
 use serde::Deserialize;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::env;
 use std::io::BufReader;
 use std::io::Cursor;
 use std::net::SocketAddr;
 use std::num::NonZeroUsize;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 use crate::file_fetcher::FileFetcher;
 use crate::util::fs::canonicalize_path_maybe_not_exists;
 use crate::version;
 
+use deno_config::glob::PathOrPattern;
 use deno_config::glob::PathOrPatternSet;
 use deno_config::FmtConfig;
 use deno_config::LintConfig;
@@ -378,29 +389,339 @@ impl TestOptions {
   }
 }
 
-#[derive(Clone, Default, Debug)]
+/// Whether the files with a conventional test file name are linted.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum LintTestFiles {
+  #[default]
+  Include,
+  Exclude,
+  Only,
+}
+
+/// Files that are tagged as generated code and how they're linted.
+#[derive(Clone, Debug)]
+pub struct LintGeneratedFiles {
+  pub files: PathOrPatternSet,
+  /// The globs as they were provided, which are part of the incremental
+  /// cache state so that changing them lints the cached files again.
+  pub globs: Vec<String>,
+  /// Skip the files rather than linting them with the "generated" preset.
+  pub skip: bool,
+}
+
+/// What's done with a file of the `--inbox` directory once it's linted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintInboxDone {
+  /// The file is left in the inbox.
+  Keep,
+  /// The file is deleted, while its result is kept.
+  Delete,
+  /// The file is moved to this directory, together with its result.
+  Move(PathBuf),
+}
+
+/// Directory that's watched for new files to lint, for pipelines that drop
+/// the files to validate in it.
+#[derive(Clone, Debug)]
+pub struct LintInbox {
+  pub dir: PathBuf,
+  pub done: LintInboxDone,
+}
+
+/// Order of the diagnostics in the JSON output. Every order is total, so
+/// the output is the same between runs.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum LintJsonSort {
+  /// By file, then line and column.
+  #[default]
+  Position,
+  /// By rule code, then position.
+  Code,
+  /// Errors before warnings, then position.
+  Severity,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub enum LintReporterKind {
   #[default]
   Pretty,
   Json,
+  /// JSON with the diagnostics nested under the workspace members.
+  JsonWorkspace,
+  /// JSON Lines, with a diagnostic or error object per line that's printed
+  /// as soon as it's found.
+  NdJson,
+  /// A Checkstyle XML report.
+  Checkstyle,
+  /// A TAP version 13 report with a test point per file.
+  Tap,
+  /// The locations of the problems grouped by rule.
+  ByRule,
   Compact,
+  /// GitHub Actions workflow commands, which annotate the changed files.
+  GithubActions,
+  /// A GitLab Code Quality report.
+  Gitlab,
+  /// Per rule counts of the problems `--fix` would fix.
+  FixPreview,
+  /// Length-delimited Protocol Buffers messages.
+  Protobuf,
+}
+
+/// Options of the pretty lint reporter. They're independent of each other
+/// so they can be freely combined.
+#[derive(Clone, Debug)]
+pub struct PrettyLintOptions {
+  /// Maximum number of diagnostics displayed.
+  pub limit: Option<usize>,
+  /// Display the diagnostics grouped by rule rather than in lint order, the
+  /// rules with the most problems first.
+  pub group_by_rule: bool,
+  /// Display only the location of each diagnostic, for `--by-rule`.
+  pub locations_only: bool,
+  pub show_snippets: bool,
+  pub show_hints: bool,
+  /// Character underlining the problems in the snippets rather than `^`.
+  pub underline_char: Option<char>,
+  /// Draw the frame of the snippets with box-drawing characters.
+  pub unicode_frame: bool,
+  /// Link the location of the diagnostics to their file with OSC 8
+  /// hyperlinks.
+  pub hyperlinks: bool,
+  /// Display the diagnostics after this many as compact one-liners.
+  pub compact_after: Option<usize>,
+  /// Point out in the summary when there are more problems than this.
+  pub max_warnings: Option<usize>,
+  /// Print the number of problems of each rule after the summary.
+  pub rule_stats: bool,
+}
+
+impl Default for PrettyLintOptions {
+  fn default() -> Self {
+    Self {
+      limit: None,
+      group_by_rule: false,
+      locations_only: false,
+      show_snippets: true,
+      show_hints: true,
+      underline_char: None,
+      unicode_frame: false,
+      hyperlinks: false,
+      compact_after: None,
+      max_warnings: None,
+      rule_stats: false,
+    }
+  }
+}
+
+/// Options of how the problems are reported and where they're written.
+#[derive(Clone, Debug, Default)]
+pub struct LintReporterOptions {
+  pub kind: LintReporterKind,
+  /// Print absolute paths rather than file URLs in compact and JSON output.
+  pub absolute_paths: bool,
+  /// Display file paths relative to this directory in pretty, compact and
+  /// JSON output.
+  pub relative_to: Option<PathBuf>,
+  /// Print the end position of the problems in compact output.
+  pub compact_ranges: bool,
+  /// File to write the protobuf output to rather than stdout.
+  pub output: Option<PathBuf>,
+  pub pretty: PrettyLintOptions,
+  pub json_sort: LintJsonSort,
+  /// Report the name of the declaration each diagnostic is in.
+  pub enclosing_symbol: bool,
+  /// Report only the first diagnostic of each file, with the number of the
+  /// other ones appended to its message.
+  pub one_per_file: bool,
+  /// Group the diagnostics by the author of their line with `git blame`.
+  pub blame: bool,
+  /// SQLite database to record the diagnostics of each run in.
+  pub db_output: Option<PathBuf>,
+  /// Directory to render the code frame of each diagnostic in as SVG.
+  pub svg_dir: Option<PathBuf>,
+  /// Write the diagnostics of each file to a `<file>.lint.json` next to it.
+  pub sidecar: bool,
+  /// File to write the pass or fail result of every checked file to.
+  pub file_results: Option<PathBuf>,
+  /// Number of problems above which a file is pointed out after linting.
+  pub file_problem_threshold: Option<usize>,
+  /// Print additional information about the run, like the enabled rules.
+  pub verbose: bool,
+  /// Print the time spent on each rule and the slowest files.
+  pub timing: bool,
+}
+
+/// Options of `--fix` and of the other ways of fixing the problems.
+#[derive(Clone, Debug, Default)]
+pub struct LintFixOptions {
+  pub enabled: bool,
+  /// Fail the files whose fixes don't converge within the maximum number of
+  /// fix iterations.
+  pub fail_on_nonconvergence: bool,
+  /// Codes of the rules whose problems are fixed, which is every rule when
+  /// empty.
+  pub only: HashSet<String>,
+  /// Print the changes as a unified diff rather than writing them, failing
+  /// when any file would change.
+  pub dry_run: bool,
+  /// Number of times the fixes of a file are applied again after the first
+  /// pass, when it's not the default.
+  pub max_iterations: Option<usize>,
+  /// Only apply the fixes that keep the runtime behavior.
+  pub safe_only: bool,
+  /// Ask whether to apply the fix of each fixable problem before linting.
+  pub interactive: bool,
+}
+
+/// Options of the incremental cache of the lint results.
+#[derive(Clone, Debug, Default)]
+pub struct LintCacheOptions {
+  /// Key that separates the incremental cache entries of this run from the
+  /// ones of other keys, like the ones of other branches.
+  pub namespace: Option<String>,
+  /// Database of the incremental cache, rather than the one in the Deno
+  /// directory.
+  pub path: Option<PathBuf>,
+}
+
+/// Options that narrow down the linted files and the reported diagnostics.
+#[derive(Clone, Debug, Default)]
+pub struct LintFilterOptions {
+  /// Byte windows of the files given as `path@start-end`, in which case
+  /// only the diagnostics overlapping one of them are reported.
+  pub byte_windows: HashMap<PathBuf, Vec<Range<usize>>>,
+  /// The lines of the `--patch` diff, in which case only its files are
+  /// linted and only the diagnostics on those lines are reported.
+  pub changed_lines: Option<ChangedLines>,
+  /// How many directories deep to lint below each include root, unlimited
+  /// when not set.
+  pub max_depth: Option<usize>,
+  /// Tags of the rules whose diagnostics aren't reported.
+  pub hide_tags: Vec<String>,
+  pub test_files: LintTestFiles,
+  pub generated: Option<LintGeneratedFiles>,
+  /// Third party directories that are never linted, like the vendor folder.
+  pub third_party: Option<PathOrPatternSet>,
+}
+
+impl LintFilterOptions {
+  /// Gets the byte windows of a collected file, which are keyed by its
+  /// canonicalized path.
+  pub fn byte_windows_of(&self, file_path: &Path) -> Option<&[Range<usize>]> {
+    if self.byte_windows.is_empty() {
+      return None;
+    }
+    let file_path = canonicalize_path_maybe_not_exists(file_path)
+      .unwrap_or_else(|_| file_path.to_path_buf());
+    self
+      .byte_windows
+      .get(&file_path)
+      .map(|windows| windows.as_slice())
+  }
 }
 
 #[derive(Clone, Debug)]
 pub struct LintOptions {
   pub rules: LintRulesConfig,
+  /// The rule of `--only`, which is the only one enabled.
+  pub only_rule: Option<String>,
+  /// Rules whose problems are reported but don't fail the run, which are
+  /// set by the severities of a rule pack.
+  pub warning_rules: HashSet<String>,
+  /// Fail rather than warn when the included or excluded rules have one that
+  /// doesn't exist.
+  pub strict_rules: bool,
   pub files: FilePatterns,
-  pub reporter_kind: LintReporterKind,
-  pub fix: bool,
+  pub filter: LintFilterOptions,
+  pub reporter: LintReporterOptions,
+  pub fix: LintFixOptions,
+  pub cache: LintCacheOptions,
+  /// `--json` output of a previous run to compare the problems with.
+  pub compare: Option<PathBuf>,
+  /// File of the problems that aren't reported, written by the run with
+  /// `update_baseline`.
+  pub baseline: Option<PathBuf>,
+  pub update_baseline: bool,
+  /// File that's written when the run passes and removed when it fails.
+  pub success_marker: Option<PathBuf>,
+  /// Warn about the files that can't be read rather than failing.
+  pub skip_unreadable: bool,
+  /// Fail when a script file isn't covered by the include patterns.
+  pub require_coverage: bool,
+  /// Convert CRLF and CR line endings to LF before linting.
+  pub normalize_eol: bool,
+  /// StatsD or Prometheus pushgateway url to push the run's metrics to.
+  pub metrics_url: Option<Url>,
+  pub print_config: bool,
+  /// Open the first problem in `$EDITOR` after linting.
+  pub edit: bool,
+  /// Duration after which linting a single file is abandoned.
+  pub timeout_per_file: Option<Duration>,
+  /// Import specifiers reported by the `no-banned-imports` rule.
+  pub banned_imports: Vec<String>,
+  /// Lint `.js` and `.mjs` files as JSX.
+  pub jsx_in_js: bool,
+  /// Extra text appended to the hints of the diagnostics of a rule.
+  pub rule_messages: HashMap<String, String>,
+  /// Maximum number of problems of a rule, keyed by rule code. When set,
+  /// only the rules over their budget fail the run.
+  pub budgets: HashMap<String, usize>,
+  /// Warn about files that were modified while being linted.
+  pub warn_changed_files: bool,
+  /// Fail when a file that had no problems in a previous run has problems.
+  pub fail_on_regressions: bool,
+  /// Maximum number of files linted at once, the number of CPUs by default.
+  pub concurrency: usize,
+  /// Maximum number of problems the run may have more than recorded by a
+  /// previous run with the same configuration.
+  pub max_warnings_delta: Option<usize>,
+  /// Record the number of problems of the run for `max_warnings_delta` even
+  /// when it grew, as it's otherwise only lowered.
+  pub update_problem_count: bool,
+  /// Maximum number of problems the run may have without failing.
+  pub max_warnings: Option<usize>,
+  /// Directory watched for new files to lint, which is `--inbox`.
+  pub inbox: Option<LintInbox>,
+  /// Lint the fenced code blocks of Markdown files.
+  pub unstable_md: bool,
 }
 
 impl LintOptions {
   pub fn new_with_base(base: PathBuf) -> Self {
     Self {
       rules: Default::default(),
+      only_rule: None,
+      strict_rules: false,
+      warning_rules: HashSet::new(),
       files: FilePatterns::new_with_base(base),
-      reporter_kind: Default::default(),
-      fix: false,
+      filter: Default::default(),
+      reporter: Default::default(),
+      fix: Default::default(),
+      cache: Default::default(),
+      compare: None,
+      baseline: None,
+      update_baseline: false,
+      success_marker: None,
+      skip_unreadable: false,
+      require_coverage: false,
+      normalize_eol: false,
+      metrics_url: None,
+      print_config: false,
+      edit: false,
+      timeout_per_file: None,
+      banned_imports: Vec::new(),
+      jsx_in_js: false,
+      rule_messages: HashMap::new(),
+      budgets: HashMap::new(),
+      warn_changed_files: false,
+      fail_on_regressions: false,
+      concurrency: 1,
+      max_warnings_delta: None,
+      update_problem_count: false,
+      max_warnings: None,
+      inbox: None,
+      unstable_md: false,
     }
   }
 
@@ -409,13 +730,267 @@ impl LintOptions {
     maybe_lint_flags: Option<LintFlags>,
     initial_cwd: &Path,
   ) -> Result<Self, AnyError> {
-    let fix = maybe_lint_flags.as_ref().map(|f| f.fix).unwrap_or(false);
+    let fix_safe_only = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fix.safe_then_report)
+      .unwrap_or(false);
+    let fix = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fix.enabled)
+      .unwrap_or(false)
+      || fix_safe_only;
+    let fail_on_fix_nonconvergence = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fix.fail_on_nonconvergence)
+      .unwrap_or(false);
+    let fix_only = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fix.only.iter().cloned().collect())
+      .unwrap_or_default();
+    let fix_max_iterations =
+      maybe_lint_flags.as_ref().and_then(|f| f.fix.max_iterations);
+    let fix_dry_run = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fix.dry_run)
+      .unwrap_or(false);
+    let only_rule = maybe_lint_flags.as_ref().and_then(|f| f.only.clone());
+    let strict_rules = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.strict_rules)
+      .unwrap_or(false);
+    let rule_pack = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.rule_pack.as_ref())
+      .map(|path| RulePack::load(&initial_cwd.join(path)))
+      .transpose()?;
+    let changed_lines = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.filter.patch.as_ref())
+      .map(|path| lint_patch::load_patch(&initial_cwd.join(path), initial_cwd))
+      .transpose()?;
+    let warning_rules = rule_pack
+      .as_ref()
+      .map(|pack| pack.warning_rules())
+      .unwrap_or_default();
+    let compact_ranges = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.compact_ranges)
+      .unwrap_or(false);
+    let output = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.reporter.output.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let absolute_paths = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.absolute)
+      .unwrap_or(false);
+    let relative_to = maybe_lint_flags
+      .as_ref()
+      .is_some_and(|f| f.reporter.relative)
+      .then(|| initial_cwd.to_path_buf());
+    let print_config = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.print_config)
+      .unwrap_or(false);
+    let pretty = maybe_lint_flags
+      .as_ref()
+      .map(|f| PrettyLintOptions {
+        limit: f.reporter.limit.filter(|limit| *limit > 0),
+        group_by_rule: f.reporter.group_by_rule,
+        locations_only: false,
+        show_snippets: !f.reporter.no_snippets,
+        show_hints: !f.reporter.no_hints,
+        underline_char: f.reporter.snippet_underline,
+        unicode_frame: f.reporter.unicode_frame,
+        hyperlinks: f.reporter.hyperlinks,
+        compact_after: f.reporter.compact_after,
+        max_warnings: f.max_warnings,
+        rule_stats: f.reporter.stats,
+      })
+      .unwrap_or_default();
+    let db_output = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.reporter.db_output.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let svg_dir = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.reporter.svg_dir.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let compare = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.compare.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let baseline = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.baseline.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let update_baseline = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.update_baseline)
+      .unwrap_or(false);
+    let skip_unreadable = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.skip_unreadable)
+      .unwrap_or(false);
+    let require_coverage = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.require_coverage)
+      .unwrap_or(false);
+    let normalize_eol = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.normalize_eol)
+      .unwrap_or(false);
+    let success_marker = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.success_marker.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let metrics_url =
+      match maybe_lint_flags.as_ref().and_then(|f| f.metrics.as_ref()) {
+        Some(metrics) => {
+          let url = Url::parse(metrics)
+            .with_context(|| format!("Invalid metrics url '{}'.", metrics))?;
+          if !matches!(url.scheme(), "http" | "https" | "statsd") {
+            bail!(
+              "Unsupported metrics url '{}'. Use an http(s) or statsd url.",
+              url
+            );
+          }
+          Some(url)
+        }
+        None => None,
+      };
+    let edit = maybe_lint_flags.as_ref().map(|f| f.edit).unwrap_or(false);
+    let hide_tags = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.filter.hide_tags.clone())
+      .unwrap_or_default();
+    let warn_changed_files = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.warn_changed_files)
+      .unwrap_or(false);
+    let max_depth = maybe_lint_flags.as_ref().and_then(|f| f.filter.max_depth);
+    let verbose = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.verbose)
+      .unwrap_or(false);
+    let timing = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.timing)
+      .unwrap_or(false);
+    let concurrency = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.concurrency)
+      .or_else(|| std::thread::available_parallelism().ok())
+      .map(|n| n.get())
+      .unwrap_or(1);
+    let max_warnings_delta =
+      maybe_lint_flags.as_ref().and_then(|f| f.max_warnings_delta);
+    let update_problem_count = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.update_problem_count)
+      .unwrap_or(false);
+    let max_warnings = maybe_lint_flags.as_ref().and_then(|f| f.max_warnings);
+    let sidecar = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.sidecar)
+      .unwrap_or(false);
+    let file_results = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.reporter.file_results.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let cache_namespace = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.cache.namespace.clone());
+    let cache_path = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.cache.path.as_ref())
+      .map(|path| initial_cwd.join(path));
+    let enclosing_symbol = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.enclosing_symbol)
+      .unwrap_or(false);
+    let one_per_file = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.one_per_file)
+      .unwrap_or(false);
+    let blame = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.reporter.blame)
+      .unwrap_or(false);
+    let file_problem_threshold = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.reporter.file_problem_threshold);
+    let interactive = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fix.interactive)
+      .unwrap_or(false);
+    let test_files = match maybe_lint_flags.as_ref() {
+      Some(f) if f.filter.no_tests => LintTestFiles::Exclude,
+      Some(f) if f.filter.tests_only => LintTestFiles::Only,
+      _ => LintTestFiles::Include,
+    };
+    let json_sort = match maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.reporter.json_sort.as_deref())
+    {
+      Some("code") => LintJsonSort::Code,
+      Some("severity") => LintJsonSort::Severity,
+      _ => LintJsonSort::Position,
+    };
+    let generated = match maybe_lint_flags.as_ref() {
+      Some(f) if !f.filter.generated.is_empty() => Some(LintGeneratedFiles {
+        files: PathOrPatternSet::from_exclude_relative_path_or_patterns(
+          initial_cwd,
+          &f.filter.generated,
+        )?,
+        globs: f.filter.generated.clone(),
+        skip: f.filter.skip_generated,
+      }),
+      _ => None,
+    };
+    let inbox = maybe_lint_flags.as_ref().and_then(|f| {
+      f.inbox.as_ref().map(|dir| LintInbox {
+        dir: initial_cwd.join(dir),
+        done: match &f.inbox_done {
+          // a directory named "delete" is moved to like any other
+          Some(done_dir) => LintInboxDone::Move(initial_cwd.join(done_dir)),
+          None if f.inbox_delete => LintInboxDone::Delete,
+          None => LintInboxDone::Keep,
+        },
+      })
+    });
+    let fail_on_regressions = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.fail_on_regressions)
+      .unwrap_or(false);
+    let unstable_md = maybe_lint_flags
+      .as_ref()
+      .map(|f| f.unstable_md)
+      .unwrap_or(false);
+    let timeout_per_file = maybe_lint_flags
+      .as_ref()
+      .and_then(|f| f.timeout_per_file)
+      .map(Duration::from_secs);
     let mut maybe_reporter_kind =
       maybe_lint_flags.as_ref().and_then(|lint_flags| {
-        if lint_flags.json {
+        if lint_flags.fix.preview {
+          Some(LintReporterKind::FixPreview)
+        } else if lint_flags.reporter.json {
           Some(LintReporterKind::Json)
-        } else if lint_flags.compact {
+        } else if lint_flags.reporter.json_workspace {
+          Some(LintReporterKind::JsonWorkspace)
+        } else if lint_flags.reporter.ndjson {
+          Some(LintReporterKind::NdJson)
+        } else if lint_flags.reporter.checkstyle {
+          Some(LintReporterKind::Checkstyle)
+        } else if lint_flags.reporter.tap {
+          Some(LintReporterKind::Tap)
+        } else if lint_flags.reporter.by_rule {
+          Some(LintReporterKind::ByRule)
+        } else if lint_flags.reporter.compact {
           Some(LintReporterKind::Compact)
+        } else if lint_flags.reporter.protobuf {
+          Some(LintReporterKind::Protobuf)
         } else {
           None
         }
@@ -425,19 +1000,22 @@ impl LintOptions {
       // Flag not set, so try to get lint reporter from the config file.
       if let Some(lint_config) = &maybe_lint_config {
         maybe_reporter_kind = match lint_config.report.as_deref() {
-          Some("json") => Some(LintReporterKind::Json),
-          Some("compact") => Some(LintReporterKind::Compact),
-          Some("pretty") => Some(LintReporterKind::Pretty),
-          Some(_) => {
-            bail!("Invalid lint report type in config file")
-          }
+          Some(name) => match parse_lint_reporter_kind(name) {
+            Some(kind) => Some(kind),
+            None => bail!("Invalid lint report type in config file"),
+          },
           None => None,
         }
       }
     }
+    let reporter_kind = match maybe_reporter_kind {
+      Some(kind) => kind,
+      None => lint_reporter_kind_from_env(|name| std::env::var(name).ok())?,
+    };
 
     let (
-      maybe_file_flags,
+      mut maybe_file_flags,
+      include_extra,
       maybe_rules_tags,
       maybe_rules_include,
       maybe_rules_exclude,
@@ -445,56 +1023,245 @@ impl LintOptions {
       .map(|f| {
         (
           f.files,
+          f.include_extra,
           f.maybe_rules_tags,
           f.maybe_rules_include,
           f.maybe_rules_exclude,
         )
       })
       .unwrap_or_default();
+    maybe_file_flags.include = response_file::expand_response_files(
+      maybe_file_flags.include,
+      initial_cwd,
+    )?;
+    let (include, byte_windows) =
+      split_byte_windows(maybe_file_flags.include, initial_cwd)?;
+    maybe_file_flags.include = include;
 
-    let (maybe_config_files, maybe_config_rules) =
+    let (maybe_config_files, mut maybe_config_rules) =
       maybe_lint_config.map(|c| (c.files, c.rules)).unzip();
+    // the rule pack is below the flags and replaces the rules of the config
+    // file, which the flags are merged with
+    if let Some(pack) = &rule_pack {
+      let config_rules =
+        maybe_config_rules.get_or_insert_with(Default::default);
+      if pack.tags.is_some() {
+        config_rules.tags = pack.tags.clone();
+      }
+      if pack.include.is_some() {
+        config_rules.include = pack.include.clone();
+      }
+      if let Some(exclude) = pack.excluded_rules() {
+        config_rules.exclude = Some(exclude);
+      }
+    }
+    let mut files =
+      resolve_files(maybe_config_files, Some(maybe_file_flags), initial_cwd)?;
+    if !include_extra.is_empty() {
+      // the extra includes are added to the resolved ones, while the excludes
+      // still apply to them
+      let mut include = match files.include.take() {
+        Some(include) => include.into_path_or_patterns(),
+        // no includes means everything in the base directory
+        None => vec![PathOrPattern::Path(files.base.clone())],
+      };
+      include.extend(
+        PathOrPatternSet::from_include_relative_path_or_patterns(
+          initial_cwd,
+          &include_extra,
+        )?
+        .into_path_or_patterns(),
+      );
+      files.include = Some(PathOrPatternSet::new(include));
+    }
     Ok(Self {
-      reporter_kind: maybe_reporter_kind.unwrap_or_default(),
-      files: resolve_files(
-        maybe_config_files,
-        Some(maybe_file_flags),
-        initial_cwd,
-      )?,
-      rules: resolve_lint_rules_options(
-        maybe_config_rules,
-        maybe_rules_tags,
-        maybe_rules_include,
-        maybe_rules_exclude,
-      ),
-      fix,
+      files,
+      rules: match &only_rule {
+        Some(code) => LintRulesConfig {
+          tags: Some(Vec::new()),
+          include: Some(vec![code.clone()]),
+          // the cli rules are enabled by the config file rather than tags
+          exclude: Some(
+            ["no-slow-types", "no-banned-imports"]
+              .into_iter()
+              .filter(|name| *name != code.as_str())
+              .map(|name| name.to_string())
+              .collect(),
+          ),
+        },
+        None => resolve_lint_rules_options(
+          maybe_config_rules,
+          maybe_rules_tags,
+          maybe_rules_include,
+          maybe_rules_exclude,
+        ),
+      },
+      only_rule,
+      strict_rules,
+      warning_rules,
+      filter: LintFilterOptions {
+        byte_windows,
+        changed_lines,
+        max_depth,
+        hide_tags,
+        test_files,
+        generated,
+        third_party: None,
+      },
+      reporter: LintReporterOptions {
+        kind: reporter_kind,
+        absolute_paths,
+        relative_to,
+        compact_ranges,
+        output,
+        pretty,
+        json_sort,
+        enclosing_symbol,
+        one_per_file,
+        blame,
+        db_output,
+        svg_dir,
+        sidecar,
+        file_results,
+        file_problem_threshold,
+        verbose,
+        timing,
+      },
+      fix: LintFixOptions {
+        enabled: fix,
+        fail_on_nonconvergence: fail_on_fix_nonconvergence,
+        only: fix_only,
+        dry_run: fix_dry_run,
+        max_iterations: fix_max_iterations,
+        safe_only: fix_safe_only,
+        interactive,
+      },
+      cache: LintCacheOptions {
+        namespace: cache_namespace,
+        path: cache_path,
+      },
+      compare,
+      baseline,
+      update_baseline,
+      success_marker,
+      skip_unreadable,
+      require_coverage,
+      normalize_eol,
+      metrics_url,
+      print_config,
+      edit,
+      timeout_per_file,
+      banned_imports: Vec::new(),
+      jsx_in_js: false,
+      rule_messages: HashMap::new(),
+      budgets: HashMap::new(),
+      warn_changed_files,
+      fail_on_regressions,
+      concurrency,
+      max_warnings_delta,
+      update_problem_count,
+      max_warnings,
+      inbox,
+      unstable_md,
     })
   }
 }
 
+/// Lint configuration that's handled by the CLI rather than `deno_config`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CliLintConfig {
+  /// Import specifiers reported by the `no-banned-imports` rule.
+  pub banned_imports: Vec<String>,
+  /// Parse `.js` and `.mjs` files with JSX syntax enabled. Off by default.
+  pub jsx_in_js: bool,
+  /// Extra text that's appended to the hint of the diagnostics of a rule,
+  /// keyed by rule code, like a link to a team's guidelines.
+  pub rule_messages: HashMap<String, String>,
+  /// Maximum number of problems of a rule, keyed by rule code.
+  pub budgets: HashMap<String, usize>,
+  /// Paths and globs of third party code, relative to the config file,
+  /// which are never linted, like the vendor folder.
+  pub third_party: Vec<String>,
+}
+
+impl CliLintConfig {
+  /// Keys of the "lint" configuration that are handled by the CLI.
+  const KEYS: &'static [&'static str] = &[
+    "bannedImports",
+    "budgets",
+    "jsxInJs",
+    "ruleMessages",
+    "thirdParty",
+  ];
+
+  pub fn from_config_file(config_file: &ConfigFile) -> Result<Self, AnyError> {
+    let Some(serde_json::Value::Object(lint)) = &config_file.json.lint else {
+      return Ok(Default::default());
+    };
+    let config = lint
+      .iter()
+      .filter(|(key, _)| Self::KEYS.contains(&key.as_str()))
+      .map(|(key, value)| (key.clone(), value.clone()))
+      .collect::<serde_json::Map<_, _>>();
+    serde_json::from_value(serde_json::Value::Object(config))
+      .context("Failed to parse \"lint\" configuration")
+  }
+}
+
+/// Resolves the "lint" configuration of the config file, validating the
+/// keys that are handled by the CLI and hiding them from `deno_config`.
+pub fn to_lint_config(
+  config_file: &ConfigFile,
+) -> Result<Option<LintConfig>, AnyError> {
+  CliLintConfig::from_config_file(config_file)?;
+  let mut config_file = config_file.clone();
+  if let Some(serde_json::Value::Object(lint)) = &mut config_file.json.lint {
+    lint.retain(|key, _| !CliLintConfig::KEYS.contains(&key.as_str()));
+  }
+  config_file.to_lint_config()
+}
+
 fn resolve_lint_rules_options(
   maybe_lint_rules_config: Option<LintRulesConfig>,
   mut maybe_rules_tags: Option<Vec<String>>,
-  mut maybe_rules_include: Option<Vec<String>>,
-  mut maybe_rules_exclude: Option<Vec<String>>,
+  maybe_rules_include: Option<Vec<String>>,
+  maybe_rules_exclude: Option<Vec<String>>,
 ) -> LintRulesConfig {
-  if let Some(config_rules) = maybe_lint_rules_config {
-    // Try to get configured rules. CLI flags take precedence
-    // over config file, i.e. if there's `rules.include` in config file
-    // and `--rules-include` CLI flag, only the flag value is taken into account.
-    if maybe_rules_include.is_none() {
-      maybe_rules_include = config_rules.include;
+  let (config_include, config_exclude) = match maybe_lint_rules_config {
+    Some(config_rules) => {
+      // the tags of the CLI flags replace the ones of the config file
+      if maybe_rules_tags.is_none() {
+        maybe_rules_tags = config_rules.tags;
+      }
+      (config_rules.include, config_rules.exclude)
     }
-    if maybe_rules_exclude.is_none() {
-      maybe_rules_exclude = config_rules.exclude;
+    None => (None, None),
+  };
+  // while the included and excluded rules are merged, with the CLI flags
+  // taking precedence for the rules that are in both, i.e. if there's
+  // `rules.exclude: ["no-eval"]` in the config file and
+  // `--rules-include=no-eval`, the rule is included
+  let merge = |config_codes: Option<Vec<String>>,
+               flag_codes: &Option<Vec<String>>,
+               opposite_flag_codes: &Option<Vec<String>>| {
+    if config_codes.is_none() && flag_codes.is_none() {
+      return None;
     }
-    if maybe_rules_tags.is_none() {
-      maybe_rules_tags = config_rules.tags;
+    let mut codes = config_codes.unwrap_or_default();
+    if let Some(opposite_flag_codes) = opposite_flag_codes {
+      codes.retain(|code| !opposite_flag_codes.contains(code));
     }
-  }
+    for code in flag_codes.iter().flatten() {
+      if !codes.contains(code) {
+        codes.push(code.clone());
+      }
+    }
+    Some(codes)
+  };
   LintRulesConfig {
-    exclude: maybe_rules_exclude,
-    include: maybe_rules_include,
+    exclude: merge(config_exclude, &maybe_rules_exclude, &maybe_rules_include),
+    include: merge(config_include, &maybe_rules_include, &maybe_rules_exclude),
     tags: maybe_rules_tags,
   }
 }
@@ -1255,11 +2022,58 @@ impl CliOptions {
     lint_flags: LintFlags,
   ) -> Result<LintOptions, AnyError> {
     let maybe_lint_config = if let Some(config_file) = &self.maybe_config_file {
-      config_file.to_lint_config()?
+      to_lint_config(config_file)?
     } else {
       None
     };
-    LintOptions::resolve(maybe_lint_config, Some(lint_flags), &self.initial_cwd)
+    let mut lint_options = LintOptions::resolve(
+      maybe_lint_config,
+      Some(lint_flags),
+      &self.initial_cwd,
+    )?;
+    // the ignore file is at the root of the project, next to the config file
+    let root_dir = match self
+      .maybe_config_file
+      .as_ref()
+      .and_then(|config_file| config_file.specifier.to_file_path().ok())
+    {
+      Some(config_path) => config_path.parent().unwrap().to_path_buf(),
+      None => self.initial_cwd.clone(),
+    };
+    if let Some(ignored) = load_lint_ignore_file(&root_dir)? {
+      // after the other excludes, so that its negated patterns override them
+      let mut exclude =
+        lint_options.files.exclude.clone().into_path_or_patterns();
+      exclude.extend(ignored.into_path_or_patterns());
+      lint_options.files.exclude = PathOrPatternSet::new(exclude);
+    }
+    if let Some(config_file) = &self.maybe_config_file {
+      let cli_lint_config = CliLintConfig::from_config_file(config_file)?;
+      lint_options.banned_imports = cli_lint_config.banned_imports;
+      lint_options.jsx_in_js = cli_lint_config.jsx_in_js;
+      lint_options.rule_messages = cli_lint_config.rule_messages;
+      if !cli_lint_config.budgets.is_empty()
+        && lint_options.max_warnings.is_some()
+      {
+        // like with --compare, the budgets decide whether the run fails
+        bail!(
+          "--max-warnings can't be used with the \"budgets\" lint configuration."
+        );
+      }
+      lint_options.budgets = cli_lint_config.budgets;
+      if !cli_lint_config.third_party.is_empty() {
+        let config_dir = match config_file.specifier.to_file_path() {
+          Ok(config_path) => config_path.parent().unwrap().to_path_buf(),
+          Err(()) => self.initial_cwd.clone(),
+        };
+        lint_options.filter.third_party =
+          Some(PathOrPatternSet::from_exclude_relative_path_or_patterns(
+            &config_dir,
+            &cli_lint_config.third_party,
+          )?);
+      }
+    }
+    Ok(lint_options)
   }
 
   pub fn resolve_config_excludes(&self) -> Result<PathOrPatternSet, AnyError> {
@@ -1823,6 +2637,97 @@ fn convert_option_str_to_path_buf(
   }
 }
 
+/// Splits the `path@start-end` byte windows off the lint file arguments,
+/// returning the paths to lint and the windows by canonicalized path.
+/// Arguments that don't end with a window, like `node_modules/@std/fs`, or
+/// that are an existing file, like `a.ts@1-2`, are kept as is.
+fn split_byte_windows(
+  include: Vec<String>,
+  initial_cwd: &Path,
+) -> Result<(Vec<String>, HashMap<PathBuf, Vec<Range<usize>>>), AnyError> {
+  let mut paths = Vec::with_capacity(include.len());
+  let mut windows: HashMap<PathBuf, Vec<Range<usize>>> = HashMap::new();
+  for arg in include {
+    let window = arg.rsplit_once('@').and_then(|(path, window)| {
+      let (start, end) = window.split_once('-')?;
+      let start = start.parse::<usize>().ok()?;
+      let end = end.parse::<usize>().ok()?;
+      Some((path, start..end))
+    });
+    let Some((path, window)) = window
+      .filter(|(path, _)| !path.is_empty())
+      .filter(|_| !initial_cwd.join(&arg).exists())
+    else {
+      paths.push(arg);
+      continue;
+    };
+    if window.start > window.end {
+      bail!(
+        "Invalid byte window in '{}'. The start must not be after the end.",
+        arg
+      );
+    }
+    // the collected files are looked up canonicalized, as they may be given
+    // through a symlink or a path like `../a.ts`
+    let resolved = canonicalize_path_maybe_not_exists(&initial_cwd.join(path))?;
+    let file_windows = windows.entry(resolved).or_default();
+    if file_windows.is_empty() {
+      paths.push(path.to_string());
+    }
+    file_windows.push(window);
+  }
+  Ok((paths, windows))
+}
+
+fn parse_lint_reporter_kind(name: &str) -> Option<LintReporterKind> {
+  match name {
+    "pretty" => Some(LintReporterKind::Pretty),
+    "json" => Some(LintReporterKind::Json),
+    "json-workspace" => Some(LintReporterKind::JsonWorkspace),
+    "ndjson" => Some(LintReporterKind::NdJson),
+    "checkstyle" => Some(LintReporterKind::Checkstyle),
+    "tap" => Some(LintReporterKind::Tap),
+    "by-rule" => Some(LintReporterKind::ByRule),
+    "compact" => Some(LintReporterKind::Compact),
+    "github" => Some(LintReporterKind::GithubActions),
+    "gitlab" => Some(LintReporterKind::Gitlab),
+    "protobuf" => Some(LintReporterKind::Protobuf),
+    _ => None,
+  }
+}
+
+/// Picks the lint reporter of the CI system the CLI runs on, which is used
+/// when neither a flag nor the config file sets one. The `DENO_LINT_REPORTER`
+/// environment variable overrides the detection unless it's `auto`.
+fn lint_reporter_kind_from_env(
+  get_env: impl Fn(&str) -> Option<String>,
+) -> Result<LintReporterKind, AnyError> {
+  let name = get_env("DENO_LINT_REPORTER").filter(|n| !n.is_empty());
+  if let Some(name) = name.filter(|n| n != "auto") {
+    return match parse_lint_reporter_kind(&name) {
+      Some(kind) => Ok(kind),
+      None => bail!(
+        "Invalid DENO_LINT_REPORTER '{}'. Expected one of: auto, pretty, json, json-workspace, ndjson, checkstyle, tap, by-rule, compact, github, gitlab, protobuf.",
+        name
+      ),
+    };
+  }
+  let is_set = |name: &str| {
+    get_env(name).is_some_and(|value| {
+      !value.is_empty() && value != "false" && value != "0"
+    })
+  };
+  Ok(if is_set("GITHUB_ACTIONS") {
+    LintReporterKind::GithubActions
+  } else if is_set("GITLAB_CI") {
+    LintReporterKind::Gitlab
+  } else if is_set("CI") {
+    LintReporterKind::Compact
+  } else {
+    LintReporterKind::Pretty
+  })
+}
+
 #[cfg(test)]
 mod test {
   use crate::util::fs::FileCollector;
@@ -1994,4 +2899,153 @@ mod test {
     let reg_api_url = jsr_api_url();
     assert!(reg_api_url.as_str().ends_with('/'));
   }
+
+  #[test]
+  fn lint_byte_windows() {
+    use test_util::TempDir;
+    let temp_dir = TempDir::new();
+    temp_dir.create_dir_all("sub");
+    temp_dir.write("a.ts@1-2", "");
+    let root = temp_dir.path().canonicalize().to_path_buf();
+    let cwd = temp_dir.path().join("sub").to_path_buf();
+    let (paths, windows) = split_byte_windows(
+      vec![
+        "a.ts@10-20".to_string(),
+        "a.ts@40-40".to_string(),
+        "../b.ts@0-5".to_string(),
+        // an existing file rather than a window
+        "../a.ts@1-2".to_string(),
+        "node_modules/@std/fs".to_string(),
+        "c.ts".to_string(),
+      ],
+      &cwd,
+    )
+    .unwrap();
+    assert_eq!(
+      paths,
+      vec![
+        "a.ts",
+        "../b.ts",
+        "../a.ts@1-2",
+        "node_modules/@std/fs",
+        "c.ts"
+      ]
+    );
+    assert_eq!(
+      windows,
+      HashMap::from([
+        (root.join("sub").join("a.ts"), vec![10..20, 40..40]),
+        (root.join("b.ts"), vec![0..5]),
+      ])
+    );
+
+    // looked up by the path of the collector
+    let filter = LintFilterOptions {
+      byte_windows: windows,
+      ..Default::default()
+    };
+    let collected = deno_core::normalize_path(cwd.join("../b.ts"));
+    assert_eq!(filter.byte_windows_of(&collected), Some(&[0..5][..]));
+    assert_eq!(filter.byte_windows_of(&cwd.join("c.ts")), None);
+
+    let err =
+      split_byte_windows(vec!["a.ts@20-10".to_string()], &cwd).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Invalid byte window in 'a.ts@20-10'. The start must not be after the end."
+    );
+  }
+
+  #[test]
+  fn lint_rules_options_merge_flags() {
+    let codes = |codes: &[&str]| {
+      Some(codes.iter().map(|c| c.to_string()).collect::<Vec<_>>())
+    };
+    let config_rules = LintRulesConfig {
+      tags: codes(&["recommended"]),
+      include: codes(&["eqeqeq", "no-eval"]),
+      exclude: codes(&["no-debugger", "no-slow-types"]),
+    };
+    let rules = resolve_lint_rules_options(
+      Some(config_rules),
+      None,
+      codes(&["no-slow-types", "camelcase"]),
+      codes(&["no-eval"]),
+    );
+    assert_eq!(rules.tags, codes(&["recommended"]));
+    assert_eq!(
+      rules.include,
+      codes(&["eqeqeq", "no-slow-types", "camelcase"])
+    );
+    assert_eq!(rules.exclude, codes(&["no-debugger", "no-eval"]));
+
+    let rules = resolve_lint_rules_options(
+      None,
+      codes(&[]),
+      None,
+      codes(&["no-debugger"]),
+    );
+    assert_eq!(rules.tags, codes(&[]));
+    assert_eq!(rules.include, None);
+    assert_eq!(rules.exclude, codes(&["no-debugger"]));
+  }
+
+  #[test]
+  fn lint_reporter_kind_env() {
+    let from_env = |vars: &[(&str, &str)]| {
+      let vars = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect::<HashMap<_, _>>();
+      lint_reporter_kind_from_env(|name| vars.get(name).cloned())
+    };
+    assert_eq!(from_env(&[]).unwrap(), LintReporterKind::Pretty);
+    assert_eq!(
+      from_env(&[("CI", "true"), ("GITHUB_ACTIONS", "true")]).unwrap(),
+      LintReporterKind::GithubActions
+    );
+    assert_eq!(
+      from_env(&[("CI", "true"), ("GITLAB_CI", "true")]).unwrap(),
+      LintReporterKind::Gitlab
+    );
+    assert_eq!(from_env(&[("CI", "1")]).unwrap(), LintReporterKind::Compact);
+    assert_eq!(
+      from_env(&[("CI", "false")]).unwrap(),
+      LintReporterKind::Pretty
+    );
+    // auto is the same as leaving it unset
+    assert_eq!(
+      from_env(&[("CI", "true"), ("DENO_LINT_REPORTER", "auto")]).unwrap(),
+      LintReporterKind::Compact
+    );
+    // the override wins over the detection
+    assert_eq!(
+      from_env(&[("GITLAB_CI", "true"), ("DENO_LINT_REPORTER", "pretty")])
+        .unwrap(),
+      LintReporterKind::Pretty
+    );
+    assert_eq!(
+      from_env(&[("DENO_LINT_REPORTER", "json-workspace")]).unwrap(),
+      LintReporterKind::JsonWorkspace
+    );
+    assert_eq!(
+      from_env(&[("DENO_LINT_REPORTER", "protobuf")]).unwrap(),
+      LintReporterKind::Protobuf
+    );
+    assert!(from_env(&[("DENO_LINT_REPORTER", "xml")]).is_err());
+  }
+
+  #[test]
+  fn lint_reporter_kind_names() {
+    // the names of the `lint.report` config and DENO_LINT_REPORTER
+    assert_eq!(
+      parse_lint_reporter_kind("json-workspace"),
+      Some(LintReporterKind::JsonWorkspace)
+    );
+    assert_eq!(
+      parse_lint_reporter_kind("protobuf"),
+      Some(LintReporterKind::Protobuf)
+    );
+    assert_eq!(parse_lint_reporter_kind("xml"), None);
+  }
 }
@@ -0,0 +1,156 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+
+/// Replaces the `@file` arguments with the arguments in those files, like the
+/// response files of MSVC and clang, which avoids exceeding the command line
+/// length limit. Response files may refer to other response files. Relative
+/// paths are resolved against the cwd. An argument starting with `@@` is kept
+/// with one `@` less, for paths like `@scope/pkg/mod.ts`.
+pub fn expand_response_files(
+  args: Vec<String>,
+  cwd: &Path,
+) -> Result<Vec<String>, AnyError> {
+  if !args.iter().any(|arg| arg.starts_with('@')) {
+    return Ok(args);
+  }
+  let mut expanded = Vec::with_capacity(args.len());
+  let mut stack = HashSet::new();
+  for arg in args {
+    expand_arg(arg, cwd, &mut stack, &mut expanded)?;
+  }
+  Ok(expanded)
+}
+
+fn expand_arg(
+  arg: String,
+  cwd: &Path,
+  stack: &mut HashSet<PathBuf>,
+  expanded: &mut Vec<String>,
+) -> Result<(), AnyError> {
+  if let Some(escaped) = arg.strip_prefix("@@") {
+    expanded.push(format!("@{}", escaped));
+    return Ok(());
+  }
+  let Some(file) = arg.strip_prefix('@') else {
+    expanded.push(arg);
+    return Ok(());
+  };
+  let path = cwd.join(file);
+  let text = std::fs::read_to_string(&path)
+    .with_context(|| format!("Failed reading response file '{}'.", file))?;
+  if !stack.insert(path.clone()) {
+    bail!("Response file '{}' includes itself.", file);
+  }
+  let args = split_response_file(&text)
+    .with_context(|| format!("Failed parsing response file '{}'.", file))?;
+  for arg in args {
+    expand_arg(arg, cwd, stack, expanded)?;
+  }
+  stack.remove(&path);
+  Ok(())
+}
+
+/// Splits the text of a response file into its arguments, which are separated
+/// by whitespace. Double or single quotes keep the whitespace in between, for
+/// paths with spaces. Backslashes are kept as they are, as they separate the
+/// components of Windows paths.
+fn split_response_file(text: &str) -> Result<Vec<String>, AnyError> {
+  let mut args = Vec::new();
+  let mut arg: Option<String> = None;
+  let mut quote = None;
+  for c in text.chars() {
+    match quote {
+      Some(q) if c == q => quote = None,
+      Some(_) => arg.get_or_insert_with(String::new).push(c),
+      None if c == '"' || c == '\'' => {
+        quote = Some(c);
+        // quotes with nothing in between are an empty argument
+        arg.get_or_insert_with(String::new);
+      }
+      None if c.is_whitespace() => args.extend(arg.take()),
+      None => arg.get_or_insert_with(String::new).push(c),
+    }
+  }
+  if let Some(q) = quote {
+    bail!("Missing closing {} quote.", q);
+  }
+  args.extend(arg);
+  Ok(args)
+}
+
+#[cfg(test)]
+mod test {
+  use test_util::TempDir;
+
+  use super::*;
+
+  #[test]
+  fn expands_nested_response_files() {
+    let temp_dir = TempDir::new();
+    temp_dir.write("args.txt", "a.ts src/**/*.ts\n@more.txt\n");
+    temp_dir.write("more.txt", "b.ts");
+    temp_dir.write("cycle.txt", "@cycle.txt");
+    let cwd = temp_dir.path().as_path();
+
+    let args = expand_response_files(
+      vec!["main.ts".to_string(), "@args.txt".to_string()],
+      cwd,
+    )
+    .unwrap();
+    assert_eq!(args, vec!["main.ts", "a.ts", "src/**/*.ts", "b.ts"]);
+
+    let err =
+      expand_response_files(vec!["@missing.txt".to_string()], cwd).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Failed reading response file 'missing.txt'."
+    );
+    let err =
+      expand_response_files(vec!["@cycle.txt".to_string()], cwd).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Response file 'cycle.txt' includes itself."
+    );
+  }
+
+  #[test]
+  fn keeps_escaped_arguments() {
+    let temp_dir = TempDir::new();
+    temp_dir.write("args.txt", "@@scope/pkg/mod.ts\n");
+    let args = expand_response_files(
+      vec!["@@std/mod.ts".to_string(), "@args.txt".to_string()],
+      temp_dir.path().as_path(),
+    )
+    .unwrap();
+    assert_eq!(args, vec!["@std/mod.ts", "@scope/pkg/mod.ts"]);
+  }
+
+  #[test]
+  fn splits_quoted_arguments() {
+    assert_eq!(
+      split_response_file(
+        "a.ts \"my dir/b.ts\"\n'c d.ts'\r\nsrc\\e.ts pre\"fix\"ed.ts \"\""
+      )
+      .unwrap(),
+      vec![
+        "a.ts",
+        "my dir/b.ts",
+        "c d.ts",
+        "src\\e.ts",
+        "prefixed.ts",
+        ""
+      ]
+    );
+    assert_eq!(
+      split_response_file("a.ts \"b.ts").unwrap_err().to_string(),
+      "Missing closing \" quote."
+    );
+  }
+}
@@ -0,0 +1,178 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::Path;
+
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Deserialize;
+
+use crate::tools::lint::CLI_RULE_NAMES;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RulePackSeverity {
+  /// The rule isn't run, like when it's excluded.
+  Off,
+  /// The problems of the rule are reported, but don't fail the run.
+  Warning,
+  /// The problems of the rule fail the run, which is the default.
+  Error,
+}
+
+/// A shareable lint rule configuration loaded with `--rule-pack`, like:
+///
+/// ```json
+/// {
+///   "tags": ["recommended"],
+///   "include": ["ban-untagged-todo"],
+///   "exclude": ["no-explicit-any"],
+///   "severities": { "no-console": "warning", "no-debugger": "off" }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RulePack {
+  pub tags: Option<Vec<String>>,
+  pub include: Option<Vec<String>>,
+  pub exclude: Option<Vec<String>>,
+  pub severities: BTreeMap<String, RulePackSeverity>,
+}
+
+impl RulePack {
+  pub fn load(path: &Path) -> Result<Self, AnyError> {
+    let text = std::fs::read_to_string(path).with_context(|| {
+      format!("Failed reading rule pack '{}'.", path.display())
+    })?;
+    let pack: RulePack = serde_json::from_str(&text).with_context(|| {
+      format!("Failed parsing rule pack '{}'.", path.display())
+    })?;
+    let problems = pack.validate();
+    if !problems.is_empty() {
+      bail!(
+        "Invalid rule pack '{}':\n{}",
+        path.display(),
+        problems
+          .iter()
+          .map(|problem| format!("  {}", problem))
+          .collect::<Vec<_>>()
+          .join("\n")
+      );
+    }
+    Ok(pack)
+  }
+
+  /// Checks that the rules and tags of the pack exist, returning a problem
+  /// for each one that doesn't.
+  fn validate(&self) -> Vec<String> {
+    let all_rules = deno_lint::rules::get_all_rules();
+    let known_rules = all_rules
+      .iter()
+      .map(|rule| rule.code())
+      .chain(CLI_RULE_NAMES)
+      .collect::<HashSet<_>>();
+    let known_tags = all_rules
+      .iter()
+      .flat_map(|rule| rule.tags().iter().copied())
+      .collect::<HashSet<_>>();
+    let mut problems = Vec::new();
+    for tag in self.tags.iter().flatten() {
+      if !known_tags.contains(tag.as_str()) {
+        problems.push(format!("Unknown tag '{}' in \"tags\".", tag));
+      }
+    }
+    let rule_lists = [
+      ("include", self.include.iter().flatten().collect::<Vec<_>>()),
+      ("exclude", self.exclude.iter().flatten().collect()),
+      ("severities", self.severities.keys().collect()),
+    ];
+    for (key, codes) in rule_lists {
+      for code in codes {
+        if !known_rules.contains(code.as_str()) {
+          problems.push(format!("Unknown rule '{}' in \"{}\".", code, key));
+        }
+      }
+    }
+    problems
+  }
+
+  /// The rules that are excluded, including the ones that are turned off.
+  pub fn excluded_rules(&self) -> Option<Vec<String>> {
+    let off_rules = self
+      .severities
+      .iter()
+      .filter(|(_, severity)| **severity == RulePackSeverity::Off)
+      .map(|(code, _)| code.clone())
+      .collect::<Vec<_>>();
+    if off_rules.is_empty() {
+      return self.exclude.clone();
+    }
+    let mut exclude = self.exclude.clone().unwrap_or_default();
+    exclude.extend(off_rules);
+    Some(exclude)
+  }
+
+  /// The rules whose problems don't fail the run.
+  pub fn warning_rules(&self) -> HashSet<String> {
+    self
+      .severities
+      .iter()
+      .filter(|(_, severity)| **severity == RulePackSeverity::Warning)
+      .map(|(code, _)| code.clone())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use test_util::TempDir;
+
+  use super::*;
+
+  #[test]
+  fn loads_and_validates_rule_packs() {
+    let temp_dir = TempDir::new();
+    temp_dir.write(
+      "pack.json",
+      r#"{
+        "tags": ["recommended"],
+        "exclude": ["no-explicit-any"],
+        "severities": { "no-console": "warning", "no-debugger": "off" }
+      }"#,
+    );
+    let pack =
+      RulePack::load(temp_dir.path().join("pack.json").as_path()).unwrap();
+    assert_eq!(
+      pack.excluded_rules(),
+      Some(vec![
+        "no-explicit-any".to_string(),
+        "no-debugger".to_string()
+      ])
+    );
+    assert_eq!(
+      pack.warning_rules(),
+      HashSet::from(["no-console".to_string()])
+    );
+
+    temp_dir.write(
+      "invalid.json",
+      r#"{
+        "tags": ["strict"],
+        "include": ["no-such-rule"],
+        "severities": { "no-debugger": "warning" }
+      }"#,
+    );
+    let err = RulePack::load(temp_dir.path().join("invalid.json").as_path())
+      .unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      format!(
+        "Invalid rule pack '{}':\n  Unknown tag 'strict' in \"tags\".\n  Unknown rule 'no-such-rule' in \"include\".",
+        temp_dir.path().join("invalid.json").display()
+      )
+    );
+  }
+}
@@ -77,6 +77,12 @@ impl Caches {
     )
   }
 
+  /// Gets a lint incremental cache db at a path other than the one in the
+  /// Deno directory.
+  pub fn lint_incremental_cache_db_at(&self, path: PathBuf) -> CacheDB {
+    CacheDB::from_path(&INCREMENTAL_CACHE_DB, path, crate::version::deno())
+  }
+
   pub fn dep_analysis_db(&self) -> CacheDB {
     Self::make_db(
       &self.dep_analysis_db,
@@ -22,8 +22,20 @@ pub static INCREMENTAL_CACHE_DB: CacheDBConfiguration = CacheDBConfiguration {
       file_path TEXT PRIMARY KEY,
       state_hash TEXT NOT NULL,
       source_hash TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS incrementalcachestats (
+      state_hash TEXT PRIMARY KEY,
+      problem_count INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS incrementalcacheoutput (
+      file_path TEXT PRIMARY KEY,
+      state_hash TEXT NOT NULL,
+      source_hash TEXT NOT NULL,
+      output TEXT NOT NULL
     );",
-  on_version_change: "DELETE FROM incrementalcache;",
+  on_version_change: "DELETE FROM incrementalcache;
+    DELETE FROM incrementalcachestats;
+    DELETE FROM incrementalcacheoutput;",
   preheat_queries: &[],
   // If the cache fails, just ignore all caching attempts
   on_failure: CacheFailure::Blackhole,
@@ -46,10 +58,47 @@ impl IncrementalCache {
     self.0.is_file_same(file_path, file_text)
   }
 
+  /// Gets if the file was cached in a previous run, regardless of whether
+  /// its text changed since.
+  pub fn was_file_cached(&self, file_path: &Path) -> bool {
+    self.0.was_file_cached(file_path)
+  }
+
   pub fn update_file(&self, file_path: &Path, file_text: &str) {
     self.0.update_file(file_path, file_text)
   }
 
+  /// Gets the output stored for the file by a previous run with the same
+  /// state, if the file's text didn't change since.
+  pub fn previous_output(
+    &self,
+    file_path: &Path,
+    file_text: &str,
+  ) -> Option<&str> {
+    self.0.previous_output(file_path, file_text)
+  }
+
+  /// Stores an output for the file, like its diagnostics, that a later run
+  /// can use instead of processing the file again.
+  pub fn update_output(
+    &self,
+    file_path: &Path,
+    file_text: &str,
+    output: String,
+  ) {
+    self.0.update_output(file_path, file_text, output)
+  }
+
+  /// Gets the number of problems stored by a previous run with the same
+  /// state, if any.
+  pub fn previous_problem_count(&self) -> Option<usize> {
+    self.0.previous_problem_count
+  }
+
+  pub fn update_problem_count(&self, problem_count: usize) {
+    self.0.update_problem_count(problem_count)
+  }
+
   pub async fn wait_completion(&self) {
     self.0.wait_completion().await;
   }
@@ -57,11 +106,15 @@ impl IncrementalCache {
 
 enum ReceiverMessage {
   Update(PathBuf, u64),
+  UpdateOutput(PathBuf, u64, String),
+  UpdateProblemCount(usize),
   Exit,
 }
 
 struct IncrementalCacheInner {
   previous_hashes: HashMap<PathBuf, u64>,
+  previous_outputs: HashMap<PathBuf, (u64, String)>,
+  previous_problem_count: Option<usize>,
   sender: tokio::sync::mpsc::UnboundedSender<ReceiverMessage>,
   handle: Mutex<Option<JoinHandle<()>>>,
 }
@@ -83,11 +136,16 @@ impl IncrementalCacheInner {
     initial_file_paths: &[PathBuf],
   ) -> Self {
     let mut previous_hashes = HashMap::new();
+    let mut previous_outputs = HashMap::new();
     for path in initial_file_paths {
       if let Some(hash) = cache.get_source_hash(path) {
         previous_hashes.insert(path.to_path_buf(), hash);
       }
+      if let Some(output) = cache.get_output(path) {
+        previous_outputs.insert(path.to_path_buf(), output);
+      }
     }
+    let previous_problem_count = cache.get_problem_count();
 
     let (sender, mut receiver) =
       tokio::sync::mpsc::unbounded_channel::<ReceiverMessage>();
@@ -99,6 +157,12 @@ impl IncrementalCacheInner {
           ReceiverMessage::Update(path, hash) => {
             let _ = cache.set_source_hash(&path, hash);
           }
+          ReceiverMessage::UpdateOutput(path, hash, output) => {
+            let _ = cache.set_output(&path, hash, &output);
+          }
+          ReceiverMessage::UpdateProblemCount(problem_count) => {
+            let _ = cache.set_problem_count(problem_count);
+          }
           ReceiverMessage::Exit => break,
         }
       }
@@ -106,6 +170,8 @@ impl IncrementalCacheInner {
 
     IncrementalCacheInner {
       previous_hashes,
+      previous_outputs,
+      previous_problem_count,
       sender,
       handle: Mutex::new(Some(handle)),
     }
@@ -118,6 +184,10 @@ impl IncrementalCacheInner {
     }
   }
 
+  pub fn was_file_cached(&self, file_path: &Path) -> bool {
+    self.previous_hashes.contains_key(file_path)
+  }
+
   pub fn update_file(&self, file_path: &Path, file_text: &str) {
     let hash = FastInsecureHasher::hash(file_text);
     if let Some(previous_hash) = self.previous_hashes.get(file_path) {
@@ -130,6 +200,49 @@ impl IncrementalCacheInner {
       .send(ReceiverMessage::Update(file_path.to_path_buf(), hash));
   }
 
+  pub fn previous_output(
+    &self,
+    file_path: &Path,
+    file_text: &str,
+  ) -> Option<&str> {
+    match self.previous_outputs.get(file_path) {
+      Some((hash, output)) if *hash == FastInsecureHasher::hash(file_text) => {
+        Some(output)
+      }
+      _ => None,
+    }
+  }
+
+  pub fn update_output(
+    &self,
+    file_path: &Path,
+    file_text: &str,
+    output: String,
+  ) {
+    let hash = FastInsecureHasher::hash(file_text);
+    if let Some((previous_hash, previous_output)) =
+      self.previous_outputs.get(file_path)
+    {
+      if *previous_hash == hash && *previous_output == output {
+        return; // do not bother updating the db file because nothing has changed
+      }
+    }
+    let _ = self.sender.send(ReceiverMessage::UpdateOutput(
+      file_path.to_path_buf(),
+      hash,
+      output,
+    ));
+  }
+
+  pub fn update_problem_count(&self, problem_count: usize) {
+    if self.previous_problem_count == Some(problem_count) {
+      return;
+    }
+    let _ = self
+      .sender
+      .send(ReceiverMessage::UpdateProblemCount(problem_count));
+  }
+
   pub async fn wait_completion(&self) {
     if self.sender.send(ReceiverMessage::Exit).is_err() {
       return;
@@ -212,6 +325,119 @@ impl SqlIncrementalCache {
     )?;
     Ok(())
   }
+
+  pub fn get_output(&self, path: &Path) -> Option<(u64, String)> {
+    match self.get_output_result(path) {
+      Ok(option) => option,
+      Err(err) => {
+        if cfg!(debug_assertions) {
+          panic!("Error retrieving output: {err}");
+        } else {
+          // fail silently when not debugging
+          None
+        }
+      }
+    }
+  }
+
+  fn get_output_result(
+    &self,
+    path: &Path,
+  ) -> Result<Option<(u64, String)>, AnyError> {
+    let query = "
+      SELECT
+        source_hash,
+        output
+      FROM
+        incrementalcacheoutput
+      WHERE
+        file_path=?1
+        AND state_hash=?2
+      LIMIT 1";
+    let res = self.conn.query_row(
+      query,
+      params![path.to_string_lossy(), self.state_hash.to_string()],
+      |row| {
+        let hash: String = row.get(0)?;
+        let output: String = row.get(1)?;
+        Ok((hash.parse::<u64>()?, output))
+      },
+    )?;
+    Ok(res)
+  }
+
+  pub fn set_output(
+    &self,
+    path: &Path,
+    source_hash: u64,
+    output: &str,
+  ) -> Result<(), AnyError> {
+    let sql = "
+      INSERT OR REPLACE INTO
+        incrementalcacheoutput (file_path, state_hash, source_hash, output)
+      VALUES
+        (?1, ?2, ?3, ?4)";
+    self.conn.execute(
+      sql,
+      params![
+        path.to_string_lossy(),
+        &self.state_hash.to_string(),
+        &source_hash,
+        output,
+      ],
+    )?;
+    Ok(())
+  }
+
+  pub fn get_problem_count(&self) -> Option<usize> {
+    match self.get_problem_count_result() {
+      Ok(option) => option,
+      Err(err) => {
+        if cfg!(debug_assertions) {
+          panic!("Error retrieving problem count: {err}");
+        } else {
+          // fail silently when not debugging
+          None
+        }
+      }
+    }
+  }
+
+  fn get_problem_count_result(&self) -> Result<Option<usize>, AnyError> {
+    let query = "
+      SELECT
+        problem_count
+      FROM
+        incrementalcachestats
+      WHERE
+        state_hash=?1
+      LIMIT 1";
+    let res = self.conn.query_row(
+      query,
+      params![self.state_hash.to_string()],
+      |row| {
+        let count: i64 = row.get(0)?;
+        Ok(count as usize)
+      },
+    )?;
+    Ok(res)
+  }
+
+  pub fn set_problem_count(
+    &self,
+    problem_count: usize,
+  ) -> Result<(), AnyError> {
+    let sql = "
+      INSERT OR REPLACE INTO
+        incrementalcachestats (state_hash, problem_count)
+      VALUES
+        (?1, ?2)";
+    self.conn.execute(
+      sql,
+      params![&self.state_hash.to_string(), problem_count as i64],
+    )?;
+    Ok(())
+  }
 }
 
 #[cfg(test)]
@@ -261,6 +487,45 @@ mod test {
     assert_eq!(cache.get_source_hash(&path2), Some(5));
   }
 
+  #[test]
+  pub fn sql_cache_problem_count() {
+    let conn = CacheDB::in_memory(&INCREMENTAL_CACHE_DB, "1.0.0");
+    let mut cache = SqlIncrementalCache::new(conn, 1);
+    assert_eq!(cache.get_problem_count(), None);
+    cache.set_problem_count(3).unwrap();
+    assert_eq!(cache.get_problem_count(), Some(3));
+
+    // the count is stored per state
+    cache.state_hash = 2;
+    assert_eq!(cache.get_problem_count(), None);
+    cache.state_hash = 1;
+
+    // changing the cli version clears it
+    let conn = cache.conn.recreate_with_version("2.0.0");
+    let cache = SqlIncrementalCache::new(conn, 1);
+    assert_eq!(cache.get_problem_count(), None);
+  }
+
+  #[test]
+  pub fn sql_cache_output() {
+    let conn = CacheDB::in_memory(&INCREMENTAL_CACHE_DB, "1.0.0");
+    let mut cache = SqlIncrementalCache::new(conn, 1);
+    let path = PathBuf::from("/mod.ts");
+    assert_eq!(cache.get_output(&path), None);
+    cache.set_output(&path, 2, "[]").unwrap();
+    assert_eq!(cache.get_output(&path), Some((2, "[]".to_string())));
+
+    // the output is stored per state
+    cache.state_hash = 2;
+    assert_eq!(cache.get_output(&path), None);
+    cache.state_hash = 1;
+
+    // changing the cli version clears it
+    let conn = cache.conn.recreate_with_version("2.0.0");
+    let cache = SqlIncrementalCache::new(conn, 1);
+    assert_eq!(cache.get_output(&path), None);
+  }
+
   #[tokio::test]
   pub async fn incremental_cache_general_use() {
     let conn = CacheDB::in_memory(&INCREMENTAL_CACHE_DB, "1.0.0");
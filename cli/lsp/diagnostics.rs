@@ -799,6 +799,7 @@ fn generate_lint_diagnostics(
     .documents(DocumentsFilter::OpenDiagnosable);
   let lint_rules = get_configured_rules(
     lint_options.rules.clone(),
+    lint_options.banned_imports.clone(),
     config.config_file.as_ref(),
   )
   .rules;
@@ -1586,7 +1587,6 @@ mod tests {
   use crate::lsp::documents::Documents;
   use crate::lsp::documents::LanguageId;
   use crate::lsp::language_server::StateSnapshot;
-  use deno_config::glob::FilePatterns;
   use pretty_assertions::assert_eq;
   use std::path::Path;
   use std::path::PathBuf;
@@ -1687,12 +1687,8 @@ let c: number = "a";
       Arc::new(GlobalHttpCache::new(cache_location, RealDenoCacheEnv));
     let ts_server = TsServer::new(Default::default(), cache);
     ts_server.start(None);
-    let lint_options = LintOptions {
-      rules: Default::default(),
-      files: FilePatterns::new_with_base(temp_dir.path().to_path_buf()),
-      reporter_kind: Default::default(),
-      fix: false,
-    };
+    let lint_options =
+      LintOptions::new_with_base(temp_dir.path().to_path_buf());
 
     // test enabled
     {
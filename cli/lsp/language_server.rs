@@ -94,6 +94,7 @@ use super::urls;
 use crate::args::get_root_cert_store;
 use crate::args::package_json;
 use crate::args::resolve_import_map;
+use crate::args::to_lint_config;
 use crate::args::CaData;
 use crate::args::CacheSetting;
 use crate::args::CliOptions;
@@ -1042,8 +1043,7 @@ impl Inner {
     self.fmt_options = FmtOptions::new_with_base(self.initial_cwd.clone());
     self.lint_options = LintOptions::new_with_base(self.initial_cwd.clone());
     if let Some(config_file) = self.get_config_file()? {
-      let lint_options = config_file
-        .to_lint_config()
+      let lint_options = to_lint_config(&config_file)
         .and_then(|maybe_lint_config| {
           LintOptions::resolve(maybe_lint_config, None, &self.initial_cwd)
         })
@@ -159,10 +159,17 @@ async fn run_subcommand(flags: Flags) -> Result<i32, AnyError> {
     DenoSubcommand::Lint(lint_flags) => spawn_subcommand(async {
       if lint_flags.rules {
         tools::lint::print_rules_list(
-          lint_flags.json,
+          lint_flags.reporter.json,
           lint_flags.maybe_rules_tags,
         );
         Ok(())
+      } else if let Some(code) = &lint_flags.explain {
+        tools::lint::explain_rule(code)
+      } else if let Some(expected) = &lint_flags.rules_hash {
+        tools::lint::check_rules_hash(expected.as_deref())
+      } else if lint_flags.print_json_schema {
+        tools::lint::print_json_schema();
+        Ok(())
       } else {
         tools::lint::lint(flags, lint_flags).await
       }
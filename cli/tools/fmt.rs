@@ -28,6 +28,7 @@ use deno_core::anyhow::Context;
 use deno_core::error::generic_error;
 use deno_core::error::AnyError;
 use deno_core::futures;
+use deno_core::futures::StreamExt;
 use deno_core::parking_lot::Mutex;
 use deno_core::unsync::spawn_blocking;
 use log::debug;
@@ -638,12 +639,28 @@ pub async fn run_parallelized<F>(
 where
   F: FnOnce(PathBuf) -> Result<(), AnyError> + Send + 'static + Clone,
 {
-  let handles = file_paths.iter().map(|file_path| {
-    let f = f.clone();
-    let file_path = file_path.clone();
-    spawn_blocking(move || f(file_path))
-  });
-  let join_results = futures::future::join_all(handles).await;
+  run_parallelized_bounded(file_paths, usize::MAX, f).await
+}
+
+/// Runs `f` for each file on a blocking task, spawning the task of a file
+/// only once fewer than `concurrency` tasks are running. The files are
+/// started in order, so a concurrency of 1 runs them one after another.
+pub async fn run_parallelized_bounded<F>(
+  file_paths: Vec<PathBuf>,
+  concurrency: usize,
+  f: F,
+) -> Result<(), AnyError>
+where
+  F: FnOnce(PathBuf) -> Result<(), AnyError> + Send + 'static + Clone,
+{
+  let join_results = futures::stream::iter(file_paths.iter().cloned())
+    .map(|file_path| {
+      let f = f.clone();
+      spawn_blocking(move || f(file_path))
+    })
+    .buffered(concurrency.max(1))
+    .collect::<Vec<_>>()
+    .await;
 
   // find the tasks that panicked and let the user know which files
   let panic_file_paths = join_results
@@ -0,0 +1,217 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::borrow::Cow;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_ast::diagnostics::DiagnosticLevel;
+use deno_ast::diagnostics::DiagnosticLocation;
+use deno_ast::diagnostics::DiagnosticSnippet;
+use deno_ast::diagnostics::DiagnosticSnippetHighlight;
+use deno_ast::diagnostics::DiagnosticSnippetHighlightStyle;
+use deno_ast::diagnostics::DiagnosticSourcePos;
+use deno_ast::diagnostics::DiagnosticSourceRange;
+use deno_ast::swc::ast::CallExpr;
+use deno_ast::swc::ast::Callee;
+use deno_ast::swc::ast::Expr;
+use deno_ast::swc::ast::Lit;
+use deno_ast::swc::ast::ModuleDecl;
+use deno_ast::swc::ast::Str;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use deno_ast::ModuleSpecifier;
+use deno_ast::ParsedSource;
+use deno_ast::SourceRange;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::SourceTextInfo;
+
+pub const NO_BANNED_IMPORTS_NAME: &str = "no-banned-imports";
+
+/// An import of a specifier that's banned via the `lint.bannedImports`
+/// configuration.
+#[derive(Debug, Clone)]
+pub struct BannedImportDiagnostic {
+  pub specifier: ModuleSpecifier,
+  pub text_info: SourceTextInfo,
+  pub range: SourceRange,
+  pub imported: String,
+  pub pattern: String,
+}
+
+/// Collects the imports of the parsed source that are banned by the
+/// patterns, which are the import and re-export declarations along with the
+/// dynamic imports and `require()` calls of a string literal.
+///
+/// A pattern matches a specifier when it's equal to it or one of its
+/// subpaths (ex. `lodash` matches `lodash/fp`). A trailing `*` matches any
+/// specifier with that prefix (ex. `npm:*`). A pattern starting with `!`
+/// allows the specifiers it matches again, so the last matching pattern
+/// decides, like in a `.gitignore` file (ex. `["*", "!node:*", "!./*"]`
+/// only allows `node:` and relative imports).
+pub fn collect_banned_import_diagnostics(
+  parsed_source: &ParsedSource,
+  banned_imports: &[String],
+) -> Vec<BannedImportDiagnostic> {
+  let mut collector = ImportCollector::default();
+  parsed_source.program_ref().visit_with(&mut collector);
+  collector
+    .imports
+    .into_iter()
+    .filter_map(|(imported, range)| {
+      let pattern = find_banning_pattern(banned_imports, &imported)?;
+      Some(BannedImportDiagnostic {
+        specifier: parsed_source.specifier().clone(),
+        text_info: parsed_source.text_info().clone(),
+        range,
+        imported,
+        pattern: pattern.to_string(),
+      })
+    })
+    .collect()
+}
+
+/// Collects the specifiers of the module's imports with their range, in the
+/// order they're in the source.
+#[derive(Default)]
+struct ImportCollector {
+  imports: Vec<(String, SourceRange)>,
+}
+
+impl ImportCollector {
+  fn add(&mut self, src: &Str) {
+    self.imports.push((src.value.to_string(), src.range()));
+  }
+}
+
+impl Visit for ImportCollector {
+  noop_visit_type!();
+
+  fn visit_module_decl(&mut self, module_decl: &ModuleDecl) {
+    match module_decl {
+      ModuleDecl::Import(import_decl) => self.add(&import_decl.src),
+      ModuleDecl::ExportAll(export_all) => self.add(&export_all.src),
+      ModuleDecl::ExportNamed(export_named) => {
+        if let Some(src) = &export_named.src {
+          self.add(src);
+        }
+      }
+      _ => {}
+    }
+    module_decl.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    let is_import = match &call_expr.callee {
+      Callee::Import(_) => true,
+      Callee::Expr(expr) => {
+        matches!(&**expr, Expr::Ident(ident) if ident.sym == "require")
+      }
+      Callee::Super(_) => false,
+    };
+    if is_import {
+      if let Some(arg) = call_expr.args.first() {
+        if let Expr::Lit(Lit::Str(src)) = &*arg.expr {
+          self.add(src);
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+/// Gets the last pattern that matches the specifier, unless it's a negated
+/// one that allows it.
+fn find_banning_pattern<'a>(
+  banned_imports: &'a [String],
+  imported: &str,
+) -> Option<&'a str> {
+  banned_imports
+    .iter()
+    .rev()
+    .find(|pattern| {
+      is_banned(pattern.strip_prefix('!').unwrap_or(pattern), imported)
+    })
+    .filter(|pattern| !pattern.starts_with('!'))
+    .map(|pattern| pattern.as_str())
+}
+
+impl Diagnostic for BannedImportDiagnostic {
+  fn level(&self) -> DiagnosticLevel {
+    DiagnosticLevel::Error
+  }
+
+  fn code(&self) -> Cow<'_, str> {
+    Cow::Borrowed(NO_BANNED_IMPORTS_NAME)
+  }
+
+  fn message(&self) -> Cow<'_, str> {
+    Cow::Owned(format!("Importing '{}' is not allowed", self.imported))
+  }
+
+  fn location(&self) -> DiagnosticLocation {
+    DiagnosticLocation::ModulePosition {
+      specifier: Cow::Borrowed(&self.specifier),
+      text_info: Cow::Borrowed(&self.text_info),
+      source_pos: DiagnosticSourcePos::SourcePos(self.range.start),
+    }
+  }
+
+  fn snippet(&self) -> Option<DiagnosticSnippet<'_>> {
+    Some(DiagnosticSnippet {
+      source: Cow::Borrowed(&self.text_info),
+      highlight: DiagnosticSnippetHighlight {
+        style: DiagnosticSnippetHighlightStyle::Error,
+        range: DiagnosticSourceRange {
+          start: DiagnosticSourcePos::SourcePos(self.range.start),
+          end: DiagnosticSourcePos::SourcePos(self.range.end),
+        },
+        description: Some("the banned specifier".into()),
+      },
+    })
+  }
+
+  fn hint(&self) -> Option<Cow<'_, str>> {
+    Some(Cow::Owned(format!(
+      "Remove this import or change the 'lint.bannedImports' pattern '{}' in the config file",
+      self.pattern
+    )))
+  }
+
+  fn snippet_fixed(&self) -> Option<DiagnosticSnippet<'_>> {
+    None
+  }
+
+  fn info(&self) -> Cow<'_, [Cow<'_, str>]> {
+    Cow::Borrowed(&[])
+  }
+
+  fn docs_url(&self) -> Option<Cow<'_, str>> {
+    None
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn banned_patterns() {
+    assert!(is_banned("lodash", "lodash"));
+    assert!(is_banned("lodash", "lodash/fp"));
+    assert!(!is_banned("lodash", "lodash-es"));
+    assert!(is_banned("npm:*", "npm:chalk@5"));
+    assert!(!is_banned("npm:*", "jsr:@std/path"));
+  }
+
+  #[test]
+  fn negated_patterns() {
+    let patterns =
+      ["*", "!node:*", "!./*", "lodash", "!lodash/debounce"].map(String::from);
+    assert_eq!(find_banning_pattern(&patterns, "fs"), Some("*"));
+    assert_eq!(find_banning_pattern(&patterns, "node:fs"), None);
+    assert_eq!(find_banning_pattern(&patterns, "./mod.ts"), None);
+    assert_eq!(find_banning_pattern(&patterns, "lodash/fp"), Some("lodash"));
+    assert_eq!(find_banning_pattern(&patterns, "lodash/debounce"), None);
+    assert_eq!(find_banning_pattern(&[], "lodash"), None);
+  }
+}
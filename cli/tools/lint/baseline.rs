@@ -0,0 +1,120 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::parking_lot::Mutex;
+use deno_core::serde_json;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::fingerprint::Fingerprint;
+use super::LintOrCliDiagnostic;
+
+// WARNING: Ensure doesn't change because it's used in the baseline file
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+  version: u32,
+  fingerprints: Vec<Fingerprint>,
+}
+
+// bumped when the fingerprints change, as the ones of an older baseline
+// would no longer suppress anything
+const BASELINE_VERSION: u32 = 2;
+
+/// The problems that existed when a rule was adopted, which aren't reported
+/// with `--baseline` so that only the new ones fail the run. Problems with
+/// the same rule on identical lines of a file share a fingerprint, so the
+/// baseline suppresses all of them.
+pub struct LintBaseline {
+  path: PathBuf,
+  cwd: PathBuf,
+  known: HashSet<Fingerprint>,
+  /// The fingerprints of all problems of the run with `--update-baseline`,
+  /// which are written to the baseline rather than reported.
+  update: Option<Mutex<BTreeSet<Fingerprint>>>,
+}
+
+impl LintBaseline {
+  pub fn load(path: &Path, cwd: PathBuf) -> Result<Self, AnyError> {
+    let text = fs::read_to_string(path)
+      .with_context(|| format!("Failed reading '{}'.", path.display()))?;
+    let file: BaselineFile =
+      serde_json::from_str(&text).with_context(|| {
+        format!(
+          "Failed parsing '{}' as a lint baseline. Run with --update-baseline to write it again.",
+          path.display()
+        )
+      })?;
+    if file.version != BASELINE_VERSION {
+      bail!(
+        "The lint baseline '{}' is of an unsupported version {}. Run with --update-baseline to write it again.",
+        path.display(),
+        file.version
+      );
+    }
+    Ok(Self {
+      path: path.to_path_buf(),
+      cwd,
+      known: file.fingerprints.into_iter().collect(),
+      update: None,
+    })
+  }
+
+  pub fn for_update(path: &Path, cwd: PathBuf) -> Self {
+    Self {
+      path: path.to_path_buf(),
+      cwd,
+      known: HashSet::new(),
+      update: Some(Default::default()),
+    }
+  }
+
+  /// Removes the problems in the baseline, or records all problems when
+  /// updating it.
+  pub fn suppress<T>(
+    &self,
+    diagnostics: &mut Vec<T>,
+    as_diagnostic: impl Fn(&T) -> LintOrCliDiagnostic<'_>,
+  ) {
+    match &self.update {
+      Some(update) => {
+        update.lock().extend(
+          diagnostics
+            .iter()
+            .map(|d| self.fingerprint(&as_diagnostic(d))),
+        );
+        diagnostics.clear();
+      }
+      None => diagnostics
+        .retain(|d| !self.known.contains(&self.fingerprint(&as_diagnostic(d)))),
+    }
+  }
+
+  /// Writes the recorded problems sorted, so that the baseline diffs well
+  /// in version control, returning how many were written.
+  pub fn write(&self) -> Result<Option<usize>, AnyError> {
+    let Some(update) = &self.update else {
+      return Ok(None);
+    };
+    let fingerprints = std::mem::take(&mut *update.lock());
+    let count = fingerprints.len();
+    let file = BaselineFile {
+      version: BASELINE_VERSION,
+      fingerprints: fingerprints.into_iter().collect(),
+    };
+    fs::write(&self.path, serde_json::to_string_pretty(&file)? + "\n")
+      .with_context(|| format!("Failed writing '{}'.", self.path.display()))?;
+    Ok(Some(count))
+  }
+
+  fn fingerprint(&self, diagnostic: &LintOrCliDiagnostic) -> Fingerprint {
+    Fingerprint::from_diagnostic(diagnostic, &self.cwd)
+  }
+}
@@ -0,0 +1,181 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::error::AnyError;
+
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::colors;
+use crate::util::path::specifier_to_file_path;
+
+/// Author of the lines that git can't attribute, like the ones of files
+/// outside of a repository.
+const UNKNOWN_AUTHOR: &str = "Unknown";
+
+struct BlamedDiagnostic {
+  line: usize,
+  column: usize,
+  code: String,
+  message: String,
+}
+
+/// Groups the problems by the author of the last commit that changed their
+/// line, to route lint debt to the owners of the code. Running `git blame`
+/// is expensive, so it runs once per file when the reporter is closed.
+#[derive(Default)]
+pub struct BlameLintReporter {
+  /// Diagnostics by file path, which are the ones with a range in a file.
+  diagnostics: BTreeMap<String, Vec<BlamedDiagnostic>>,
+}
+
+impl LintReporter for BlameLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    let Some((text_info, range)) = d.range() else {
+      return;
+    };
+    let Ok(path) = specifier_to_file_path(d.specifier()) else {
+      return;
+    };
+    let position = text_info.line_and_column_display(range.start);
+    self
+      .diagnostics
+      .entry(path.display().to_string())
+      .or_default()
+      .push(BlamedDiagnostic {
+        line: position.line_number,
+        column: position.column_number,
+        code: d.code().to_string(),
+        message: d.message().to_string(),
+      });
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {}
+
+  fn close(&mut self, _check_count: usize) {
+    if self.diagnostics.is_empty() {
+      return;
+    }
+    let mut by_author: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (file_path, diagnostics) in &self.diagnostics {
+      let authors = blame_file(Path::new(file_path)).unwrap_or_default();
+      for d in diagnostics {
+        let author = authors
+          .get(&d.line)
+          .map(|a| a.as_str())
+          .unwrap_or(UNKNOWN_AUTHOR);
+        by_author
+          .entry(author.to_string())
+          .or_default()
+          .push(format!(
+            "{}:{}:{} - {} ({})",
+            file_path, d.line, d.column, d.message, d.code
+          ));
+      }
+    }
+    eprintln!("{}", colors::bold("Problems by author:"));
+    for (author, problems) in by_author {
+      eprintln!(
+        "  {} ({} {})",
+        colors::cyan(&author),
+        problems.len(),
+        if problems.len() == 1 {
+          "problem"
+        } else {
+          "problems"
+        }
+      );
+      for problem in problems {
+        eprintln!("    {}", problem);
+      }
+    }
+  }
+}
+
+/// Gets the author of each line of the file, by 1-indexed line number, or
+/// `None` when git can't blame it.
+fn blame_file(path: &Path) -> Option<HashMap<usize, String>> {
+  let output = Command::new("git")
+    .arg("blame")
+    .arg("--line-porcelain")
+    .arg("--")
+    .arg(path.file_name()?)
+    .current_dir(path.parent()?)
+    .output()
+    .ok()?;
+  if !output.status.success() {
+    return None;
+  }
+  Some(parse_line_porcelain(&String::from_utf8_lossy(
+    &output.stdout,
+  )))
+}
+
+/// Parses the output of `git blame --line-porcelain`, where each line of the
+/// file starts with a `<sha> <original line> <final line>` header followed
+/// by the details of its commit.
+fn parse_line_porcelain(output: &str) -> HashMap<usize, String> {
+  let mut authors = HashMap::new();
+  let mut line = None;
+  let mut name = None;
+  for text in output.lines() {
+    if text.starts_with('\t') {
+      // the content of the line ends its entry
+      line = None;
+      name = None;
+    } else if let Some(author) = text.strip_prefix("author ") {
+      name = Some(author.to_string());
+    } else if let Some(mail) = text.strip_prefix("author-mail ") {
+      if let (Some(line), Some(name)) = (line, name.as_ref()) {
+        authors.insert(line, format!("{} {}", name, mail));
+      }
+    } else {
+      let mut parts = text.split(' ');
+      let is_header = parts.next().is_some_and(|sha| {
+        sha.len() >= 40 && sha.chars().all(|c| c.is_ascii_hexdigit())
+      });
+      if is_header {
+        line = parts.nth(1).and_then(|l| l.parse::<usize>().ok());
+      }
+    }
+  }
+  authors
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parses_line_porcelain() {
+    let output = "\
+5c1f2e3d4b5a69788796a5b4c3d2e1f0a9b8c7d6 1 1 2
+author Jane Doe
+author-mail <jane@example.com>
+author-time 1700000000
+summary Initial commit
+filename main.ts
+\tdebugger;
+5c1f2e3d4b5a69788796a5b4c3d2e1f0a9b8c7d6 2 2
+author Jane Doe
+author-mail <jane@example.com>
+summary Initial commit
+filename main.ts
+\tconst a = 1;
+0000000000000000000000000000000000000000 3 3 1
+author Not Committed Yet
+author-mail <not.committed.yet>
+filename main.ts
+\tdebugger;
+";
+    let authors = parse_line_porcelain(output);
+    assert_eq!(authors.len(), 3);
+    assert_eq!(authors[&1], "Jane Doe <jane@example.com>");
+    assert_eq!(authors[&2], "Jane Doe <jane@example.com>");
+    assert_eq!(authors[&3], "Not Committed Yet <not.committed.yet>");
+  }
+}
@@ -0,0 +1,111 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::error::AnyError;
+
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::colors;
+use crate::util::sync::AtomicFlag;
+
+/// Counts the problems of each rule and fails the run only when a rule with
+/// a budget has more problems than it, which is configured with the
+/// "budgets" key of the lint configuration. Rules without a budget never
+/// fail the run.
+pub struct BudgetLintReporter {
+  budgets: HashMap<String, usize>,
+  counts: HashMap<String, usize>,
+  /// Raised when a rule is over its budget or a file failed to be linted.
+  is_over_budget: Arc<AtomicFlag>,
+}
+
+impl BudgetLintReporter {
+  pub fn new(
+    budgets: HashMap<String, usize>,
+    is_over_budget: Arc<AtomicFlag>,
+  ) -> Self {
+    Self {
+      budgets,
+      counts: HashMap::new(),
+      is_over_budget,
+    }
+  }
+}
+
+/// The rules that have more problems than their budget along with their
+/// number of problems and budget, sorted by rule.
+fn over_budget_rules<'a>(
+  budgets: &'a HashMap<String, usize>,
+  counts: &HashMap<String, usize>,
+) -> Vec<(&'a str, usize, usize)> {
+  let mut rules = budgets
+    .iter()
+    .filter_map(|(code, budget)| {
+      let count = counts.get(code).copied().unwrap_or(0);
+      (count > *budget).then_some((code.as_str(), count, *budget))
+    })
+    .collect::<Vec<_>>();
+  rules.sort();
+  rules
+}
+
+impl LintReporter for BudgetLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    *self.counts.entry(d.code().to_string()).or_default() += 1;
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {
+    // whether the file's problems fit in the budgets is unknown
+    self.is_over_budget.raise();
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    let rules = over_budget_rules(&self.budgets, &self.counts);
+    if rules.is_empty() {
+      return;
+    }
+    eprintln!(
+      "{}",
+      colors::red_bold(format!(
+        "{} {} over budget:",
+        rules.len(),
+        if rules.len() == 1 {
+          "rule is"
+        } else {
+          "rules are"
+        }
+      ))
+    );
+    for (code, count, budget) in rules {
+      eprintln!("  {}: {} problems, budget {}", code, count, budget);
+    }
+    self.is_over_budget.raise();
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn finds_over_budget_rules() {
+    let budgets = HashMap::from([
+      ("no-explicit-any".to_string(), 2),
+      ("no-unused-vars".to_string(), 0),
+      ("prefer-const".to_string(), 1),
+    ]);
+    let counts = HashMap::from([
+      ("no-explicit-any".to_string(), 2),
+      ("no-unused-vars".to_string(), 1),
+      ("prefer-const".to_string(), 3),
+      ("no-debugger".to_string(), 10),
+    ]);
+    assert_eq!(
+      over_budget_rules(&budgets, &counts),
+      vec![("no-unused-vars", 1, 0), ("prefer-const", 3, 1)]
+    );
+  }
+}
@@ -0,0 +1,152 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_ast::diagnostics::DiagnosticLevel;
+use deno_core::error::AnyError;
+
+use super::print_lint_error;
+use super::xml::escape_xml;
+use super::FileDisplay;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+struct CheckstyleError {
+  line: usize,
+  column: Option<usize>,
+  severity: &'static str,
+  message: String,
+  source: String,
+}
+
+/// Prints a Checkstyle XML report, which CI servers like Jenkins parse, with
+/// the problems grouped by file when closed.
+pub struct CheckstyleLintReporter {
+  warning_rules: HashSet<String>,
+  file_display: FileDisplay,
+  files: BTreeMap<String, Vec<CheckstyleError>>,
+}
+
+impl CheckstyleLintReporter {
+  pub fn new(
+    warning_rules: HashSet<String>,
+    file_display: FileDisplay,
+  ) -> Self {
+    Self {
+      warning_rules,
+      file_display,
+      files: BTreeMap::new(),
+    }
+  }
+}
+
+impl LintReporter for CheckstyleLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    // the diagnostics without a range are on line 0, as Checkstyle requires
+    // a line
+    let (line, column) = match d.range() {
+      Some((text_info, range)) => {
+        let position = text_info.line_and_column_display(range.start);
+        (position.line_number, Some(position.column_number))
+      }
+      None => (0, None),
+    };
+    let severity = if self.warning_rules.contains(d.code().as_ref()) {
+      "warning"
+    } else {
+      match d.level() {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+      }
+    };
+    let filename = self.file_display.display(d.specifier());
+    self
+      .files
+      .entry(filename)
+      .or_default()
+      .push(CheckstyleError {
+        line,
+        column,
+        severity,
+        message: d.message().to_string(),
+        source: d.code().to_string(),
+      });
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    // the report only holds problems, so errors go to stderr
+    print_lint_error(file_path, err);
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    print!("{}", format_checkstyle(&self.files));
+  }
+}
+
+fn format_checkstyle(files: &BTreeMap<String, Vec<CheckstyleError>>) -> String {
+  let mut xml = String::new();
+  writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+  writeln!(xml, r#"<checkstyle version="4.3">"#).unwrap();
+  for (filename, errors) in files {
+    writeln!(xml, r#"  <file name="{}">"#, escape_xml(filename)).unwrap();
+    for error in errors {
+      write!(xml, r#"    <error line="{}""#, error.line).unwrap();
+      if let Some(column) = error.column {
+        write!(xml, r#" column="{}""#, column).unwrap();
+      }
+      writeln!(
+        xml,
+        r#" severity="{}" message="{}" source="{}"/>"#,
+        error.severity,
+        escape_xml(&error.message),
+        escape_xml(&error.source)
+      )
+      .unwrap();
+    }
+    writeln!(xml, "  </file>").unwrap();
+  }
+  writeln!(xml, "</checkstyle>").unwrap();
+  xml
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn formats_checkstyle() {
+    let files = BTreeMap::from([(
+      "main.ts".to_string(),
+      vec![
+        CheckstyleError {
+          line: 2,
+          column: Some(7),
+          severity: "error",
+          message: "`a` is never used".to_string(),
+          source: "no-unused-vars".to_string(),
+        },
+        CheckstyleError {
+          line: 0,
+          column: None,
+          severity: "warning",
+          message: "Import of \"lodash\" <banned>".to_string(),
+          source: "no-banned-imports".to_string(),
+        },
+      ],
+    )]);
+    assert_eq!(
+      format_checkstyle(&files),
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="4.3">
+  <file name="main.ts">
+    <error line="2" column="7" severity="error" message="`a` is never used" source="no-unused-vars"/>
+    <error line="0" severity="warning" message="Import of &quot;lodash&quot; &lt;banned&gt;" source="no-banned-imports"/>
+  </file>
+</checkstyle>
+"#
+    );
+  }
+}
@@ -0,0 +1,237 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Reporters for the output formats of CI systems, which are picked
+//! automatically when linting on them.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use log::info;
+use serde::Serialize;
+
+use super::fingerprint::relative_filename;
+use super::fingerprint::FingerprintIds;
+use super::print_lint_error;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+/// Escapes the message of a workflow command.
+fn escape_data(text: &str) -> String {
+  text
+    .replace('%', "%25")
+    .replace('\r', "%0D")
+    .replace('\n', "%0A")
+}
+
+/// Escapes a property of a workflow command, like the file.
+fn escape_property(text: &str) -> String {
+  escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Prints a GitHub Actions `::error` or `::warning` workflow command for each
+/// problem, which GitHub shows as annotations on the changed lines.
+pub struct GithubActionsLintReporter {
+  warning_rules: HashSet<String>,
+  cwd: PathBuf,
+  lint_count: usize,
+}
+
+impl GithubActionsLintReporter {
+  pub fn new(warning_rules: HashSet<String>) -> Self {
+    Self {
+      warning_rules,
+      cwd: std::env::current_dir().unwrap_or_default(),
+      lint_count: 0,
+    }
+  }
+
+  fn format_diagnostic(&self, d: &LintOrCliDiagnostic) -> String {
+    let command = if self.warning_rules.contains(d.code().as_ref()) {
+      "warning"
+    } else {
+      "error"
+    };
+    let path = relative_filename(d.specifier(), &self.cwd);
+    let message = match d.hint() {
+      Some(hint) => format!("{}\n{}", d.message(), hint),
+      None => d.message().to_string(),
+    };
+    let Some((text_info, range)) = d.range() else {
+      // annotations need a position, so the problem is only logged
+      return format!(
+        "::{}::{}",
+        command,
+        escape_data(&format!("{}: {} ({})", path, message, d.code()))
+      );
+    };
+    let start = text_info.line_and_column_display(range.start);
+    let end = text_info.line_and_column_display(range.end);
+    let properties = [
+      format!("file={}", escape_property(&path)),
+      format!("line={}", start.line_number),
+      format!("col={}", start.column_number),
+      format!("endLine={}", end.line_number),
+      format!("endColumn={}", end.column_number),
+      format!("title={}", escape_property(&d.code())),
+    ];
+    format!(
+      "::{} {}::{}",
+      command,
+      properties.join(","),
+      escape_data(&message)
+    )
+  }
+}
+
+impl LintReporter for GithubActionsLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.lint_count += 1;
+    println!("{}", self.format_diagnostic(&d));
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    println!(
+      "::error::{}",
+      escape_data(&format!("Error linting {}: {:#}", file_path, err))
+    );
+  }
+
+  fn close(&mut self, check_count: usize) {
+    match self.lint_count {
+      0 => {}
+      1 => info!("Found 1 problem"),
+      n => info!("Found {} problems", n),
+    }
+    match check_count {
+      1 => info!("Checked 1 file"),
+      n => info!("Checked {} files", n),
+    }
+  }
+}
+
+#[derive(Serialize)]
+struct GitlabLines {
+  begin: usize,
+  end: usize,
+}
+
+#[derive(Serialize)]
+struct GitlabLocation {
+  path: String,
+  lines: GitlabLines,
+}
+
+// WARNING: Ensure doesn't change because GitLab reads it as a Code Quality
+// report
+#[derive(Serialize)]
+struct GitlabIssue {
+  description: String,
+  check_name: String,
+  fingerprint: String,
+  severity: &'static str,
+  location: GitlabLocation,
+}
+
+/// Prints a GitLab Code Quality report, which is a JSON array of issues, when
+/// closed. The job saves it with `deno lint > gl-code-quality-report.json`
+/// and the `artifacts:reports:codequality` key.
+pub struct GitlabLintReporter {
+  warning_rules: HashSet<String>,
+  cwd: PathBuf,
+  ids: FingerprintIds,
+  issues: Vec<GitlabIssue>,
+}
+
+impl GitlabLintReporter {
+  pub fn new(warning_rules: HashSet<String>) -> Self {
+    Self {
+      warning_rules,
+      cwd: std::env::current_dir().unwrap_or_default(),
+      ids: Default::default(),
+      issues: Vec::new(),
+    }
+  }
+}
+
+impl LintReporter for GitlabLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    let path = relative_filename(d.specifier(), &self.cwd);
+    let (begin, end) = match d.range() {
+      Some((text_info, range)) => (
+        text_info.line_and_column_display(range.start).line_number,
+        text_info.line_and_column_display(range.end).line_number,
+      ),
+      None => (1, 1),
+    };
+    // stable across runs so GitLab can tell new issues from existing ones
+    let fingerprint = self.ids.next_id(&d, &self.cwd);
+    self.issues.push(GitlabIssue {
+      description: d.message().to_string(),
+      check_name: d.code().to_string(),
+      fingerprint,
+      severity: if self.warning_rules.contains(d.code().as_ref()) {
+        "minor"
+      } else {
+        "major"
+      },
+      location: GitlabLocation {
+        path,
+        lines: GitlabLines { begin, end },
+      },
+    });
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    // the report only holds issues, so errors go to stderr
+    print_lint_error(file_path, err);
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    println!("{}", serde_json::to_string_pretty(&self.issues).unwrap());
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::ModuleSpecifier;
+  use deno_ast::SourceRange;
+  use deno_ast::SourceTextInfo;
+
+  use super::super::banned_imports::BannedImportDiagnostic;
+  use super::*;
+
+  #[test]
+  fn formats_workflow_commands() {
+    let text_info =
+      SourceTextInfo::from_string("// a\nimport \"lodash\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::parse("https://deno.land/x/a,b.ts").unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 12, start + 20),
+      imported: "lodash".to_string(),
+      pattern: "lodash".to_string(),
+    };
+    let d = LintOrCliDiagnostic::BannedImport(&diagnostic);
+    let reporter = GithubActionsLintReporter::new(HashSet::new());
+    let command = reporter.format_diagnostic(&d);
+    assert!(command.starts_with(
+      "::error file=https%3A//deno.land/x/a%2Cb.ts,line=2,col=8,endLine=2,endColumn=16,title=no-banned-imports::"
+    ));
+    assert!(!command.contains('\n'));
+
+    let reporter = GithubActionsLintReporter::new(HashSet::from([
+      "no-banned-imports".to_string(),
+    ]));
+    assert!(reporter.format_diagnostic(&d).starts_with("::warning "));
+  }
+
+  #[test]
+  fn escapes_workflow_commands() {
+    assert_eq!(escape_data("50%\r\nb: c,d"), "50%25%0D%0Ab: c,d");
+    assert_eq!(escape_property("50%\nb: c,d"), "50%25%0Ab%3A c%2Cd");
+  }
+}
@@ -0,0 +1,198 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Deserialize;
+
+use super::fingerprint::normalize_filename;
+use super::fingerprint::relative_filename;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::colors;
+use crate::util::sync::AtomicFlag;
+
+/// Matches a problem across runs. The `--json` output doesn't have the text
+/// of the line of a problem to build its fingerprint from, so the message
+/// takes the place of the line hash.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct ProblemKey {
+  file: String,
+  code: String,
+  message: String,
+}
+
+#[derive(Deserialize)]
+struct PreviousDiagnostic {
+  filename: String,
+  code: String,
+  message: String,
+}
+
+#[derive(Deserialize)]
+struct PreviousRun {
+  diagnostics: Vec<PreviousDiagnostic>,
+}
+
+/// Compares the problems of the run with the ones in the `--json` output of
+/// a previous run and prints which ones are new, resolved or unchanged.
+pub struct CompareLintReporter {
+  previous_path: PathBuf,
+  previous: Vec<ProblemKey>,
+  current: Vec<ProblemKey>,
+  cwd: PathBuf,
+  /// Raised when there are new problems or a file failed to be linted, which
+  /// fails the run.
+  has_new_problems: Arc<AtomicFlag>,
+}
+
+impl CompareLintReporter {
+  pub fn new(
+    previous_path: &Path,
+    cwd: PathBuf,
+    has_new_problems: Arc<AtomicFlag>,
+  ) -> Result<Self, AnyError> {
+    let text = std::fs::read_to_string(previous_path).with_context(|| {
+      format!("Failed reading '{}'.", previous_path.display())
+    })?;
+    let previous_run: PreviousRun =
+      serde_json::from_str(&text).with_context(|| {
+        format!(
+          "Failed parsing '{}' as the --json output of deno lint.",
+          previous_path.display()
+        )
+      })?;
+    let previous = previous_run
+      .diagnostics
+      .into_iter()
+      .map(|d| ProblemKey {
+        file: normalize_filename(&d.filename, &cwd),
+        code: d.code,
+        message: d.message,
+      })
+      .collect();
+    Ok(Self {
+      previous_path: previous_path.to_path_buf(),
+      previous,
+      current: Vec::new(),
+      cwd,
+      has_new_problems,
+    })
+  }
+}
+
+/// The problems of the current run that aren't in the previous run and the
+/// problems of the previous run that aren't in the current run. Problems with
+/// the same key are matched by count.
+fn diff_problems(
+  previous: &[ProblemKey],
+  current: &[ProblemKey],
+) -> (Vec<ProblemKey>, Vec<ProblemKey>) {
+  let mut remaining = HashMap::<&ProblemKey, usize>::new();
+  for problem in previous {
+    *remaining.entry(problem).or_default() += 1;
+  }
+  let mut new = Vec::new();
+  for problem in current {
+    match remaining.get_mut(problem) {
+      Some(count) if *count > 0 => *count -= 1,
+      _ => new.push(problem.clone()),
+    }
+  }
+  let mut resolved = Vec::new();
+  for problem in previous {
+    if let Some(count) = remaining.get_mut(problem) {
+      if *count > 0 {
+        *count -= 1;
+        resolved.push(problem.clone());
+      }
+    }
+  }
+  new.sort();
+  resolved.sort();
+  (new, resolved)
+}
+
+impl LintReporter for CompareLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.current.push(ProblemKey {
+      file: relative_filename(d.specifier(), &self.cwd),
+      code: d.code().to_string(),
+      message: d.message().to_string(),
+    });
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {
+    // whether the file has new problems is unknown
+    self.has_new_problems.raise();
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    let (new, resolved) = diff_problems(&self.previous, &self.current);
+    let print_problems = |title: String, problems: &[ProblemKey]| {
+      if problems.is_empty() {
+        return;
+      }
+      eprintln!("{}", title);
+      for problem in problems {
+        eprintln!("  {} {}: {}", problem.file, problem.code, problem.message);
+      }
+    };
+    print_problems(
+      colors::red_bold(format!("New problems ({}):", new.len())).to_string(),
+      &new,
+    );
+    print_problems(
+      colors::green_bold(format!("Resolved problems ({}):", resolved.len()))
+        .to_string(),
+      &resolved,
+    );
+    eprintln!(
+      "Compared with '{}': {} new, {} resolved, {} unchanged",
+      self.previous_path.display(),
+      new.len(),
+      resolved.len(),
+      self.current.len() - new.len()
+    );
+    if !new.is_empty() {
+      self.has_new_problems.raise();
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn problem(code: &str, message: &str) -> ProblemKey {
+    ProblemKey {
+      file: "main.ts".to_string(),
+      code: code.to_string(),
+      message: message.to_string(),
+    }
+  }
+
+  #[test]
+  fn diffs_problems_by_count() {
+    let unused = problem("no-unused-vars", "`a` is never used");
+    let debugger = problem("no-debugger", "`debugger` statement");
+    let constant = problem("prefer-const", "`b` is never reassigned");
+    let (new, resolved) = diff_problems(
+      &[unused.clone(), debugger.clone(), debugger.clone()],
+      &[
+        debugger.clone(),
+        unused.clone(),
+        unused.clone(),
+        constant.clone(),
+      ],
+    );
+    assert_eq!(new, vec![unused, constant]);
+    assert_eq!(resolved, vec![debugger]);
+  }
+}
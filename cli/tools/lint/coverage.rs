@@ -0,0 +1,72 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_config::glob::FilePatterns;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+
+use super::matches_test_files;
+use crate::args::LintOptions;
+use crate::util::fs::FileCollector;
+use crate::util::path::is_script_ext;
+
+/// Fails when a script file under `root` is neither linted nor skipped on
+/// purpose by an exclude pattern, so new directories can't silently escape
+/// the include patterns of `--require-coverage`.
+pub fn check_lint_coverage(
+  lint_options: &LintOptions,
+  root: &Path,
+  target_files: &[PathBuf],
+) -> Result<(), AnyError> {
+  let uncovered = find_uncovered_files(lint_options, root, target_files)?;
+  if uncovered.is_empty() {
+    return Ok(());
+  }
+  bail!(
+    "Found {} script {} not covered by the lint include patterns:\n{}",
+    uncovered.len(),
+    if uncovered.len() == 1 {
+      "file"
+    } else {
+      "files"
+    },
+    uncovered
+      .iter()
+      .map(|path| {
+        let path = path.strip_prefix(root).unwrap_or(path);
+        format!("  {}", path.display())
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  );
+}
+
+fn find_uncovered_files(
+  lint_options: &LintOptions,
+  root: &Path,
+  target_files: &[PathBuf],
+) -> Result<Vec<PathBuf>, AnyError> {
+  let test_files = lint_options.filter.test_files;
+  // the same folders as the ones never linted are skipped
+  let all_files = FileCollector::new(move |e| {
+    is_script_ext(e.path) && matches_test_files(e.path, test_files)
+  })
+  .ignore_git_folder()
+  .ignore_node_modules()
+  .ignore_vendor_folder()
+  .set_ignored_paths(lint_options.filter.third_party.clone())
+  .collect_file_patterns(FilePatterns::new_with_base(root.to_path_buf()))?;
+  let target_files = target_files.iter().collect::<HashSet<_>>();
+  let mut uncovered = all_files
+    .into_iter()
+    .filter(|path| {
+      !target_files.contains(path)
+        && !lint_options.files.exclude.matches_path(path)
+    })
+    .collect::<Vec<_>>();
+  uncovered.sort();
+  Ok(uncovered)
+}
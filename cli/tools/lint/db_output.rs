@@ -0,0 +1,149 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::path::Path;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_runtime::deno_webstorage::rusqlite::params;
+use deno_runtime::deno_webstorage::rusqlite::Connection;
+
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+const TABLE_INITIALIZER: &str = "CREATE TABLE IF NOT EXISTS lint_diagnostics (
+    run_timestamp TEXT NOT NULL,
+    file TEXT NOT NULL,
+    code TEXT NOT NULL,
+    message TEXT NOT NULL,
+    line INTEGER,
+    col INTEGER
+  );";
+
+/// Records every diagnostic of a run into a SQLite database so lint
+/// results can be tracked over time. All rows of a run are written in
+/// a single transaction that is committed when the reporter is closed.
+pub struct SqliteLintReporter {
+  conn: Connection,
+  run_timestamp: String,
+  has_failed: bool,
+}
+
+impl SqliteLintReporter {
+  pub fn new(path: &Path) -> Result<Self, AnyError> {
+    let conn = Connection::open(path).with_context(|| {
+      format!("Failed opening lint database '{}'.", path.display())
+    })?;
+    conn.execute_batch(TABLE_INITIALIZER)?;
+    conn.execute_batch("BEGIN TRANSACTION;")?;
+    Ok(Self {
+      conn,
+      run_timestamp: crate::util::time::utc_now().to_rfc3339(),
+      has_failed: false,
+    })
+  }
+
+  fn warn_on_failure(&mut self, result: Result<(), AnyError>) {
+    if let Err(err) = result {
+      if !self.has_failed {
+        log::warn!("Failed writing lint results to database: {:#}", err);
+      }
+      self.has_failed = true;
+    }
+  }
+}
+
+impl LintReporter for SqliteLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    // lines and columns are stored 1-indexed
+    let (line, col) = match d.range() {
+      Some((text_info, range)) => {
+        let line_and_column = text_info.line_and_column_display(range.start);
+        (
+          Some(line_and_column.line_number),
+          Some(line_and_column.column_number),
+        )
+      }
+      None => (None, None),
+    };
+    let result = self
+      .conn
+      .execute(
+        "INSERT INTO lint_diagnostics (run_timestamp, file, code, message, line, col) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+          self.run_timestamp,
+          d.specifier().as_str(),
+          d.code().to_string(),
+          d.message().to_string(),
+          line,
+          col,
+        ],
+      )
+      .map(|_| ())
+      .map_err(AnyError::from);
+    self.warn_on_failure(result);
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {}
+
+  fn close(&mut self, _check_count: usize) {
+    let result = self.conn.execute_batch("COMMIT;").map_err(AnyError::from);
+    self.warn_on_failure(result);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::ModuleSpecifier;
+  use deno_ast::SourceRange;
+  use deno_ast::SourceTextInfo;
+  use test_util::TempDir;
+
+  use super::super::banned_imports::BannedImportDiagnostic;
+  use super::*;
+
+  #[test]
+  fn records_diagnostics_of_every_run() {
+    let temp_dir = TempDir::new();
+    let db_path = temp_dir.path().join("lint.db");
+    let text_info =
+      SourceTextInfo::from_string("// a\nimport \"lodash\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 12, start + 20),
+      imported: "lodash".to_string(),
+      pattern: "lodash".to_string(),
+    };
+    for _ in 0..2 {
+      let mut reporter = SqliteLintReporter::new(db_path.as_path()).unwrap();
+      reporter.visit_diagnostic(LintOrCliDiagnostic::BannedImport(&diagnostic));
+      reporter.close(1);
+    }
+
+    let conn = Connection::open(db_path.as_path()).unwrap();
+    let mut stmt = conn
+      .prepare("SELECT file, code, line, col FROM lint_diagnostics")
+      .unwrap();
+    let rows = stmt
+      .query_map([], |row| {
+        Ok((
+          row.get::<_, String>(0)?,
+          row.get::<_, String>(1)?,
+          row.get::<_, usize>(2)?,
+          row.get::<_, usize>(3)?,
+        ))
+      })
+      .unwrap()
+      .collect::<Result<Vec<_>, _>>()
+      .unwrap();
+    let row = (
+      "file:///main.ts".to_string(),
+      "no-banned-imports".to_string(),
+      2,
+      8,
+    );
+    assert_eq!(rows, vec![row.clone(), row]);
+  }
+}
@@ -0,0 +1,170 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use deno_ast::SourceRange;
+use deno_ast::SourceTextInfo;
+use deno_core::serde_json;
+use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::diagnostic::LintFix;
+use deno_lint::diagnostic::LintFixChange;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::util::fs::specifier_from_file_path;
+
+/// A diagnostic stored in the incremental cache, whose ranges are byte
+/// offsets in the text of the file.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedLintDiagnostic {
+  start: usize,
+  end: usize,
+  code: String,
+  message: String,
+  hint: Option<String>,
+  fixes: Vec<CachedLintFix>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedLintFix {
+  description: String,
+  changes: Vec<CachedLintFixChange>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedLintFixChange {
+  start: usize,
+  end: usize,
+  new_text: String,
+}
+
+/// Serializes the diagnostics of a file for the incremental cache.
+pub fn serialize_diagnostics(diagnostics: &[LintDiagnostic]) -> String {
+  let cached = diagnostics
+    .iter()
+    .map(|d| {
+      let file_start = d.text_info.range().start;
+      CachedLintDiagnostic {
+        start: d.range.start.as_byte_index(file_start),
+        end: d.range.end.as_byte_index(file_start),
+        code: d.code.clone(),
+        message: d.message.clone(),
+        hint: d.hint.clone(),
+        fixes: d
+          .fixes
+          .iter()
+          .map(|fix| CachedLintFix {
+            description: fix.description.to_string(),
+            changes: fix
+              .changes
+              .iter()
+              .map(|change| CachedLintFixChange {
+                start: change.range.start.as_byte_index(file_start),
+                end: change.range.end.as_byte_index(file_start),
+                new_text: change.new_text.to_string(),
+              })
+              .collect(),
+          })
+          .collect(),
+      }
+    })
+    .collect::<Vec<_>>();
+  serde_json::to_string(&cached).unwrap()
+}
+
+/// Recreates the diagnostics that were serialized for the file with the same
+/// text, or returns `None` when the cached output can't be used, in which
+/// case the file is linted again.
+pub fn deserialize_diagnostics(
+  output: &str,
+  file_path: &Path,
+  file_text: &str,
+) -> Option<Vec<LintDiagnostic>> {
+  let cached: Vec<CachedLintDiagnostic> = serde_json::from_str(output).ok()?;
+  let specifier = specifier_from_file_path(file_path).ok()?;
+  let text_info = SourceTextInfo::from_string(file_text.to_string());
+  let file_start = text_info.range().start;
+  let to_range = |start: usize, end: usize| {
+    (start <= end && file_text.get(start..end).is_some())
+      .then(|| SourceRange::new(file_start + start, file_start + end))
+  };
+  cached
+    .into_iter()
+    .map(|d| {
+      let fixes = d
+        .fixes
+        .into_iter()
+        .map(|fix| {
+          let changes = fix
+            .changes
+            .into_iter()
+            .map(|change| {
+              Some(LintFixChange {
+                new_text: Cow::Owned(change.new_text),
+                range: to_range(change.start, change.end)?,
+              })
+            })
+            .collect::<Option<Vec<_>>>()?;
+          Some(LintFix {
+            description: Cow::Owned(fix.description),
+            changes,
+          })
+        })
+        .collect::<Option<Vec<_>>>()?;
+      Some(LintDiagnostic {
+        specifier: specifier.clone(),
+        range: to_range(d.start, d.end)?,
+        text_info: text_info.clone(),
+        message: d.message,
+        code: d.code,
+        hint: d.hint,
+        fixes,
+      })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn round_trips_diagnostics() {
+    let file_path = test_util::TempDir::new().path().join("main.ts");
+    let file_text = "let a = 1;\ndebugger;\n";
+    let text_info = SourceTextInfo::from_string(file_text.to_string());
+    let start = text_info.range().start;
+    let diagnostic = LintDiagnostic {
+      specifier: specifier_from_file_path(file_path.as_path()).unwrap(),
+      range: SourceRange::new(start + 11, start + 20),
+      text_info,
+      message: "`debugger` statement is not allowed".to_string(),
+      code: "no-debugger".to_string(),
+      hint: Some("Remove the `debugger` statement".to_string()),
+      fixes: vec![LintFix {
+        description: Cow::Borrowed("Remove `debugger` statement"),
+        changes: vec![LintFixChange {
+          new_text: Cow::Borrowed(""),
+          range: SourceRange::new(start + 11, start + 20),
+        }],
+      }],
+    };
+    let output = serialize_diagnostics(&[diagnostic]);
+
+    let diagnostics =
+      deserialize_diagnostics(&output, file_path.as_path(), file_text).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    let d = &diagnostics[0];
+    assert_eq!(d.code, "no-debugger");
+    let file_start = d.text_info.range().start;
+    assert_eq!(d.range.start.as_byte_index(file_start), 11);
+    assert_eq!(d.range.end.as_byte_index(file_start), 20);
+    assert_eq!(d.fixes[0].changes[0].range, d.range);
+
+    // ranges past the end of the text can't be used
+    assert!(
+      deserialize_diagnostics(&output, file_path.as_path(), "let a;").is_none()
+    );
+  }
+}
@@ -0,0 +1,80 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use deno_ast::ModuleSpecifier;
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use log::info;
+
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::util::path::specifier_to_file_path;
+
+/// Opens the first problem of the run in `$EDITOR` once linting is done.
+///
+/// Files are linted in parallel, so the first problem is the one that sorts
+/// first by file and position rather than the first one that was visited.
+#[derive(Default)]
+pub struct EditorLintReporter {
+  first_problem: Option<(ModuleSpecifier, usize, usize)>,
+}
+
+impl EditorLintReporter {
+  fn open_editor(&self, path: PathBuf, line: usize) -> Result<(), AnyError> {
+    let editor = std::env::var("EDITOR").unwrap_or_default();
+    let mut parts = editor.split_whitespace();
+    let Some(program) = parts.next() else {
+      bail!("The EDITOR environment variable is not set.");
+    };
+    let status = Command::new(program)
+      .args(parts)
+      .arg(format!("+{}", line))
+      .arg(&path)
+      .status()
+      .with_context(|| format!("Failed running editor '{}'.", editor))?;
+    if !status.success() {
+      bail!("Editor '{}' exited with {}.", editor, status);
+    }
+    Ok(())
+  }
+}
+
+impl LintReporter for EditorLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    // lines and columns are 1-indexed like in the editor
+    let (line, col) = match d.range() {
+      Some((text_info, range)) => {
+        let line_and_column = text_info.line_and_column_display(range.start);
+        (line_and_column.line_number, line_and_column.column_number)
+      }
+      None => (1, 1),
+    };
+    let problem = (d.specifier().clone(), line, col);
+    if self
+      .first_problem
+      .as_ref()
+      .map_or(true, |first| problem < *first)
+    {
+      self.first_problem = Some(problem);
+    }
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {}
+
+  fn close(&mut self, _check_count: usize) {
+    let Some((specifier, line, col)) = &self.first_problem else {
+      info!("No problems to edit.");
+      return;
+    };
+    let result = specifier_to_file_path(specifier).and_then(|path| {
+      info!("Opening {}:{}:{}", path.display(), line, col);
+      self.open_editor(path, *line)
+    });
+    if let Err(err) = result {
+      log::warn!("Failed opening the first problem: {:#}", err);
+    }
+  }
+}
@@ -0,0 +1,156 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::swc::ast;
+use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use deno_ast::ParsedSource;
+use deno_ast::SourceRange;
+use deno_ast::SourceRangedForSpanned;
+
+/// The named functions, classes and methods of a module, used to find the
+/// declaration a diagnostic is in.
+pub struct EnclosingSymbols {
+  symbols: Vec<(SourceRange, String)>,
+}
+
+impl EnclosingSymbols {
+  pub fn collect(parsed_source: &ParsedSource) -> Self {
+    let mut collector = SymbolCollector::default();
+    parsed_source.program_ref().visit_with(&mut collector);
+    Self {
+      symbols: collector.symbols,
+    }
+  }
+
+  /// Gets the name of the innermost declaration containing the range, like
+  /// `Foo.bar` for the method `bar` of the class `Foo`. Returns `None` for
+  /// ranges at the top level of the module.
+  pub fn find(&self, range: SourceRange) -> Option<&str> {
+    self
+      .symbols
+      .iter()
+      .filter(|(symbol_range, _)| {
+        symbol_range.start <= range.start && range.end <= symbol_range.end
+      })
+      // nested declarations start after the ones containing them
+      .max_by_key(|(symbol_range, _)| symbol_range.start)
+      .map(|(_, name)| name.as_str())
+  }
+}
+
+#[derive(Default)]
+struct SymbolCollector {
+  /// Names of the declarations being visited, outermost first.
+  names: Vec<String>,
+  symbols: Vec<(SourceRange, String)>,
+}
+
+impl SymbolCollector {
+  fn visit_symbol(
+    &mut self,
+    name: String,
+    range: SourceRange,
+    visit_children: impl FnOnce(&mut Self),
+  ) {
+    self.names.push(name);
+    self.symbols.push((range, self.names.join(".")));
+    visit_children(self);
+    self.names.pop();
+  }
+}
+
+fn prop_name(key: &ast::PropName) -> Option<String> {
+  match key {
+    ast::PropName::Ident(ident) => Some(ident.sym.to_string()),
+    ast::PropName::Str(s) => Some(s.value.to_string()),
+    _ => None,
+  }
+}
+
+impl Visit for SymbolCollector {
+  fn visit_fn_decl(&mut self, node: &ast::FnDecl) {
+    self.visit_symbol(node.ident.sym.to_string(), node.function.range(), |v| {
+      node.visit_children_with(v)
+    });
+  }
+
+  fn visit_class_decl(&mut self, node: &ast::ClassDecl) {
+    self.visit_symbol(node.ident.sym.to_string(), node.class.range(), |v| {
+      node.visit_children_with(v)
+    });
+  }
+
+  fn visit_class_method(&mut self, node: &ast::ClassMethod) {
+    match prop_name(&node.key) {
+      Some(name) => {
+        self.visit_symbol(name, node.range(), |v| node.visit_children_with(v))
+      }
+      None => node.visit_children_with(self),
+    }
+  }
+
+  fn visit_constructor(&mut self, node: &ast::Constructor) {
+    self.visit_symbol("constructor".to_string(), node.range(), |v| {
+      node.visit_children_with(v)
+    });
+  }
+
+  fn visit_var_declarator(&mut self, node: &ast::VarDeclarator) {
+    // functions and classes assigned to a variable are named after it
+    let is_named_init = node.init.as_deref().is_some_and(|init| {
+      matches!(
+        init,
+        ast::Expr::Arrow(_) | ast::Expr::Fn(_) | ast::Expr::Class(_)
+      )
+    });
+    match &node.name {
+      ast::Pat::Ident(ident) if is_named_init => {
+        self.visit_symbol(ident.id.sym.to_string(), node.range(), |v| {
+          node.visit_children_with(v)
+        })
+      }
+      _ => node.visit_children_with(self),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::MediaType;
+  use deno_ast::ModuleSpecifier;
+  use deno_ast::SourceTextInfo;
+
+  use super::*;
+
+  #[test]
+  fn finds_innermost_symbol() {
+    let text = "const top = 1;
+function outer() {
+  const inner = () => 1;
+}
+class Foo {
+  constructor() {}
+  bar() { return 1; }
+}
+";
+    let parsed_source = deno_ast::parse_module(deno_ast::ParseParams {
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      text_info: SourceTextInfo::new(text.into()),
+      media_type: MediaType::TypeScript,
+      capture_tokens: false,
+      scope_analysis: false,
+      maybe_syntax: None,
+    })
+    .unwrap();
+    let symbols = EnclosingSymbols::collect(&parsed_source);
+    let start = parsed_source.text_info().range().start;
+    let find = |needle: &str| {
+      let pos = start + text.find(needle).unwrap();
+      symbols.find(SourceRange::new(pos, pos + needle.len()))
+    };
+    assert_eq!(find("top"), None);
+    assert_eq!(find("() => 1"), Some("outer.inner"));
+    assert_eq!(find("{}"), Some("Foo.constructor"));
+    assert_eq!(find("return 1"), Some("Foo.bar"));
+  }
+}
@@ -0,0 +1,162 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_lint::linter::LintFileOptions;
+use deno_lint::rules;
+use deno_lint::rules::LintRule;
+
+use super::apply_lint_fixes;
+use super::create_linter;
+use crate::colors;
+use crate::util::diff::diff;
+
+/// Prints the documentation of a rule along with what `--fix` does to the
+/// invalid example of its documentation.
+pub fn explain_rule(code: &str) -> Result<(), AnyError> {
+  let Some(rule) = rules::get_all_rules()
+    .into_iter()
+    .find(|rule| rule.code() == code)
+  else {
+    bail!(
+      "Unknown lint rule '{}'. Run `deno lint --rules` to list the available rules.",
+      code
+    );
+  };
+
+  // printed even with `--quiet`, like the rules list
+  print!("{}", colors::cyan(rule.code()));
+  if rule.tags().is_empty() {
+    println!();
+  } else {
+    println!(" [{}]", colors::gray(rule.tags().join(", ")));
+  }
+  println!();
+  println!("{}", rule.docs().trim());
+  println!();
+
+  match invalid_example(rule.docs()) {
+    Some((media_type, example)) => {
+      match example_fix(rule, media_type, example)? {
+        Some(fixed) => {
+          println!("{}", colors::bold("Example fix:"));
+          print!("{}", diff(example, &fixed));
+        }
+        None => println!("`--fix` doesn't fix the problems of this rule."),
+      }
+    }
+    None => println!("This rule has no example to show a fix for."),
+  }
+  Ok(())
+}
+
+/// Gets the first code block after the "Invalid" heading of the docs of a
+/// rule, along with the media type of its language.
+fn invalid_example(docs: &str) -> Option<(MediaType, &str)> {
+  let after_heading = &docs[docs.find("Invalid")?..];
+  let block = &after_heading[after_heading.find("```")? + 3..];
+  let (language, block) = block.split_once('\n')?;
+  let code = &block[..block.find("```")?];
+  let media_type = match language.trim() {
+    "tsx" => MediaType::Tsx,
+    "jsx" => MediaType::Jsx,
+    "js" | "javascript" => MediaType::JavaScript,
+    _ => MediaType::TypeScript,
+  };
+  Some((media_type, code))
+}
+
+/// Lints the example with only the rule and applies the fixes of its
+/// problems, returning `None` when there's nothing to fix.
+fn example_fix(
+  rule: &'static dyn LintRule,
+  media_type: MediaType,
+  example: &str,
+) -> Result<Option<String>, AnyError> {
+  let linter = create_linter(vec![rule]);
+  let specifier = ModuleSpecifier::parse(&format!(
+    "file:///example{}",
+    media_type.as_ts_extension()
+  ))?;
+  // some examples don't parse on purpose, which means there's nothing to fix
+  let Ok((source, diagnostics)) = linter.lint_file(LintFileOptions {
+    specifier,
+    source_code: example.to_string(),
+    media_type,
+  }) else {
+    return Ok(None);
+  };
+  Ok(
+    apply_lint_fixes(source.text_info(), &diagnostics, &|_| true)
+      .map(|(fixed_text, _)| fixed_text),
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn finds_invalid_example() {
+    let docs = "Disallows `var`.
+
+### Invalid:
+
+```tsx
+var a = <div />;
+```
+
+### Valid:
+
+```typescript
+let a = 1;
+```
+";
+    let (media_type, example) = invalid_example(docs).unwrap();
+    assert_eq!(media_type, MediaType::Tsx);
+    assert_eq!(example, "var a = <div />;\n");
+    assert!(invalid_example("Disallows `var`.").is_none());
+  }
+
+  #[test]
+  fn fixes_example() {
+    let rule = |code: &str| {
+      rules::get_all_rules()
+        .into_iter()
+        .find(|rule| rule.code() == code)
+        .unwrap()
+    };
+    assert_eq!(
+      example_fix(
+        rule("no-window"),
+        MediaType::TypeScript,
+        "window.alert(1);\n"
+      )
+      .unwrap(),
+      Some("globalThis.alert(1);\n".to_string())
+    );
+    // nothing to fix
+    assert_eq!(
+      example_fix(rule("no-window"), MediaType::TypeScript, "alert(1);\n")
+        .unwrap(),
+      None
+    );
+    assert_eq!(
+      example_fix(
+        rule("no-explicit-any"),
+        MediaType::TypeScript,
+        "export const a: any = 1;\n"
+      )
+      .unwrap(),
+      None
+    );
+    // doesn't parse
+    assert_eq!(
+      example_fix(rule("no-window"), MediaType::TypeScript, "window.(\n")
+        .unwrap(),
+      None
+    );
+  }
+}
@@ -0,0 +1,106 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_config::glob::PathOrPattern;
+use deno_config::glob::PathOrPatternSet;
+use deno_core::serde_json;
+use serde::Serialize;
+
+use super::lint_file_collector;
+use crate::args::LintOptions;
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Serialize)]
+struct JsonPathOrPattern {
+  kind: &'static str,
+  value: String,
+}
+
+impl JsonPathOrPattern {
+  fn new(path_or_pattern: &PathOrPattern) -> Self {
+    let (kind, value) = match path_or_pattern {
+      PathOrPattern::Path(path) => ("path", path.display().to_string()),
+      PathOrPattern::NegatedPath(path) => {
+        ("negatedPath", path.display().to_string())
+      }
+      PathOrPattern::RemoteUrl(url) => ("url", url.to_string()),
+      PathOrPattern::Pattern(pattern) => ("glob", pattern.as_str().to_string()),
+    };
+    JsonPathOrPattern { kind, value }
+  }
+
+  fn display(&self) -> String {
+    match self.kind {
+      "path" => self.value.clone(),
+      "negatedPath" => format!("!{}", self.value),
+      kind => format!("{} ({})", self.value, kind),
+    }
+  }
+}
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFilePatterns {
+  base: String,
+  /// `None` when everything in the base directory is included.
+  include: Option<Vec<JsonPathOrPattern>>,
+  exclude: Vec<JsonPathOrPattern>,
+  skipped_folders: Vec<&'static str>,
+  third_party: Vec<JsonPathOrPattern>,
+  max_depth: Option<usize>,
+}
+
+fn to_json_patterns(set: &PathOrPatternSet) -> Vec<JsonPathOrPattern> {
+  set.inner().iter().map(JsonPathOrPattern::new).collect()
+}
+
+/// Prints the file patterns that the files to lint are collected with,
+/// including the folders that are skipped implicitly, to debug why a file is
+/// linted or not.
+pub fn print_file_patterns(lint_options: &LintOptions, json: bool) {
+  let files = &lint_options.files;
+  let patterns = JsonFilePatterns {
+    base: files.base.display().to_string(),
+    include: files.include.as_ref().map(to_json_patterns),
+    exclude: to_json_patterns(&files.exclude),
+    skipped_folders: lint_file_collector(lint_options)
+      .ignored_dir_names()
+      .collect(),
+    third_party: lint_options
+      .filter
+      .third_party
+      .as_ref()
+      .map(to_json_patterns)
+      .unwrap_or_default(),
+    max_depth: lint_options.filter.max_depth,
+  };
+  if json {
+    println!("{}", serde_json::to_string_pretty(&patterns).unwrap());
+    return;
+  }
+
+  fn print_list(title: &str, patterns: &[JsonPathOrPattern]) {
+    if patterns.is_empty() {
+      println!("{}: none", title);
+      return;
+    }
+    println!("{}:", title);
+    for pattern in patterns {
+      println!("  {}", pattern.display());
+    }
+  }
+
+  println!("Base: {}", patterns.base);
+  match &patterns.include {
+    Some(include) => print_list("Include", include),
+    None => println!("Include: everything in the base directory"),
+  }
+  print_list("Exclude", &patterns.exclude);
+  println!("Skipped folders: {}", patterns.skipped_folders.join(", "));
+  if !patterns.third_party.is_empty() {
+    print_list("Third party", &patterns.third_party);
+  }
+  if let Some(max_depth) = patterns.max_depth {
+    println!("Max depth: {}", max_depth);
+  }
+}
@@ -0,0 +1,108 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::fs;
+use std::path::Path;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::parking_lot::Mutex;
+use deno_core::serde_json;
+use serde::Serialize;
+
+use super::FileDisplay;
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum JsonFileStatus {
+  Pass,
+  Fail,
+}
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFileResult {
+  filename: String,
+  status: JsonFileStatus,
+  problem_count: usize,
+}
+
+/// The pass or fail result of every checked file, including the ones
+/// without problems and the ones that are skipped because they're cached,
+/// so that build tools can cache the lint action of each file. The files
+/// that are skipped as generated or with `--skip-unreadable` aren't checked,
+/// while the ones that can't be read or linted in time fail.
+pub struct FileResults {
+  files: FileDisplay,
+  results: Mutex<Vec<JsonFileResult>>,
+  /// Problems of the checked files that are found apart from linting them.
+  added_problems: Mutex<Vec<JsonFileResult>>,
+}
+
+impl FileResults {
+  pub fn new(files: FileDisplay) -> Self {
+    Self {
+      files,
+      results: Default::default(),
+      added_problems: Default::default(),
+    }
+  }
+
+  pub fn add(&self, file_path: &Path, problem_count: usize, passed: bool) {
+    let result = self.result(file_path, problem_count, passed);
+    self.results.lock().push(result);
+  }
+
+  /// Adds problems to the result of a checked file that are found apart
+  /// from linting it, like the ones of no-slow-types, which fail it unless
+  /// they're warnings.
+  pub fn add_problems(
+    &self,
+    file_path: &Path,
+    problem_count: usize,
+    passed: bool,
+  ) {
+    let result = self.result(file_path, problem_count, passed);
+    self.added_problems.lock().push(result);
+  }
+
+  fn result(
+    &self,
+    file_path: &Path,
+    problem_count: usize,
+    passed: bool,
+  ) -> JsonFileResult {
+    JsonFileResult {
+      filename: self.files.display_path(file_path),
+      status: if passed {
+        JsonFileStatus::Pass
+      } else {
+        JsonFileStatus::Fail
+      },
+      problem_count,
+    }
+  }
+
+  /// Writes the results sorted by file name, as they're added in the
+  /// order the files finish linting.
+  pub fn write(&self, path: &Path) -> Result<(), AnyError> {
+    let mut results = std::mem::take(&mut *self.results.lock());
+    for added in std::mem::take(&mut *self.added_problems.lock()) {
+      // the files that weren't checked have no result
+      let Some(result) =
+        results.iter_mut().find(|r| r.filename == added.filename)
+      else {
+        continue;
+      };
+      result.problem_count += added.problem_count;
+      if let JsonFileStatus::Fail = added.status {
+        result.status = JsonFileStatus::Fail;
+      }
+    }
+    results.sort_by(|a, b| a.filename.cmp(&b.filename));
+    let json = serde_json::to_string_pretty(&results)?;
+    fs::write(path, json)
+      .with_context(|| format!("Failed writing '{}'.", path.display()))
+  }
+}
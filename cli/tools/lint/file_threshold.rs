@@ -0,0 +1,83 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+
+use deno_ast::ModuleSpecifier;
+use deno_core::error::AnyError;
+
+use super::FileDisplay;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::colors;
+
+/// Counts the problems of each file and points out the files with more of
+/// them than a threshold when the run is closed, as a sign of code that's
+/// worth refactoring. Unlike a limit, every problem is still reported and
+/// the full count of the file is printed.
+pub struct FileThresholdLintReporter {
+  threshold: usize,
+  files: FileDisplay,
+  counts: HashMap<ModuleSpecifier, usize>,
+}
+
+impl FileThresholdLintReporter {
+  pub fn new(threshold: usize, files: FileDisplay) -> Self {
+    Self {
+      threshold,
+      files,
+      counts: HashMap::new(),
+    }
+  }
+}
+
+/// The files with more problems than the threshold along with their number
+/// of problems, with the most problems first.
+fn files_over_threshold(
+  counts: &HashMap<ModuleSpecifier, usize>,
+  threshold: usize,
+) -> Vec<(&ModuleSpecifier, usize)> {
+  let mut files = counts
+    .iter()
+    .filter(|(_, count)| **count > threshold)
+    .map(|(specifier, count)| (specifier, *count))
+    .collect::<Vec<_>>();
+  files.sort_by(|(a, a_count), (b, b_count)| {
+    b_count.cmp(a_count).then_with(|| a.cmp(b))
+  });
+  files
+}
+
+impl LintReporter for FileThresholdLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    *self.counts.entry(d.specifier().clone()).or_default() += 1;
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {}
+
+  fn close(&mut self, _check_count: usize) {
+    for (specifier, count) in files_over_threshold(&self.counts, self.threshold)
+    {
+      eprintln!(
+        "{} file {} has {} problems; consider refactoring",
+        colors::yellow("Warning"),
+        self.files.display(specifier),
+        count
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn finds_files_over_threshold() {
+    let a = ModuleSpecifier::parse("file:///a.ts").unwrap();
+    let b = ModuleSpecifier::parse("file:///b.ts").unwrap();
+    let c = ModuleSpecifier::parse("file:///c.ts").unwrap();
+    let counts = HashMap::from([(a.clone(), 30), (b.clone(), 250), (c, 20)]);
+    assert_eq!(files_over_threshold(&counts, 20), vec![(&b, 250), (&a, 30)]);
+    assert!(files_over_threshold(&counts, 250).is_empty());
+  }
+}
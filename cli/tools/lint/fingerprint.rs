@@ -0,0 +1,222 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Identifies problems across runs and checkouts of a repository, for the
+//! baseline, `--compare` and the reporters that hand problems to other tools.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_ast::ModuleSpecifier;
+use deno_ast::SourceRange;
+use deno_ast::SourceTextInfo;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::LintOrCliDiagnostic;
+use crate::util::checksum;
+use crate::util::path::specifier_to_file_path;
+
+/// Identifies a problem by its file, its rule and a hash of the text of its
+/// line rather than its position, so that it stays the same when the code
+/// above it changes. Problems with the same rule on identical lines of a
+/// file share a fingerprint.
+// WARNING: Ensure doesn't change because it's used in the baseline file
+#[derive(
+  Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Fingerprint {
+  pub file: String,
+  pub code: String,
+  pub line_hash: String,
+}
+
+impl Fingerprint {
+  pub fn new(
+    specifier: &ModuleSpecifier,
+    code: &str,
+    text_info: &SourceTextInfo,
+    range: SourceRange,
+    cwd: &Path,
+  ) -> Self {
+    Self {
+      file: relative_filename(specifier, cwd),
+      code: code.to_string(),
+      line_hash: hash_line(text_info, range),
+    }
+  }
+
+  /// Gets the fingerprint of the diagnostic. Diagnostics without a range
+  /// have no line to hash, so they're identified by their file and rule.
+  pub fn from_diagnostic(d: &LintOrCliDiagnostic, cwd: &Path) -> Self {
+    match d.range() {
+      Some((text_info, range)) => {
+        Self::new(d.specifier(), &d.code(), text_info, range, cwd)
+      }
+      None => Self {
+        file: relative_filename(d.specifier(), cwd),
+        code: d.code().to_string(),
+        line_hash: String::new(),
+      },
+    }
+  }
+
+  /// Hashes the fingerprint of the nth problem with it into the id that
+  /// other tools get for the problem.
+  fn id(&self, occurrence: usize) -> String {
+    let occurrence = occurrence.to_string();
+    let mut id = checksum::gen(&[
+      self.file.as_str(),
+      "\0",
+      self.code.as_str(),
+      "\0",
+      self.line_hash.as_str(),
+      "\0",
+      occurrence.as_str(),
+    ]);
+    id.truncate(16);
+    id
+  }
+}
+
+/// Gives each problem of a run an id from its fingerprint, counting the
+/// problems that share one so that each of them gets its own id.
+#[derive(Default)]
+pub struct FingerprintIds {
+  occurrences: HashMap<Fingerprint, usize>,
+}
+
+impl FingerprintIds {
+  pub fn next_id(&mut self, d: &LintOrCliDiagnostic, cwd: &Path) -> String {
+    let fingerprint = Fingerprint::from_diagnostic(d, cwd);
+    let occurrence = self.occurrences.entry(fingerprint.clone()).or_default();
+    let id = fingerprint.id(*occurrence);
+    *occurrence += 1;
+    id
+  }
+}
+
+/// Gets the path of a file relative to `base` with forward slashes, so that
+/// it's the same in any checkout of a repository and on any platform. Files
+/// outside of `base` keep their absolute path and remote specifiers are left
+/// as urls.
+pub fn relative_filename(specifier: &ModuleSpecifier, base: &Path) -> String {
+  match specifier_to_file_path(specifier) {
+    Ok(path) => relative_path(&path, base),
+    Err(_) => specifier.to_string(),
+  }
+}
+
+/// Gets the filename of the `--json` output relative to `base` like
+/// [`relative_filename`]. It's a file url unless `--absolute` was used.
+pub fn normalize_filename(filename: &str, base: &Path) -> String {
+  match ModuleSpecifier::parse(filename) {
+    Ok(specifier) if specifier.scheme() == "file" => {
+      relative_filename(&specifier, base)
+    }
+    // windows paths parse as urls with the drive letter as the scheme
+    Ok(specifier) if specifier.scheme().len() > 1 => filename.to_string(),
+    _ => relative_path(&PathBuf::from(filename), base),
+  }
+}
+
+fn relative_path(path: &Path, base: &Path) -> String {
+  match path.strip_prefix(base) {
+    Ok(relative) => relative.to_string_lossy().replace('\\', "/"),
+    Err(_) => path.to_string_lossy().to_string(),
+  }
+}
+
+/// Hashes the text of the line where the problem starts without its
+/// indentation, so that reindenting the code keeps the same fingerprint.
+/// It's a SHA-256 hash, which is the same with any build of deno.
+fn hash_line(text_info: &SourceTextInfo, range: SourceRange) -> String {
+  let line_index = text_info.line_and_column_index(range.start).line_index;
+  checksum::gen(&[text_info.line_text(line_index).trim()])
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn hashes_line_without_indentation() {
+    let text_info = SourceTextInfo::from_string(
+      "let a = 1;\n  let a = 1;\nlet b = 2;\n".to_string(),
+    );
+    let start = text_info.range().start;
+    let first = hash_line(&text_info, SourceRange::new(start + 4, start + 5));
+    assert_eq!(
+      first,
+      "c58e9029f1fd3d1b109cbcf9ea78fa301c222b9a0783e8a6d49929769d71fc29"
+    );
+    assert_eq!(
+      first,
+      hash_line(&text_info, SourceRange::new(start + 15, start + 16))
+    );
+    assert_ne!(
+      first,
+      hash_line(&text_info, SourceRange::new(start + 26, start + 27))
+    );
+  }
+
+  #[test]
+  fn relative_filenames() {
+    let base = if cfg!(windows) {
+      PathBuf::from("C:\\project")
+    } else {
+      PathBuf::from("/project")
+    };
+    let inside =
+      ModuleSpecifier::from_file_path(base.join("src").join("a.ts")).unwrap();
+    assert_eq!(relative_filename(&inside, &base), "src/a.ts");
+    let outside_path = base.parent().unwrap().join("b.ts");
+    let outside = ModuleSpecifier::from_file_path(&outside_path).unwrap();
+    assert_eq!(
+      relative_filename(&outside, &base),
+      outside_path.to_string_lossy()
+    );
+    let remote = ModuleSpecifier::parse("https://deno.land/x/a.ts").unwrap();
+    assert_eq!(
+      relative_filename(&remote, &base),
+      "https://deno.land/x/a.ts"
+    );
+  }
+
+  #[test]
+  fn normalizes_filenames() {
+    let cwd = std::env::current_dir().unwrap();
+    let url = ModuleSpecifier::from_file_path(cwd.join("src/main.ts")).unwrap();
+    assert_eq!(normalize_filename(url.as_str(), &cwd), "src/main.ts");
+    assert_eq!(
+      normalize_filename(&cwd.join("main.ts").display().to_string(), &cwd),
+      "main.ts"
+    );
+    assert_eq!(
+      normalize_filename("https://deno.land/x/mod.ts", &cwd),
+      "https://deno.land/x/mod.ts"
+    );
+  }
+
+  #[test]
+  fn ids_ignore_position() {
+    let text_info =
+      SourceTextInfo::from_string("debugger;\n\n  debugger;\n".to_string());
+    let start = text_info.range().start;
+    let specifier = ModuleSpecifier::parse("file:///project/a.ts").unwrap();
+    let base = Path::new("/project");
+    let fingerprint = |offset: usize| {
+      Fingerprint::new(
+        &specifier,
+        "no-debugger",
+        &text_info,
+        SourceRange::new(start + offset, start + offset + 9),
+        base,
+      )
+    };
+    assert_eq!(fingerprint(0).id(0), fingerprint(13).id(0));
+    assert_ne!(fingerprint(0).id(0), fingerprint(13).id(1));
+  }
+}
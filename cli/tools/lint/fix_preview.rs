@@ -0,0 +1,77 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+
+use deno_core::error::AnyError;
+use log::info;
+
+use super::print_lint_error;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::colors;
+
+/// Reports how many problems of each rule `--fix` would fix, without
+/// printing the problems themselves.
+#[derive(Default)]
+pub struct FixPreviewLintReporter {
+  fixes_by_rule: BTreeMap<String, usize>,
+  problems: usize,
+}
+
+impl FixPreviewLintReporter {
+  fn fixable_count(&self) -> usize {
+    self.fixes_by_rule.values().sum()
+  }
+}
+
+impl LintReporter for FixPreviewLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.problems += 1;
+    // only the diagnostics of deno_lint rules carry fixes
+    if let LintOrCliDiagnostic::Lint(d) = d {
+      if !d.fixes.is_empty() {
+        *self.fixes_by_rule.entry(d.code.clone()).or_default() += 1;
+      }
+    }
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    print_lint_error(file_path, err);
+  }
+
+  fn close(&mut self, check_count: usize) {
+    for (rule, count) in &self.fixes_by_rule {
+      info!("{} would fix {}", colors::bold(rule), count);
+    }
+    let fixable = self.fixable_count();
+    match fixable {
+      0 => info!("No problems can be fixed via --fix."),
+      1 => info!("1 of {} problems can be fixed via --fix.", self.problems),
+      n => info!(
+        "{} of {} problems can be fixed via --fix.",
+        n, self.problems
+      ),
+    }
+    match check_count {
+      n if n <= 1 => info!("Checked {} file", n),
+      n => info!("Checked {} files", n),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn fixable_count() {
+    let reporter = FixPreviewLintReporter {
+      fixes_by_rule: BTreeMap::from([
+        ("no-unused-vars".to_string(), 30),
+        ("prefer-const".to_string(), 10),
+      ]),
+      problems: 45,
+    };
+    assert_eq!(reporter.fixable_count(), 40);
+  }
+}
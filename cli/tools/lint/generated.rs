@@ -0,0 +1,43 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_lint::rules::LintRule;
+
+/// Rules of the "generated" preset. These catch problems that matter
+/// regardless of whether the code was written by hand, unlike the style
+/// rules that codegen output routinely trips.
+const GENERATED_RULE_CODES: &[&str] = &[
+  "no-debugger",
+  "no-deprecated-deno-api",
+  "no-eval",
+  "no-global-assign",
+  "no-import-assign",
+  "no-unsafe-finally",
+];
+
+/// Gets the enabled rules that are part of the "generated" preset, which
+/// are the ones generated files are linted with.
+pub fn generated_file_rules(
+  rules: &[&'static dyn LintRule],
+) -> Vec<&'static dyn LintRule> {
+  rules
+    .iter()
+    .filter(|rule| GENERATED_RULE_CODES.contains(&rule.code()))
+    .copied()
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use deno_lint::rules::get_recommended_rules;
+
+  use super::*;
+
+  #[test]
+  fn keeps_enabled_preset_rules() {
+    let rules = generated_file_rules(&get_recommended_rules());
+    let codes = rules.iter().map(|r| r.code()).collect::<Vec<_>>();
+    assert!(codes.contains(&"no-debugger"));
+    assert!(!codes.contains(&"no-unused-vars"));
+    assert!(!codes.contains(&"prefer-const"));
+  }
+}
@@ -0,0 +1,238 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Support for ignore directives that expire, for suppressions that are
+//! meant to be temporary:
+//!
+//! ```ts
+//! // deno-lint-ignore-until 2025-01-01 no-explicit-any
+//! let a: any;
+//! ```
+//!
+//! Like `deno-lint-ignore`, a directive ignores the problems of the rules it
+//! lists, or of all rules when it lists none, on the next line. From the
+//! date on, the problems are reported again along with an
+//! `expired-ignore-directive` problem on the directive. The diagnostics are
+//! filtered after linting rather than by deno_lint, so the files with
+//! ignored problems aren't cached as passing and are linted again when the
+//! directives expire. The fixes of the ignored problems aren't applied.
+//!
+//! The directives are read from the comments of the parsed module, so the
+//! text of strings and templates is never taken for one. The code blocks of
+//! Markdown files have no directives.
+
+use chrono::NaiveDate;
+use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::ParsedSource;
+use deno_ast::SourceRange;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::SourceTextInfo;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_lint::diagnostic::LintDiagnostic;
+
+const IGNORE_UNTIL_DIRECTIVE: &str = "deno-lint-ignore-until";
+const EXPIRED_IGNORE_DIRECTIVE_CODE: &str = "expired-ignore-directive";
+
+#[derive(Debug, PartialEq, Eq)]
+struct IgnoreUntilDirective {
+  /// Index of the line of the directive, which applies to the next line.
+  line_index: usize,
+  until: NaiveDate,
+  codes: Vec<String>,
+}
+
+impl IgnoreUntilDirective {
+  fn ignores(&self, text_info: &SourceTextInfo, d: &LintDiagnostic) -> bool {
+    text_info.line_and_column_index(d.range.start).line_index
+      == self.line_index + 1
+      && (self.codes.is_empty() || self.codes.contains(&d.code))
+  }
+}
+
+/// Removes the diagnostics ignored by the directives that didn't expire, and
+/// adds a diagnostic for each expired directive that would have ignored some.
+pub fn apply_ignore_until_directives(
+  source: &ParsedSource,
+  diagnostics: &mut Vec<LintDiagnostic>,
+  today: NaiveDate,
+) -> Result<(), AnyError> {
+  // parsed even when there are no problems, so an invalid directive is
+  // reported before the file has problems to ignore
+  let directives = parse_directives(source)?;
+  let text_info = source.text_info();
+  let mut expired_diagnostics = Vec::new();
+  for directive in directives {
+    if today < directive.until {
+      diagnostics.retain(|d| !directive.ignores(text_info, d));
+    } else if let Some(d) =
+      diagnostics.iter().find(|d| directive.ignores(text_info, d))
+    {
+      expired_diagnostics.push(expired_diagnostic(d, text_info, &directive));
+    }
+  }
+  diagnostics.extend(expired_diagnostics);
+  Ok(())
+}
+
+/// Gets whether a diagnostic is ignored by a directive that didn't expire,
+/// for its fixes not to be applied.
+pub fn unexpired_ignores(
+  source: &ParsedSource,
+  today: NaiveDate,
+) -> Result<impl Fn(&LintDiagnostic) -> bool, AnyError> {
+  let directives = parse_directives(source)?
+    .into_iter()
+    .filter(|directive| today < directive.until)
+    .collect::<Vec<_>>();
+  Ok(move |d: &LintDiagnostic| {
+    directives
+      .iter()
+      .any(|directive| directive.ignores(&d.text_info, d))
+  })
+}
+
+fn expired_diagnostic(
+  ignored: &LintDiagnostic,
+  text_info: &SourceTextInfo,
+  directive: &IgnoreUntilDirective,
+) -> LintDiagnostic {
+  let line_text = text_info.line_text(directive.line_index);
+  let indent = line_text.len() - line_text.trim_start().len();
+  LintDiagnostic {
+    specifier: ignored.specifier.clone(),
+    range: SourceRange::new(
+      text_info.line_start(directive.line_index) + indent,
+      text_info.line_end(directive.line_index),
+    ),
+    text_info: text_info.clone(),
+    message: format!(
+      "Ignore directive expired on {}",
+      directive.until.format("%Y-%m-%d")
+    ),
+    code: EXPIRED_IGNORE_DIRECTIVE_CODE.to_string(),
+    hint: Some(
+      "Fix the problems on the next line or remove the directive".to_string(),
+    ),
+    fixes: Vec::new(),
+  }
+}
+
+/// Gets whether the text may have directives, for the diagnostics of a file
+/// not to be replayed from the cache when they can only be found by parsing
+/// it. Strings and templates with the directive are false positives.
+pub fn may_have_ignore_until_directives(file_text: &str) -> bool {
+  file_text.contains(IGNORE_UNTIL_DIRECTIVE)
+}
+
+fn parse_directives(
+  source: &ParsedSource,
+) -> Result<Vec<IgnoreUntilDirective>, AnyError> {
+  let text_info = source.text_info();
+  let mut directives = Vec::new();
+  if !may_have_ignore_until_directives(text_info.text_str()) {
+    return Ok(directives);
+  }
+  for comment in source.comments().get_vec() {
+    if comment.kind != CommentKind::Line {
+      continue;
+    }
+    let mut parts = comment.text.split_whitespace();
+    if parts.next() != Some(IGNORE_UNTIL_DIRECTIVE) {
+      continue;
+    }
+    let line_index = text_info.line_index(comment.start());
+    let line_number = line_index + 1;
+    let Some(date) = parts.next() else {
+      bail!(
+        "Missing date in the {} directive on line {}. Expected an ISO-8601 date like 2025-01-01.",
+        IGNORE_UNTIL_DIRECTIVE,
+        line_number
+      );
+    };
+    let until = match parse_iso_date(date) {
+      Some(until) => until,
+      None => bail!(
+        "Invalid date '{}' in the {} directive on line {}. Expected an ISO-8601 date like 2025-01-01.",
+        date,
+        IGNORE_UNTIL_DIRECTIVE,
+        line_number
+      ),
+    };
+    directives.push(IgnoreUntilDirective {
+      line_index,
+      until,
+      codes: parts.map(ToString::to_string).collect(),
+    });
+  }
+  Ok(directives)
+}
+
+/// Parses an ISO-8601 calendar date, which has a zero padded month and day.
+fn parse_iso_date(text: &str) -> Option<NaiveDate> {
+  if text.len() != "2025-01-01".len() {
+    return None;
+  }
+  NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::MediaType;
+  use deno_ast::ModuleSpecifier;
+
+  use super::*;
+
+  fn parse(text: &str) -> ParsedSource {
+    deno_ast::parse_module(deno_ast::ParseParams {
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      text_info: SourceTextInfo::new(text.into()),
+      media_type: MediaType::TypeScript,
+      capture_tokens: false,
+      scope_analysis: false,
+      maybe_syntax: None,
+    })
+    .unwrap()
+  }
+
+  #[test]
+  fn parses_directives() {
+    let source = parse(
+      "// deno-lint-ignore-until 2025-01-01 no-explicit-any no-var\nvar a: any;\n  // deno-lint-ignore-until 2030-12-31\ndebugger;\n// deno-lint-ignore no-var\n",
+    );
+    assert_eq!(
+      parse_directives(&source).unwrap(),
+      vec![
+        IgnoreUntilDirective {
+          line_index: 0,
+          until: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+          codes: vec!["no-explicit-any".to_string(), "no-var".to_string()],
+        },
+        IgnoreUntilDirective {
+          line_index: 2,
+          until: NaiveDate::from_ymd_opt(2030, 12, 31).unwrap(),
+          codes: vec![],
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn ignores_directives_in_strings() {
+    let source = parse(
+      "const a = `\n// deno-lint-ignore-until 01/01/2025\n`;\nconst b = \"// deno-lint-ignore-until\";\n",
+    );
+    assert_eq!(parse_directives(&source).unwrap(), vec![]);
+  }
+
+  #[test]
+  fn errors_on_malformed_dates() {
+    for text in [
+      "// deno-lint-ignore-until\n",
+      "// deno-lint-ignore-until 2025-1-1 no-var\n",
+      "// deno-lint-ignore-until 2025-02-30 no-var\n",
+      "// deno-lint-ignore-until 01/01/2025 no-var\n",
+    ] {
+      assert!(parse_directives(&parse(text)).is_err(), "{}", text);
+    }
+  }
+}
@@ -0,0 +1,135 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+
+use super::lint_files;
+use crate::args::Flags;
+use crate::args::LintFlags;
+use crate::args::LintInboxDone;
+use crate::colors;
+use crate::factory::CliFactory;
+use crate::util::file_watcher;
+use crate::util::path::is_script_ext;
+
+/// Interval between the checks of whether a new file is still being
+/// written.
+const WRITE_SETTLE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Watches the `--inbox` directory and lints each script file that's created
+/// in it once, like the files of a regular run, writing its problems to a
+/// sidecar file. Runs until the process is stopped.
+pub async fn lint_inbox(
+  flags: Flags,
+  lint_flags: LintFlags,
+) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags.clone())?;
+  let lint_options = factory
+    .cli_options()
+    .resolve_lint_options(lint_flags.clone())?;
+  let inbox = lint_options.inbox.clone().unwrap();
+  if let LintInboxDone::Move(done_dir) = &inbox.done {
+    fs::create_dir_all(done_dir).with_context(|| {
+      format!("Failed creating directory '{}'.", done_dir.display())
+    })?;
+  }
+
+  let (_watcher, mut created_files) =
+    file_watcher::watch_created_files(&inbox.dir).with_context(|| {
+      format!("Failed watching inbox '{}'.", inbox.dir.display())
+    })?;
+  log::info!(
+    "{} Watching '{}' for new files.",
+    colors::intense_blue("Lint"),
+    inbox.dir.display()
+  );
+  // the files that are kept in the inbox, which are linted only once even
+  // when they're moved into it again
+  let mut linted_paths = HashSet::new();
+  while let Some(file_path) = created_files.recv().await {
+    if !is_script_ext(&file_path) || linted_paths.contains(&file_path) {
+      continue;
+    }
+    wait_for_write_completion(&file_path).await;
+    let result =
+      lint_inbox_file(&flags, &lint_flags, &file_path, &inbox.done).await;
+    if let Err(err) = result {
+      log::error!(
+        "{}: Failed linting '{}': {:#}",
+        colors::red_bold("error"),
+        file_path.display(),
+        err
+      );
+    }
+    if inbox.done == LintInboxDone::Keep {
+      linted_paths.insert(file_path);
+    }
+  }
+  Ok(())
+}
+
+/// Waits until the size and modification time of a file stop changing,
+/// as it may be created before its content is written. The file may
+/// also be gone, in which case linting it fails.
+async fn wait_for_write_completion(file_path: &Path) {
+  fn state(file_path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = fs::metadata(file_path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+  }
+
+  let mut last_state = state(file_path);
+  loop {
+    tokio::time::sleep(WRITE_SETTLE_INTERVAL).await;
+    let current_state = state(file_path);
+    if current_state == last_state {
+      return;
+    }
+    last_state = current_state;
+  }
+}
+
+/// Lints a file of the inbox with the options of the run, writing its
+/// sidecar file next to where it's kept, and moves or deletes it when
+/// configured.
+async fn lint_inbox_file(
+  flags: &Flags,
+  lint_flags: &LintFlags,
+  file_path: &Path,
+  done: &LintInboxDone,
+) -> Result<(), AnyError> {
+  let file_path = match done {
+    LintInboxDone::Keep | LintInboxDone::Delete => file_path.to_path_buf(),
+    LintInboxDone::Move(done_dir) => move_to_dir(file_path, done_dir)?,
+  };
+  // the config file may have changed since the previous file
+  let factory = CliFactory::from_flags(flags.clone())?;
+  let mut lint_options = factory
+    .cli_options()
+    .resolve_lint_options(lint_flags.clone())?;
+  lint_options.reporter.sidecar = true;
+  lint_files(factory, lint_options, vec![file_path.clone()]).await?;
+  if *done == LintInboxDone::Delete {
+    fs::remove_file(&file_path)
+      .with_context(|| format!("Failed deleting '{}'.", file_path.display()))?;
+  }
+  Ok(())
+}
+
+fn move_to_dir(file_path: &Path, dir: &Path) -> Result<PathBuf, AnyError> {
+  let moved_path = dir.join(file_path.file_name().unwrap());
+  fs::rename(file_path, &moved_path).with_context(|| {
+    format!(
+      "Failed moving '{}' to '{}'.",
+      file_path.display(),
+      dir.display()
+    )
+  })?;
+  Ok(moved_path)
+}
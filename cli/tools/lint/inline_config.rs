@@ -0,0 +1,105 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Support for a per-file rule configuration block at the top of a file:
+//!
+//! ```ts
+//! /* deno-lint-config { "exclude": ["no-explicit-any"] } */
+//! ```
+//!
+//! The block is applied on top of the rules resolved from the project config
+//! and CLI flags, so it takes precedence for that file only. The incremental
+//! cache is keyed on the file's text, so adding or changing the block always
+//! invalidates the file's cache entry.
+
+use deno_core::anyhow::Context;
+use deno_core::error::generic_error;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_lint::rules;
+use deno_lint::rules::LintRule;
+use serde::Deserialize;
+
+const INLINE_CONFIG_PREFIX: &str = "/* deno-lint-config";
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct InlineLintConfig {
+  pub include: Vec<String>,
+  pub exclude: Vec<String>,
+}
+
+impl InlineLintConfig {
+  /// Parses the config block if the file starts with one.
+  pub fn parse(file_text: &str) -> Result<Option<Self>, AnyError> {
+    let text = file_text.trim_start_matches('\u{FEFF}').trim_start();
+    let Some(rest) = text.strip_prefix(INLINE_CONFIG_PREFIX) else {
+      return Ok(None);
+    };
+    let Some(end) = rest.find("*/") else {
+      return Err(generic_error("Unterminated deno-lint-config block."));
+    };
+    let config = serde_json::from_str(rest[..end].trim())
+      .context("Failed parsing deno-lint-config block.")?;
+    Ok(Some(config))
+  }
+
+  /// Applies this file's overrides to the project's configured rules.
+  pub fn apply(
+    &self,
+    rules: &[&'static dyn LintRule],
+  ) -> Vec<&'static dyn LintRule> {
+    let mut file_rules = rules
+      .iter()
+      .filter(|r| !self.exclude.iter().any(|c| c == r.code()))
+      .copied()
+      .collect::<Vec<_>>();
+    for rule in rules::get_all_rules() {
+      let is_included = self.include.iter().any(|c| c == rule.code());
+      if is_included && !file_rules.iter().any(|r| r.code() == rule.code()) {
+        file_rules.push(rule);
+      }
+    }
+    file_rules
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_inline_config() {
+    assert_eq!(InlineLintConfig::parse("const a = 1;").unwrap(), None);
+    assert_eq!(
+      InlineLintConfig::parse(
+        "\n/* deno-lint-config { \"exclude\": [\"no-explicit-any\"] } */\nlet a: any;",
+      )
+      .unwrap(),
+      Some(InlineLintConfig {
+        include: vec![],
+        exclude: vec!["no-explicit-any".to_string()],
+      })
+    );
+    assert!(InlineLintConfig::parse("/* deno-lint-config { ").is_err());
+    assert!(InlineLintConfig::parse(
+      "/* deno-lint-config { \"other\": [] } */"
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn apply_inline_config() {
+    let config = InlineLintConfig {
+      include: vec!["eqeqeq".to_string()],
+      exclude: vec!["no-explicit-any".to_string()],
+    };
+    let codes = config
+      .apply(&rules::get_recommended_rules())
+      .into_iter()
+      .map(|r| r.code())
+      .collect::<Vec<_>>();
+    assert!(codes.contains(&"eqeqeq"));
+    assert!(!codes.contains(&"no-explicit-any"));
+    assert!(codes.contains(&"no-debugger"));
+  }
+}
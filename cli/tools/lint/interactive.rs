@@ -0,0 +1,148 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::fs;
+use std::io::stdin;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_ast::ParsedSource;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::linter::Linter;
+use deno_lint::rules::LintRule;
+
+use super::apply_lint_fixes_and_relint;
+use super::create_linter;
+use super::lint_file;
+use super::lint_media_type;
+use super::FixedSource;
+use super::InlineLintConfig;
+use crate::util::fs::specifier_from_file_path;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FixAnswer {
+  Yes,
+  No,
+  All,
+  Quit,
+}
+
+fn parse_fix_answer(answer: &str) -> Option<FixAnswer> {
+  match answer.trim() {
+    "y" => Some(FixAnswer::Yes),
+    "n" => Some(FixAnswer::No),
+    "a" => Some(FixAnswer::All),
+    "q" => Some(FixAnswer::Quit),
+    _ => None,
+  }
+}
+
+fn prompt_fix() -> Result<FixAnswer, AnyError> {
+  loop {
+    eprint!("Apply this fix [y,n,a,q]? ");
+    std::io::stderr().flush()?;
+    let mut line = String::new();
+    if stdin().read_line(&mut line)? == 0 {
+      // stdin was closed
+      return Ok(FixAnswer::Quit);
+    }
+    match parse_fix_answer(&line) {
+      Some(answer) => return Ok(answer),
+      None => eprintln!(
+        "y - apply this fix\nn - skip this fix\na - apply this fix and all the remaining ones\nq - quit without applying the remaining fixes"
+      ),
+    }
+  }
+}
+
+/// Shows the fixable problems of the files one at a time and asks whether to
+/// apply their fix, like `git add -p`. The accepted fixes of a file are
+/// written once all of its problems were answered.
+pub fn fix_interactively(
+  paths: &[PathBuf],
+  rules: &[&'static dyn LintRule],
+  jsx_in_js: bool,
+) -> Result<(), AnyError> {
+  let default_linter = create_linter(rules.to_vec());
+  let mut apply_all = false;
+  for file_path in paths {
+    let file_text = fs::read_to_string(file_path)?;
+    let linter = match InlineLintConfig::parse(&file_text)? {
+      Some(inline_config) => create_linter(inline_config.apply(rules)),
+      None => default_linter.clone(),
+    };
+    let FixedSource {
+      source,
+      mut diagnostics,
+      ..
+    } = lint_file(&linter, file_path, file_text, None, jsx_in_js)?;
+    diagnostics.retain(|d| !d.fixes.is_empty());
+    diagnostics.sort_by_key(|d| d.range.start);
+
+    let mut accepted = Vec::new();
+    let mut quit = false;
+    for d in diagnostics {
+      if !apply_all {
+        eprintln!("{}", d.display());
+        match prompt_fix()? {
+          FixAnswer::Yes => {}
+          FixAnswer::No => continue,
+          FixAnswer::All => apply_all = true,
+          FixAnswer::Quit => {
+            quit = true;
+            break;
+          }
+        }
+      }
+      accepted.push(d);
+    }
+
+    write_fixes(&linter, file_path, &source, &accepted, jsx_in_js)?;
+    if quit {
+      break;
+    }
+  }
+  Ok(())
+}
+
+fn write_fixes(
+  linter: &Linter,
+  file_path: &Path,
+  source: &ParsedSource,
+  accepted: &[LintDiagnostic],
+  jsx_in_js: bool,
+) -> Result<(), AnyError> {
+  let specifier = specifier_from_file_path(file_path)?;
+  let media_type = lint_media_type(&specifier, jsx_in_js);
+  // relinting ensures the fixed file still parses
+  let Some((fixed_source, _, _)) = apply_lint_fixes_and_relint(
+    &specifier,
+    media_type,
+    linter,
+    source.text_info(),
+    accepted,
+    &|_| true,
+  )?
+  else {
+    return Ok(());
+  };
+  fs::write(file_path, fixed_source.text_info().text_str())
+    .context("Failed writing fix to file.")
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn fix_answers() {
+    assert!(parse_fix_answer("y\n") == Some(FixAnswer::Yes));
+    assert!(parse_fix_answer("n\n") == Some(FixAnswer::No));
+    assert!(parse_fix_answer("a\n") == Some(FixAnswer::All));
+    assert!(parse_fix_answer("q") == Some(FixAnswer::Quit));
+    assert!(parse_fix_answer("yes\n").is_none());
+  }
+}
@@ -0,0 +1,130 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use deno_ast::ModuleSpecifier;
+use deno_config::WorkspaceMemberConfig;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Serialize;
+
+use super::sort_diagnostics;
+use super::FileDisplay;
+use super::JsonLintDiagnostic;
+use super::LintError;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::args::LintJsonSort;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonWorkspaceMember {
+  name: String,
+  #[serde(skip)]
+  dir: ModuleSpecifier,
+  problem_count: usize,
+  diagnostics: Vec<JsonLintDiagnostic>,
+}
+
+/// Like the JSON reporter, but nests the diagnostics under the workspace
+/// member whose directory contains them. Diagnostics outside of every
+/// member are kept at the top level.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceJsonLintReporter {
+  members: Vec<JsonWorkspaceMember>,
+  diagnostics: Vec<JsonLintDiagnostic>,
+  errors: Vec<LintError>,
+  /// Length in bytes of the linted text of each file with diagnostics,
+  /// keyed by filename, so that clients like editors can tell whether their
+  /// buffer is still the text the ranges refer to.
+  file_byte_lengths: BTreeMap<String, usize>,
+  /// Number of files that were checked.
+  checked: usize,
+  #[serde(skip)]
+  files: FileDisplay,
+  #[serde(skip)]
+  sort: LintJsonSort,
+  #[serde(skip)]
+  warning_rules: HashSet<String>,
+}
+
+impl WorkspaceJsonLintReporter {
+  pub fn new(
+    members: Vec<WorkspaceMemberConfig>,
+    files: FileDisplay,
+    sort: LintJsonSort,
+    warning_rules: HashSet<String>,
+  ) -> Self {
+    Self {
+      members: members
+        .into_iter()
+        .map(|member| JsonWorkspaceMember {
+          dir: member.config_file.specifier.join("./").unwrap(),
+          name: member.package_name,
+          problem_count: 0,
+          diagnostics: Vec::new(),
+        })
+        .collect(),
+      diagnostics: Vec::new(),
+      errors: Vec::new(),
+      file_byte_lengths: BTreeMap::new(),
+      checked: 0,
+      files,
+      sort,
+      warning_rules,
+    }
+  }
+}
+
+impl LintReporter for WorkspaceJsonLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.visit_diagnostic_in_symbol(d, None);
+  }
+
+  fn visit_diagnostic_in_symbol(
+    &mut self,
+    d: LintOrCliDiagnostic,
+    enclosing_symbol: Option<&str>,
+  ) {
+    let diagnostic = JsonLintDiagnostic {
+      enclosing_symbol: enclosing_symbol.map(ToString::to_string),
+      ..JsonLintDiagnostic::new(d, &self.files)
+    };
+    if let Some((text_info, _)) = d.range() {
+      self
+        .file_byte_lengths
+        .entry(diagnostic.filename.clone())
+        .or_insert_with(|| text_info.text_str().len());
+    }
+    // the innermost member when they're nested
+    let specifier = d.specifier().as_str();
+    let member = self
+      .members
+      .iter_mut()
+      .filter(|member| specifier.starts_with(member.dir.as_str()))
+      .max_by_key(|member| member.dir.as_str().len());
+    match member {
+      Some(member) => {
+        member.problem_count += 1;
+        member.diagnostics.push(diagnostic);
+      }
+      None => self.diagnostics.push(diagnostic),
+    }
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    self.errors.push(LintError::new(file_path, err));
+  }
+
+  fn close(&mut self, check_count: usize) {
+    self.checked = check_count;
+    for member in &mut self.members {
+      sort_diagnostics(&mut member.diagnostics, self.sort, &self.warning_rules);
+    }
+    sort_diagnostics(&mut self.diagnostics, self.sort, &self.warning_rules);
+    let json = serde_json::to_string_pretty(&self);
+    println!("{}", json.unwrap());
+  }
+}
@@ -0,0 +1,244 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Linting of the fenced code blocks of Markdown files with `--unstable-md`,
+//! which keeps the examples of the documentation in sync with the code:
+//!
+//! ````md
+//! ```ts
+//! const a: any = 1;
+//! ```
+//! ````
+//!
+//! Each block is linted on its own as the media type of its info string, as
+//! a copy of the file where everything but the code of the block is replaced
+//! with spaces. The positions of the code don't change, so the diagnostics
+//! point at the lines of the Markdown file. The fixes of the diagnostics
+//! aren't applied, so they're removed. A block that doesn't parse is reported
+//! as a problem at its syntax error, so the other blocks are still linted.
+//!
+//! The file has no parsed module of its own, so what needs one, like the
+//! banned imports and `--timings`, skips it.
+
+use std::ops::Range;
+use std::path::Path;
+
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_ast::SourceTextInfo;
+use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::linter::LintFileOptions;
+use deno_lint::linter::Linter;
+
+/// Info string word of the blocks that aren't linted.
+const IGNORE_INFO: &str = "ignore";
+
+/// Code of the problems of the code blocks that don't parse.
+const CODE_BLOCK_SYNTAX_ERROR_CODE: &str = "code-block-syntax-error";
+
+pub fn is_markdown_path(path: &Path) -> bool {
+  path.extension().is_some_and(|ext| {
+    ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+  })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct CodeBlock {
+  media_type: MediaType,
+  /// Byte range of the lines between the fences.
+  range: Range<usize>,
+}
+
+struct OpenFence {
+  fence_char: char,
+  fence_len: usize,
+  /// `None` for the blocks that aren't linted.
+  media_type: Option<MediaType>,
+  content_start: usize,
+}
+
+/// Lints the code blocks of a Markdown file, returning the text of the file
+/// that the diagnostics point into.
+pub fn lint_markdown_file(
+  linter: &Linter,
+  specifier: &ModuleSpecifier,
+  source_code: String,
+  jsx_in_js: bool,
+) -> (SourceTextInfo, Vec<LintDiagnostic>) {
+  let text_info = SourceTextInfo::from_string(source_code);
+  let text = text_info.text_str();
+  let mut diagnostics = Vec::new();
+  for block in find_code_blocks(text, jsx_in_js) {
+    let r = linter.lint_file(LintFileOptions {
+      specifier: specifier.clone(),
+      media_type: block.media_type,
+      source_code: mask_outside(text, &block.range),
+    });
+    match r {
+      Ok((_, block_diagnostics)) => {
+        diagnostics.extend(block_diagnostics.into_iter().map(|mut d| {
+          d.text_info = text_info.clone();
+          d.fixes.clear();
+          d
+        }));
+      }
+      Err(err) => diagnostics.push(LintDiagnostic {
+        specifier: specifier.clone(),
+        range: err.range,
+        text_info: text_info.clone(),
+        message: err.message().to_string(),
+        code: CODE_BLOCK_SYNTAX_ERROR_CODE.to_string(),
+        hint: Some(
+          "Fix the code or add `ignore` to the info string of the block"
+            .to_string(),
+        ),
+        fixes: Vec::new(),
+      }),
+    }
+  }
+  (text_info, diagnostics)
+}
+
+/// Finds the fenced code blocks with a supported info string. A block
+/// without a closing fence ends with the file.
+fn find_code_blocks(text: &str, jsx_in_js: bool) -> Vec<CodeBlock> {
+  let mut blocks = Vec::new();
+  let mut open_fence: Option<OpenFence> = None;
+  let mut line_start = 0;
+  for line in text.split_inclusive('\n') {
+    let line_end = line_start + line.len();
+    match &open_fence {
+      Some(fence) => {
+        if is_closing_fence(line, fence) {
+          if let Some(media_type) = fence.media_type {
+            blocks.push(CodeBlock {
+              media_type,
+              range: fence.content_start..line_start,
+            });
+          }
+          open_fence = None;
+        }
+      }
+      None => {
+        open_fence = parse_opening_fence(line, line_end, jsx_in_js);
+      }
+    }
+    line_start = line_end;
+  }
+  if let Some(OpenFence {
+    media_type: Some(media_type),
+    content_start,
+    ..
+  }) = open_fence
+  {
+    blocks.push(CodeBlock {
+      media_type,
+      range: content_start..text.len(),
+    });
+  }
+  blocks
+}
+
+/// Gets the fence of the line when it opens a code block, which is indented
+/// by up to three spaces.
+fn parse_opening_fence(
+  line: &str,
+  line_end: usize,
+  jsx_in_js: bool,
+) -> Option<OpenFence> {
+  let (fence_char, fence_len, rest) = split_fence(line)?;
+  let info = rest.trim();
+  if fence_char == '`' && info.contains('`') {
+    return None;
+  }
+  let media_type = match info.split_whitespace().next() {
+    Some(_) if info.split_whitespace().any(|w| w == IGNORE_INFO) => None,
+    Some("ts" | "typescript") => Some(MediaType::TypeScript),
+    Some("tsx") => Some(MediaType::Tsx),
+    Some("js" | "javascript") if jsx_in_js => Some(MediaType::Jsx),
+    Some("js" | "javascript") => Some(MediaType::JavaScript),
+    Some("jsx") => Some(MediaType::Jsx),
+    _ => None,
+  };
+  Some(OpenFence {
+    fence_char,
+    fence_len,
+    media_type,
+    content_start: line_end,
+  })
+}
+
+fn is_closing_fence(line: &str, fence: &OpenFence) -> bool {
+  split_fence(line).is_some_and(|(fence_char, fence_len, rest)| {
+    fence_char == fence.fence_char
+      && fence_len >= fence.fence_len
+      && rest.trim().is_empty()
+  })
+}
+
+/// Splits a line that starts with a fence of at least three backticks or
+/// tildes into the character and length of the fence and the rest.
+fn split_fence(line: &str) -> Option<(char, usize, &str)> {
+  let trimmed = line.trim_start_matches(' ');
+  if line.len() - trimmed.len() > 3 {
+    return None;
+  }
+  let fence_char = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+  let fence_len = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+  if fence_len < 3 {
+    return None;
+  }
+  Some((fence_char, fence_len, &trimmed[fence_len..]))
+}
+
+/// Replaces the text outside the range with spaces, keeping the line breaks
+/// and the byte length of every character so the positions don't change.
+fn mask_outside(text: &str, range: &Range<usize>) -> String {
+  let mut masked = String::with_capacity(text.len());
+  for (index, c) in text.char_indices() {
+    if range.contains(&index) || matches!(c, '\n' | '\r') {
+      masked.push(c);
+    } else {
+      masked.extend(std::iter::repeat(' ').take(c.len_utf8()));
+    }
+  }
+  masked
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn finds_code_blocks() {
+    let text = "# Title\n\n```ts\nlet a = 1;\n```\n\n```ts ignore\nlet b;\n```\n\n~~~~jsx\n<div />;\n```\n~~~~\n\n```sh\nls\n```\n\n   ```js\nlet c;\n";
+    assert_eq!(
+      find_code_blocks(text, false),
+      vec![
+        CodeBlock {
+          media_type: MediaType::TypeScript,
+          range: 15..26,
+        },
+        CodeBlock {
+          media_type: MediaType::Jsx,
+          range: 64..77,
+        },
+        CodeBlock {
+          media_type: MediaType::JavaScript,
+          range: 106..113,
+        },
+      ]
+    );
+    assert_eq!(&text[15..26], "let a = 1;\n");
+    assert_eq!(&text[64..77], "<div />;\n```\n");
+    assert_eq!(&text[106..113], "let c;\n");
+    assert_eq!(find_code_blocks(text, true)[2].media_type, MediaType::Jsx);
+  }
+
+  #[test]
+  fn masks_outside_of_range() {
+    let text = "é ```\nlet a;\r\n```";
+    let masked = mask_outside(text, &(11..14));
+    assert_eq!(masked, "      \n    a;\r\n   ");
+    assert_eq!(masked.len(), text.len());
+  }
+}
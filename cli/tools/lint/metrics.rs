@@ -0,0 +1,184 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::net::UdpSocket;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::url::Url;
+use deno_runtime::tokio_util::create_basic_runtime;
+
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+use crate::http_util::HttpClient;
+
+/// Aggregated counts of a lint run.
+#[derive(Debug, Default)]
+struct LintMetrics {
+  problems: usize,
+  checked_files: usize,
+  duration: Duration,
+  rule_problems: BTreeMap<String, usize>,
+}
+
+impl LintMetrics {
+  /// Formats the metrics in the Prometheus text exposition format, as
+  /// accepted by a pushgateway.
+  fn to_prometheus(&self) -> String {
+    let mut text = String::new();
+    let mut gauge = |name: &str, value: &dyn std::fmt::Display| {
+      writeln!(text, "# TYPE {name} gauge").unwrap();
+      writeln!(text, "{name} {value}").unwrap();
+    };
+    gauge("deno_lint_problems", &self.problems);
+    gauge("deno_lint_checked_files", &self.checked_files);
+    gauge("deno_lint_duration_seconds", &self.duration.as_secs_f64());
+    writeln!(text, "# TYPE deno_lint_rule_problems gauge").unwrap();
+    for (rule, count) in &self.rule_problems {
+      writeln!(text, "deno_lint_rule_problems{{rule=\"{rule}\"}} {count}")
+        .unwrap();
+    }
+    text
+  }
+
+  /// Formats the metrics as StatsD packets.
+  fn to_statsd(&self) -> Vec<String> {
+    let mut packets = vec![
+      format!("deno_lint.problems:{}|g", self.problems),
+      format!("deno_lint.checked_files:{}|g", self.checked_files),
+      format!("deno_lint.duration:{}|ms", self.duration.as_millis()),
+    ];
+    packets.extend(
+      self
+        .rule_problems
+        .iter()
+        .map(|(rule, count)| format!("deno_lint.rule.{rule}:{count}|g")),
+    );
+    packets
+  }
+}
+
+/// Pushes the aggregated counts of a run to a metrics endpoint when the
+/// reporter is closed. A `statsd://host:port` url sends StatsD packets over
+/// UDP, while an http(s) url posts to a Prometheus pushgateway.
+pub struct MetricsLintReporter {
+  url: Url,
+  http_client: Arc<HttpClient>,
+  start: Instant,
+  metrics: LintMetrics,
+}
+
+impl MetricsLintReporter {
+  pub fn new(url: Url, http_client: Arc<HttpClient>) -> Self {
+    Self {
+      url,
+      http_client,
+      start: Instant::now(),
+      metrics: Default::default(),
+    }
+  }
+
+  fn push(&self) -> Result<(), AnyError> {
+    match self.url.scheme() {
+      "statsd" => {
+        let (Some(host), Some(port)) = (self.url.host_str(), self.url.port())
+        else {
+          bail!("Expected a host and port in '{}'.", self.url);
+        };
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        for packet in self.metrics.to_statsd() {
+          socket.send_to(packet.as_bytes(), (host, port))?;
+        }
+        Ok(())
+      }
+      "http" | "https" => {
+        let client = self.http_client.client()?.clone();
+        let url = self.url.clone();
+        let body = self.metrics.to_prometheus();
+        // the reporter is closed synchronously, so push on a separate runtime
+        std::thread::spawn(move || {
+          create_basic_runtime().block_on(async move {
+            client
+              .post(url)
+              .body(body)
+              .send()
+              .await?
+              .error_for_status()?;
+            Ok::<_, AnyError>(())
+          })
+        })
+        .join()
+        .unwrap()
+      }
+      scheme => bail!("Unsupported metrics url scheme '{}'.", scheme),
+    }
+  }
+}
+
+impl LintReporter for MetricsLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.metrics.problems += 1;
+    *self
+      .metrics
+      .rule_problems
+      .entry(d.code().to_string())
+      .or_default() += 1;
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {}
+
+  fn close(&mut self, check_count: usize) {
+    self.metrics.checked_files = check_count;
+    self.metrics.duration = self.start.elapsed();
+    if let Err(err) = self.push() {
+      log::warn!("Failed pushing lint metrics to '{}': {:#}", self.url, err);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn format_metrics() {
+    let metrics = LintMetrics {
+      problems: 3,
+      checked_files: 2,
+      duration: Duration::from_millis(1500),
+      rule_problems: BTreeMap::from([
+        ("no-unused-vars".to_string(), 2),
+        ("prefer-const".to_string(), 1),
+      ]),
+    };
+    assert_eq!(
+      metrics.to_prometheus(),
+      concat!(
+        "# TYPE deno_lint_problems gauge\n",
+        "deno_lint_problems 3\n",
+        "# TYPE deno_lint_checked_files gauge\n",
+        "deno_lint_checked_files 2\n",
+        "# TYPE deno_lint_duration_seconds gauge\n",
+        "deno_lint_duration_seconds 1.5\n",
+        "# TYPE deno_lint_rule_problems gauge\n",
+        "deno_lint_rule_problems{rule=\"no-unused-vars\"} 2\n",
+        "deno_lint_rule_problems{rule=\"prefer-const\"} 1\n",
+      )
+    );
+    assert_eq!(
+      metrics.to_statsd(),
+      vec![
+        "deno_lint.problems:3|g",
+        "deno_lint.checked_files:2|g",
+        "deno_lint.duration:1500|ms",
+        "deno_lint.rule.no-unused-vars:2|g",
+        "deno_lint.rule.prefer-const:1|g",
+      ]
+    );
+  }
+}
@@ -2,19 +2,21 @@
 
 //! This module provides file linting utilities using
 //! [`deno_lint`](https://github.com/denoland/deno_lint).
+use chrono::NaiveDate;
 use deno_ast::diagnostics::Diagnostic;
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
 use deno_ast::ParsedSource;
 use deno_ast::SourceRange;
 use deno_ast::SourceTextInfo;
-use deno_config::glob::FilePatterns;
 use deno_core::anyhow::bail;
 use deno_core::anyhow::Context;
 use deno_core::error::generic_error;
 use deno_core::error::AnyError;
 use deno_core::parking_lot::Mutex;
 use deno_core::serde_json;
+use deno_core::unsync::spawn_blocking;
+use deno_core::unsync::JoinHandle;
 use deno_graph::FastCheckDiagnostic;
 use deno_lint::diagnostic::LintDiagnostic;
 use deno_lint::linter::LintFileOptions;
@@ -26,43 +28,249 @@ use log::debug;
 use log::info;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
 use std::io::stdin;
+use std::io::IsTerminal;
 use std::io::Read;
+use std::io::Write;
+use std::ops::Range;
+use std::ops::RangeInclusive;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::RecvTimeoutError;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::args::Flags;
 use crate::args::LintFlags;
+use crate::args::LintJsonSort;
 use crate::args::LintOptions;
 use crate::args::LintReporterKind;
 use crate::args::LintRulesConfig;
+use crate::args::LintTestFiles;
+use crate::args::PrettyLintOptions;
 use crate::cache::IncrementalCache;
 use crate::colors;
 use crate::factory::CliFactory;
-use crate::tools::fmt::run_parallelized;
+use crate::http_util::HttpClient;
+use crate::tools::fmt::run_parallelized_bounded;
+use crate::util::diff::unified_diff;
+use crate::util::display::human_elapsed;
+use crate::util::display::human_size;
 use crate::util::file_watcher;
 use crate::util::fs::canonicalize_path;
 use crate::util::fs::specifier_from_file_path;
 use crate::util::fs::FileCollector;
+use crate::util::fs::WalkEntry;
 use crate::util::path::is_script_ext;
+use crate::util::path::specifier_to_file_path;
 use crate::util::sync::AtomicFlag;
-
+use crate::util::text_encoding::normalize_line_endings;
+use crate::util::time::utc_now;
+
+mod banned_imports;
+mod baseline;
+mod blame;
+mod budgets;
+mod checkstyle;
+mod ci;
+mod compare;
+mod coverage;
+mod db_output;
+mod diagnostics_cache;
+mod editor;
+mod enclosing_symbol;
+mod explain;
+mod file_patterns;
+mod file_results;
+mod file_threshold;
+mod fingerprint;
+mod fix_preview;
+mod generated;
+mod ignore_until;
+mod inbox;
+mod inline_config;
+mod interactive;
+mod json_workspace;
+mod markdown;
+mod metrics;
+mod ndjson;
 pub mod no_slow_types;
-
-static STDIN_FILE_NAME: &str = "$deno$stdin.ts";
-
-fn create_reporter(kind: LintReporterKind) -> Box<dyn LintReporter + Send> {
-  match kind {
-    LintReporterKind::Pretty => Box::new(PrettyLintReporter::new()),
-    LintReporterKind::Json => Box::new(JsonLintReporter::new()),
-    LintReporterKind::Compact => Box::new(CompactLintReporter::new()),
+mod protobuf;
+mod rules_hash;
+mod snippet_style;
+mod svg;
+mod tap;
+mod timing;
+mod unknown_rules;
+mod xml;
+
+use banned_imports::BannedImportDiagnostic;
+use banned_imports::NO_BANNED_IMPORTS_NAME;
+use baseline::LintBaseline;
+use blame::BlameLintReporter;
+use budgets::BudgetLintReporter;
+use checkstyle::CheckstyleLintReporter;
+use ci::GithubActionsLintReporter;
+use ci::GitlabLintReporter;
+use compare::CompareLintReporter;
+use coverage::check_lint_coverage;
+use db_output::SqliteLintReporter;
+use editor::EditorLintReporter;
+use enclosing_symbol::EnclosingSymbols;
+pub use explain::explain_rule;
+use file_patterns::print_file_patterns;
+use file_results::FileResults;
+use file_threshold::FileThresholdLintReporter;
+use fingerprint::relative_filename;
+use fix_preview::FixPreviewLintReporter;
+use ignore_until::apply_ignore_until_directives;
+use ignore_until::may_have_ignore_until_directives;
+use ignore_until::unexpired_ignores;
+use inbox::lint_inbox;
+use inline_config::InlineLintConfig;
+use interactive::fix_interactively;
+use json_workspace::WorkspaceJsonLintReporter;
+use markdown::is_markdown_path;
+use markdown::lint_markdown_file;
+use metrics::MetricsLintReporter;
+use ndjson::NdJsonLintReporter;
+use protobuf::ProtobufLintReporter;
+pub use rules_hash::check_rules_hash;
+use snippet_style::link_location;
+use snippet_style::restyle_snippet;
+use svg::SvgLintReporter;
+use tap::TapLintReporter;
+use timing::LintTimings;
+use unknown_rules::find_unknown_rules;
+use unknown_rules::format_unknown_rules;
+
+static STDIN_FILE_STEM: &str = "$deno$stdin";
+static EVAL_FILE_STEM: &str = "$deno$eval";
+
+/// `ratchet_failed` is raised by the reporters of `--compare` and the
+/// "budgets" configuration, which decide whether the run fails when they're
+/// used rather than whether there are any problems.
+fn create_reporter(
+  lint_options: &LintOptions,
+  http_client: &Arc<HttpClient>,
+  maybe_config_file: Option<&deno_config::ConfigFile>,
+  ratchet_failed: &Arc<AtomicFlag>,
+) -> Result<Box<dyn LintReporter + Send>, AnyError> {
+  let files = FileDisplay::new(lint_options);
+  let mut reporters: Vec<Box<dyn LintReporter + Send>> = Vec::with_capacity(4);
+  reporters.push(match lint_options.reporter.kind {
+    LintReporterKind::Pretty => Box::new(PrettyLintReporter::new(
+      lint_options.reporter.pretty.clone(),
+      files.with_file_paths(),
+    )),
+    LintReporterKind::Json => Box::new(JsonLintReporter::new(
+      files,
+      lint_options.reporter.json_sort,
+      lint_options.warning_rules.clone(),
+    )),
+    LintReporterKind::JsonWorkspace => match maybe_config_file {
+      Some(config_file) if !config_file.json.workspaces.is_empty() => {
+        Box::new(WorkspaceJsonLintReporter::new(
+          config_file.to_workspace_members()?,
+          files,
+          lint_options.reporter.json_sort,
+          lint_options.warning_rules.clone(),
+        ))
+      }
+      // not in a workspace, so there are no members to nest under
+      _ => Box::new(JsonLintReporter::new(
+        files,
+        lint_options.reporter.json_sort,
+        lint_options.warning_rules.clone(),
+      )),
+    },
+    LintReporterKind::NdJson => Box::new(NdJsonLintReporter::new(files)),
+    LintReporterKind::Checkstyle => Box::new(CheckstyleLintReporter::new(
+      lint_options.warning_rules.clone(),
+      files,
+    )),
+    LintReporterKind::Tap => Box::new(TapLintReporter::new(files)),
+    LintReporterKind::ByRule => Box::new(PrettyLintReporter::new(
+      PrettyLintOptions {
+        group_by_rule: true,
+        locations_only: true,
+        ..lint_options.reporter.pretty.clone()
+      },
+      files.with_file_paths(),
+    )),
+    LintReporterKind::Compact => Box::new(CompactLintReporter::new(
+      files,
+      lint_options.reporter.compact_ranges,
+    )),
+    LintReporterKind::GithubActions => Box::new(
+      GithubActionsLintReporter::new(lint_options.warning_rules.clone()),
+    ),
+    LintReporterKind::Gitlab => {
+      Box::new(GitlabLintReporter::new(lint_options.warning_rules.clone()))
+    }
+    LintReporterKind::FixPreview => Box::<FixPreviewLintReporter>::default(),
+    LintReporterKind::Protobuf => Box::new(ProtobufLintReporter::new(
+      lint_options.reporter.output.as_deref(),
+      lint_options.warning_rules.clone(),
+      FileDisplay::new(lint_options),
+    )?),
+  });
+  if let Some(db_output) = &lint_options.reporter.db_output {
+    reporters.push(Box::new(SqliteLintReporter::new(db_output)?));
+  }
+  if let Some(svg_dir) = &lint_options.reporter.svg_dir {
+    reporters.push(Box::new(SvgLintReporter::new(svg_dir)?));
   }
+  if let Some(compare) = &lint_options.compare {
+    reporters.push(Box::new(CompareLintReporter::new(
+      compare,
+      std::env::current_dir()?,
+      ratchet_failed.clone(),
+    )?));
+  }
+  if !lint_options.budgets.is_empty() {
+    reporters.push(Box::new(BudgetLintReporter::new(
+      lint_options.budgets.clone(),
+      ratchet_failed.clone(),
+    )));
+  }
+  if let Some(metrics_url) = &lint_options.metrics_url {
+    reporters.push(Box::new(MetricsLintReporter::new(
+      metrics_url.clone(),
+      http_client.clone(),
+    )));
+  }
+  if lint_options.reporter.blame {
+    reporters.push(Box::<BlameLintReporter>::default());
+  }
+  if let Some(threshold) = lint_options.reporter.file_problem_threshold {
+    reporters.push(Box::new(FileThresholdLintReporter::new(
+      threshold,
+      FileDisplay::new(lint_options).with_file_paths(),
+    )));
+  }
+  // last so the editor is opened after the results are printed
+  if lint_options.edit {
+    reporters.push(Box::<EditorLintReporter>::default());
+  }
+  Ok(if reporters.len() == 1 {
+    reporters.remove(0)
+  } else {
+    Box::new(CombinedLintReporter(reporters))
+  })
 }
 
 pub async fn lint(flags: Flags, lint_flags: LintFlags) -> Result<(), AnyError> {
+  if lint_flags.inbox.is_some() {
+    return lint_inbox(flags, lint_flags).await;
+  }
   if let Some(watch_flags) = &lint_flags.watch {
     if lint_flags.is_stdin() {
       return Err(generic_error(
@@ -74,19 +282,18 @@ pub async fn lint(flags: Flags, lint_flags: LintFlags) -> Result<(), AnyError> {
       file_watcher::PrintConfig::new("Lint", !watch_flags.no_clear_screen),
       move |flags, watcher_communicator, changed_paths| {
         let lint_flags = lint_flags.clone();
-        Ok(async move {
+        let watch_no_fail = lint_flags.watch_no_fail;
+        let operation = async move {
           let factory = CliFactory::from_flags(flags)?;
           let cli_options = factory.cli_options();
           let lint_options = cli_options.resolve_lint_options(lint_flags)?;
-          let files = collect_lint_files(lint_options.files.clone()).and_then(
-            |files| {
-              if files.is_empty() {
-                Err(generic_error("No target files found."))
-              } else {
-                Ok(files)
-              }
-            },
-          )?;
+          let files = collect_lint_files(&lint_options).and_then(|files| {
+            if files.is_empty() {
+              Err(generic_error("No target files found."))
+            } else {
+              Ok(files)
+            }
+          })?;
           _ = watcher_communicator.watch_paths(files.clone());
 
           let lint_paths = if let Some(paths) = changed_paths {
@@ -105,46 +312,196 @@ pub async fn lint(flags: Flags, lint_flags: LintFlags) -> Result<(), AnyError> {
           };
 
           lint_files(factory, lint_options, lint_paths).await?;
-          Ok(())
+          Ok::<_, AnyError>(())
+        };
+        Ok(async move {
+          match operation.await {
+            // keep the iteration from being reported as failed
+            Err(err) if watch_no_fail => {
+              eprintln!("{}: {:#}", colors::red_bold("error"), err);
+              Ok(())
+            }
+            result => result,
+          }
         })
       },
     )
     .await?;
   } else {
+    if let Some(success_marker) = &lint_flags.success_marker {
+      // removed before the options are resolved so that it's absent whenever
+      // the run errors out, including on an invalid config file
+      let cwd = std::env::current_dir()?;
+      remove_success_marker(&cwd.join(success_marker))?;
+    }
     let factory = CliFactory::from_flags(flags)?;
     let cli_options = factory.cli_options();
     let is_stdin = lint_flags.is_stdin();
+    let maybe_eval = lint_flags.eval.clone();
+    let stdin_ext = lint_flags.stdin_ext.clone();
+    let only_print_file_patterns = lint_flags.print_file_patterns;
+    let stdin_file_list = lint_flags.stdin_file_list;
+    let no_error_on_issues = lint_flags.no_error_on_issues;
     let lint_options = cli_options.resolve_lint_options(lint_flags)?;
-    let files = &lint_options.files;
-    let success = if is_stdin {
-      let reporter_kind = lint_options.reporter_kind;
-      let reporter_lock = Arc::new(Mutex::new(create_reporter(reporter_kind)));
-      let lint_rules = get_config_rules_err_empty(
-        lint_options.rules,
-        cli_options.maybe_config_file().as_ref(),
-      )?;
-      let file_path = cli_options.initial_cwd().join(STDIN_FILE_NAME);
-      let r = lint_stdin(&file_path, lint_rules.rules);
+    if only_print_file_patterns {
+      print_file_patterns(
+        &lint_options,
+        lint_options.reporter.kind == LintReporterKind::Json,
+      );
+      return Ok(());
+    }
+    let success_marker = lint_options.success_marker.clone();
+    let success = if is_stdin || maybe_eval.is_some() {
+      let maybe_config_file = cli_options.maybe_config_file().as_ref();
+      let ratchet_failed = Arc::new(AtomicFlag::default());
+      let reporter_lock = Arc::new(Mutex::new(create_reporter(
+        &lint_options,
+        factory.http_client(),
+        maybe_config_file,
+        &ratchet_failed,
+      )?));
+      let lint_rules =
+        get_config_rules_err_empty(&lint_options, maybe_config_file)?;
+      if lint_options.print_config {
+        print_effective_config(
+          &lint_options.rules,
+          &lint_rules,
+          maybe_config_file,
+        );
+      }
+      let file_stem = if maybe_eval.is_some() {
+        EVAL_FILE_STEM
+      } else {
+        STDIN_FILE_STEM
+      };
+      let file_path = cli_options.initial_cwd().join(format!(
+        "{}.{}",
+        file_stem,
+        stdin_ext.as_deref().unwrap_or("ts")
+      ));
+      let baseline = create_baseline(&lint_options, cli_options.initial_cwd())?;
+      let today = utc_now().date_naive();
+      let hidden_rules = get_hidden_rule_codes(&lint_options.filter.hide_tags);
+      reporter_lock
+        .lock()
+        .visit_checked_files(std::slice::from_ref(&file_path));
+      let r = lint_stdin(&file_path, maybe_eval, lint_rules.rules).and_then(
+        |(source, mut diagnostics)| {
+          append_rule_messages(&mut diagnostics, &lint_options.rule_messages);
+          apply_ignore_until_directives(&source, &mut diagnostics, today)?;
+          Ok((Some(source), diagnostics))
+        },
+      );
+      let mut banned_import_diagnostics = match &r {
+        Ok((Some(source), _)) => collect_reported_banned_imports(
+          source,
+          &lint_rules.banned_imports,
+          &hidden_rules,
+        ),
+        Ok((None, _)) | Err(_) => Vec::new(),
+      };
+      let r = r.map(|(source, mut diagnostics)| {
+        filter_reported_diagnostics(
+          &file_path,
+          &mut diagnostics,
+          LintOrCliDiagnostic::Lint,
+          &lint_options,
+          &hidden_rules,
+          baseline.as_ref(),
+        );
+        // the banned imports aren't recorded in the baseline
+        filter_reported_diagnostics(
+          &file_path,
+          &mut banned_import_diagnostics,
+          LintOrCliDiagnostic::BannedImport,
+          &lint_options,
+          &hidden_rules,
+          None,
+        );
+        (source, diagnostics)
+      });
+      let has_file_error = r.is_err();
       let success = handle_lint_result(
         &file_path.to_string_lossy(),
         r,
         reporter_lock.clone(),
+        &lint_options.warning_rules,
+        lint_options.reporter.enclosing_symbol,
+        lint_options.reporter.one_per_file,
       );
+      let passes_banned_imports = report_banned_imports(
+        &mut **reporter_lock.lock(),
+        &banned_import_diagnostics,
+        &lint_options.warning_rules,
+      );
+      let success = success && passes_banned_imports;
       reporter_lock.lock().close(1);
-      success
+      if let Some(baseline) = &baseline {
+        write_baseline(baseline, &lint_options)?;
+      }
+      if lint_options.compare.is_some() || !lint_options.budgets.is_empty() {
+        !ratchet_failed.is_raised() && !has_file_error
+      } else {
+        success
+      }
     } else {
-      let target_files =
-        collect_lint_files(files.clone()).and_then(|files| {
-          if files.is_empty() {
-            Err(generic_error("No target files found."))
-          } else {
-            Ok(files)
-          }
-        })?;
+      let target_files = if stdin_file_list {
+        read_stdin_file_list(cli_options.initial_cwd())
+      } else {
+        collect_lint_files(&lint_options)
+      }
+      .and_then(|files| {
+        if files.is_empty() {
+          Err(generic_error("No target files found."))
+        } else {
+          Ok(files)
+        }
+      })?;
       debug!("Found {} files", target_files.len());
+      if lint_options.require_coverage {
+        let root = match cli_options.maybe_config_file() {
+          Some(config_file) => specifier_to_file_path(&config_file.specifier)?
+            .parent()
+            .unwrap()
+            .to_path_buf(),
+          None => cli_options.initial_cwd().to_path_buf(),
+        };
+        check_lint_coverage(&lint_options, &root, &target_files)?;
+      }
+      if lint_options.fix.interactive {
+        if stdin().is_terminal() {
+          let lint_rules = get_config_rules_err_empty(
+            &lint_options,
+            cli_options.maybe_config_file().as_ref(),
+          )?;
+          fix_interactively(
+            &target_files,
+            &lint_rules.rules,
+            lint_options.jsx_in_js,
+          )?;
+        } else {
+          log::warn!(
+            "{} --interactive was ignored because stdin is not a terminal.",
+            colors::yellow("Warning"),
+          );
+        }
+      }
+      // report the problems that are left
       lint_files(factory, lint_options, target_files).await?
     };
-    if !success {
+    if let Some(success_marker) = &success_marker {
+      if success {
+        fs::write(success_marker, crate::util::time::utc_now().to_rfc3339())
+          .with_context(|| {
+            format!(
+              "Failed writing success marker '{}'.",
+              success_marker.display()
+            )
+          })?;
+      }
+    }
+    // the problems are reported either way
+    if !success && !no_error_on_issues {
       std::process::exit(1);
     }
   }
@@ -152,39 +509,131 @@ pub async fn lint(flags: Flags, lint_flags: LintFlags) -> Result<(), AnyError> {
   Ok(())
 }
 
+fn remove_success_marker(path: &Path) -> Result<(), AnyError> {
+  match fs::remove_file(path) {
+    Ok(()) => Ok(()),
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+    Err(err) => Err(err).with_context(|| {
+      format!("Failed removing success marker '{}'.", path.display())
+    }),
+  }
+}
+
 async fn lint_files(
   factory: CliFactory,
   lint_options: LintOptions,
   paths: Vec<PathBuf>,
 ) -> Result<bool, AnyError> {
+  let lint_options = Arc::new(lint_options);
+  let (paths, duplicate_paths) = dedup_lint_paths(paths);
+  if lint_options.reporter.verbose {
+    for path in &duplicate_paths {
+      log::warn!(
+        "{} '{}' was included more than once and is only linted once.",
+        colors::yellow("Warning"),
+        path.display(),
+      );
+    }
+  }
   let caches = factory.caches()?;
   let maybe_config_file = factory.cli_options().maybe_config_file().as_ref();
+  // todo(dsherret): abstract away this lock behind a performant interface
+  let ratchet_failed = Arc::new(AtomicFlag::default());
+  let reporter_lock = Arc::new(Mutex::new(create_reporter(
+    &lint_options,
+    factory.http_client(),
+    maybe_config_file,
+    &ratchet_failed,
+  )?));
   let lint_rules =
-    get_config_rules_err_empty(lint_options.rules, maybe_config_file)?;
-  let incremental_cache = Arc::new(IncrementalCache::new(
-    caches.lint_incremental_cache_db(),
-    &lint_rules.incremental_cache_state(),
-    &paths,
-  ));
+    get_config_rules_err_empty(&lint_options, maybe_config_file)?;
+  if lint_options.print_config {
+    print_effective_config(&lint_options.rules, &lint_rules, maybe_config_file);
+  }
+  if lint_options.reporter.verbose {
+    print_enabled_rules_summary(
+      &lint_options.rules,
+      &lint_rules,
+      maybe_config_file,
+    );
+  }
+  let mut cache_state = lint_rules.incremental_cache_state();
+  if let Some(generated) = &lint_options.filter.generated {
+    // whether a file is generated changes the rules it's linted with
+    cache_state.push(if generated.skip {
+      "skip-generated"
+    } else {
+      "generated"
+    });
+    cache_state.extend(generated.globs.iter().map(|s| s.as_str()));
+  }
+  // these change how a file is parsed and where its diagnostics are, while
+  // the cache is keyed on the text of the file
+  if lint_options.jsx_in_js {
+    cache_state.push("jsx-in-js");
+  }
+  if lint_options.normalize_eol {
+    cache_state.push("normalize-eol");
+  }
+  let cache_db = match &lint_options.cache.path {
+    Some(cache_path) => caches.lint_incremental_cache_db_at(cache_path.clone()),
+    None => caches.lint_incremental_cache_db(),
+  };
+  let incremental_cache = Arc::new(match &lint_options.cache.namespace {
+    // entries of different namespaces have different state hashes
+    Some(cache_namespace) => {
+      IncrementalCache::new(cache_db, &(cache_state, cache_namespace), &paths)
+    }
+    None => IncrementalCache::new(cache_db, &cache_state, &paths),
+  });
   let target_files_len = paths.len();
-  let reporter_kind = lint_options.reporter_kind;
-  // todo(dsherret): abstract away this lock behind a performant interface
-  let reporter_lock =
-    Arc::new(Mutex::new(create_reporter(reporter_kind.clone())));
+  reporter_lock.lock().visit_checked_files(&paths);
   let has_error = Arc::new(AtomicFlag::default());
+  // failures other than problems, like files that couldn't be read or
+  // linted and the changes of a dry run, which fail the run regardless of
+  // `--max-warnings`
+  let has_file_error = Arc::new(AtomicFlag::default());
+  let regressed_paths = Arc::new(Mutex::new(Vec::new()));
+  let problem_count = Arc::new(AtomicUsize::new(0));
+  let source_stats = Arc::new(LintedSourceStats::default());
+  let file_results = lint_options
+    .reporter
+    .file_results
+    .as_ref()
+    .map(|_| Arc::new(FileResults::new(FileDisplay::new(&lint_options))));
+  let timings = lint_options
+    .reporter
+    .timing
+    .then(|| Arc::new(LintTimings::default()));
+  // loaded once and shared by the tasks of all files
+  let baseline =
+    create_baseline(&lint_options, factory.cli_options().initial_cwd())?
+      .map(Arc::new);
+  // the same date for all files, for the deno-lint-ignore-until directives
+  let today = utc_now().date_naive();
+  let start = Instant::now();
 
   let mut futures = Vec::with_capacity(2);
   if lint_rules.no_slow_types {
     if let Some(config_file) = maybe_config_file {
       let members = config_file.to_workspace_members()?;
       let has_error = has_error.clone();
+      let fail_on_slow_types =
+        !lint_options.warning_rules.contains(NO_SLOW_TYPES_NAME);
+      let problem_count = problem_count.clone();
+      let file_results = file_results.clone();
       let reporter_lock = reporter_lock.clone();
+      let lint_options = lint_options.clone();
+      let hidden_rules = get_hidden_rule_codes(&lint_options.filter.hide_tags);
+      let baseline = baseline.clone();
       let module_graph_creator = factory.module_graph_creator().await?.clone();
       let path_urls = paths
         .iter()
         .filter_map(|p| ModuleSpecifier::from_file_path(p).ok())
         .collect::<HashSet<_>>();
       futures.push(deno_core::unsync::spawn(async move {
+        // the progress of loading the graph is logged at the info level, so
+        // --quiet silences it while the diagnostics are still reported
         let graph = module_graph_creator
           .create_and_validate_publish_graph(&members, true)
           .await?;
@@ -201,8 +650,40 @@ async fn lint_files(
             &export_urls,
             &graph,
           );
-          if !diagnostics.is_empty() {
-            has_error.raise();
+          // filtered by their file like the problems of the linted files
+          let mut diagnostics_by_file = BTreeMap::<_, Vec<_>>::new();
+          for diagnostic in diagnostics {
+            diagnostics_by_file
+              .entry(diagnostic.specifier().clone())
+              .or_default()
+              .push(diagnostic);
+          }
+          for (specifier, mut diagnostics) in diagnostics_by_file {
+            let path = specifier_to_file_path(&specifier).ok();
+            if let Some(path) = &path {
+              filter_reported_diagnostics(
+                path,
+                &mut diagnostics,
+                LintOrCliDiagnostic::FastCheck,
+                &lint_options,
+                &hidden_rules,
+                baseline.as_deref(),
+              );
+            }
+            if diagnostics.is_empty() {
+              continue;
+            }
+            if fail_on_slow_types {
+              has_error.raise();
+            }
+            problem_count.fetch_add(diagnostics.len(), Ordering::Relaxed);
+            if let (Some(file_results), Some(path)) = (&file_results, &path) {
+              file_results.add_problems(
+                path,
+                diagnostics.len(),
+                !fail_on_slow_types,
+              );
+            }
             let mut reporter = reporter_lock.lock();
             for diagnostic in &diagnostics {
               reporter
@@ -216,44 +697,55 @@ async fn lint_files(
   }
 
   futures.push({
-    let has_error = has_error.clone();
-    let linter = create_linter(lint_rules.rules);
-    let reporter_lock = reporter_lock.clone();
-    let incremental_cache = incremental_cache.clone();
-    let fix = lint_options.fix;
+    let fix_options = lint_options.fix.enabled.then(|| {
+      Arc::new(FileFixOptions {
+        fail_on_nonconvergence: lint_options.fix.fail_on_nonconvergence,
+        only_rules: lint_options.fix.only.clone(),
+        dry_run: lint_options.fix.dry_run,
+        max_iterations: lint_options.fix.max_iterations,
+        safe_only: lint_options.fix.safe_only,
+        today,
+      })
+    });
+    // the results of a single rule aren't worth caching, and the ones of a
+    // dry run are of the fixed text rather than the one of the file
+    let use_cache =
+      lint_options.only_rule.is_none() && !lint_options.fix.dry_run;
+    // the diagnostics of a file with an unchanged text are replayed from the
+    // cache, except when something needs the file to be parsed
+    let replay_diagnostics = use_cache
+      && !lint_options.fix.enabled
+      && !lint_options.reporter.enclosing_symbol
+      && lint_rules.banned_imports.is_empty();
+    let generated_rules = generated::generated_file_rules(&lint_rules.rules);
+    let file_linter = Arc::new(FileLinter {
+      lint_options: lint_options.clone(),
+      generated_linter: create_linter(generated_rules.clone()),
+      generated_rules,
+      linter: create_linter(lint_rules.rules.clone()),
+      rules: lint_rules.rules,
+      banned_imports: lint_rules.banned_imports,
+      fix_options,
+      use_cache,
+      replay_diagnostics,
+      today,
+      hidden_rules: get_hidden_rule_codes(&lint_options.filter.hide_tags),
+      baseline: baseline.clone(),
+      file_display: FileDisplay::new(&lint_options),
+      reporter_lock: reporter_lock.clone(),
+      incremental_cache: incremental_cache.clone(),
+      has_error: has_error.clone(),
+      has_file_error: has_file_error.clone(),
+      regressed_paths: regressed_paths.clone(),
+      problem_count: problem_count.clone(),
+      source_stats: source_stats.clone(),
+      file_results: file_results.clone(),
+      timings: timings.clone(),
+    });
+    let concurrency = lint_options.concurrency;
     deno_core::unsync::spawn(async move {
-      run_parallelized(paths, {
-        move |file_path| {
-          let file_text = fs::read_to_string(&file_path)?;
-
-          // don't bother rechecking this file if it didn't have any diagnostics before
-          if incremental_cache.is_file_same(&file_path, &file_text) {
-            return Ok(());
-          }
-
-          let r = lint_file(&linter, &file_path, file_text, fix);
-          if let Ok((file_source, file_diagnostics)) = &r {
-            if file_diagnostics.is_empty() {
-              // update the incremental cache if there were no diagnostics
-              incremental_cache.update_file(
-                &file_path,
-                // ensure the returned text is used here as it may have been modified via --fix
-                file_source.text_info().text_str(),
-              )
-            }
-          }
-
-          let success = handle_lint_result(
-            &file_path.to_string_lossy(),
-            r,
-            reporter_lock.clone(),
-          );
-          if !success {
-            has_error.raise();
-          }
-
-          Ok(())
-        }
+      run_parallelized_bounded(paths, concurrency, move |file_path| {
+        file_linter.lint_path(&file_path)
       })
       .await
     })
@@ -261,18 +753,628 @@ async fn lint_files(
 
   deno_core::futures::future::try_join_all(futures).await?;
 
+  let problem_count = problem_count.load(Ordering::Relaxed);
+  let exceeded_delta = match lint_options.max_warnings_delta {
+    Some(max_delta) => match incremental_cache.previous_problem_count() {
+      _ if lint_options.update_problem_count => {
+        incremental_cache.update_problem_count(problem_count);
+        None
+      }
+      Some(previous_count) if problem_count > previous_count + max_delta => {
+        Some(previous_count)
+      }
+      Some(previous_count) => {
+        // only lowered, so that the problems added within the delta don't
+        // raise the baseline run after run
+        if problem_count < previous_count {
+          incremental_cache.update_problem_count(problem_count);
+        }
+        None
+      }
+      // the first run with this configuration sets the baseline
+      None => {
+        incremental_cache.update_problem_count(problem_count);
+        None
+      }
+    },
+    None => None,
+  };
+
   incremental_cache.wait_completion().await;
   reporter_lock.lock().close(target_files_len);
+  if lint_options.reporter.verbose {
+    source_stats.print(start.elapsed());
+  }
+  if let Some(timings) = &timings {
+    timings.print();
+  }
+  if let Some(baseline) = &baseline {
+    write_baseline(baseline, &lint_options)?;
+  }
+  if let (Some(file_results), Some(path)) =
+    (&file_results, &lint_options.reporter.file_results)
+  {
+    file_results.write(path)?;
+  }
 
+  if let Some(previous_count) = exceeded_delta {
+    bail!(
+      "Found {} problems, {} more than the {} recorded by a previous run.",
+      problem_count,
+      problem_count - previous_count,
+      previous_count
+    );
+  }
+
+  let mut regressed_paths = std::mem::take(&mut *regressed_paths.lock());
+  if !regressed_paths.is_empty() {
+    regressed_paths.sort();
+    bail!(
+      "Found problems in {} previously clean file{}:\n{}",
+      regressed_paths.len(),
+      if regressed_paths.len() == 1 { "" } else { "s" },
+      regressed_paths
+        .iter()
+        .map(|path| format!("  {}", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n")
+    );
+  }
+
+  if lint_options.compare.is_some() || !lint_options.budgets.is_empty() {
+    // only new problems or the ones of over budget rules fail the run, along
+    // with the files whose problems are unknown
+    return Ok(!ratchet_failed.is_raised() && !has_file_error.is_raised());
+  }
+  if let Some(max_warnings) = lint_options.max_warnings {
+    return Ok(problem_count <= max_warnings && !has_file_error.is_raised());
+  }
   Ok(!has_error.is_raised())
 }
 
-fn collect_lint_files(files: FilePatterns) -> Result<Vec<PathBuf>, AnyError> {
-  FileCollector::new(|e| is_script_ext(e.path))
-    .ignore_git_folder()
-    .ignore_node_modules()
-    .ignore_vendor_folder()
-    .collect_file_patterns(files)
+/// Lints the files of `lint_files`, shared by the tasks of all files.
+struct FileLinter {
+  lint_options: Arc<LintOptions>,
+  rules: Vec<&'static dyn LintRule>,
+  linter: Linter,
+  /// The rules of the generated files, which are linted with fewer rules.
+  generated_rules: Vec<&'static dyn LintRule>,
+  generated_linter: Linter,
+  banned_imports: Vec<String>,
+  fix_options: Option<Arc<FileFixOptions>>,
+  use_cache: bool,
+  replay_diagnostics: bool,
+  /// The same date for all files, for the deno-lint-ignore-until directives.
+  today: NaiveDate,
+  hidden_rules: HashSet<&'static str>,
+  baseline: Option<Arc<LintBaseline>>,
+  file_display: FileDisplay,
+  reporter_lock: Arc<Mutex<Box<dyn LintReporter + Send>>>,
+  incremental_cache: Arc<IncrementalCache>,
+  has_error: Arc<AtomicFlag>,
+  has_file_error: Arc<AtomicFlag>,
+  regressed_paths: Arc<Mutex<Vec<PathBuf>>>,
+  problem_count: Arc<AtomicUsize>,
+  source_stats: Arc<LintedSourceStats>,
+  file_results: Option<Arc<FileResults>>,
+  timings: Option<Arc<LintTimings>>,
+}
+
+impl FileLinter {
+  fn lint_path(&self, file_path: &Path) -> Result<(), AnyError> {
+    let generated = self
+      .lint_options
+      .filter
+      .generated
+      .as_ref()
+      .filter(|generated| generated.files.matches_path(file_path));
+    if generated.is_some_and(|generated| generated.skip) {
+      return Ok(());
+    }
+    let (rules, linter) = match generated {
+      Some(_) => (&self.generated_rules, &self.generated_linter),
+      None => (&self.rules, &self.linter),
+    };
+
+    let file_text = match fs::read_to_string(file_path) {
+      Ok(file_text) => file_text,
+      Err(err) if self.lint_options.skip_unreadable => {
+        log::warn!(
+          "{} Skipped '{}' because it couldn't be read: {}",
+          colors::yellow("Warning"),
+          file_path.display(),
+          err
+        );
+        return Ok(());
+      }
+      Err(err) => return self.report_file(file_path, Err(err.into()), vec![]),
+    };
+
+    // a file that's in the cache had no diagnostics in a previous run, so
+    // it's linted again to find out if that's still the case
+    let was_clean = self.lint_options.fail_on_regressions
+      && self.incremental_cache.was_file_cached(file_path);
+    // don't bother rechecking this file if it didn't have any diagnostics before
+    if self.use_cache
+      && !was_clean
+      && self.incremental_cache.is_file_same(file_path, &file_text)
+    {
+      return self.report_file(file_path, Ok((None, vec![])), vec![]);
+    }
+    let cached_output = if self.replay_diagnostics && !was_clean {
+      self
+        .incremental_cache
+        .previous_output(file_path, &file_text)
+    } else {
+      None
+    };
+
+    // after the cache check, which is by the text of the file
+    let normalizes_eol =
+      self.lint_options.normalize_eol && file_text.contains('\r');
+    let file_text = if normalizes_eol {
+      self.normalize_eol(file_path, file_text)?
+    } else {
+      file_text
+    };
+    if self.lint_options.reporter.verbose {
+      self.source_stats.add(&file_text);
+    }
+
+    let cached_diagnostics = cached_output.and_then(|output| {
+      self.replay_cached_diagnostics(file_path, &file_text, output)
+    });
+    match cached_diagnostics {
+      Some(file_diagnostics) => {
+        self.report_file(file_path, Ok((None, file_diagnostics)), vec![])
+      }
+      None => self.lint_text(
+        file_path,
+        file_text,
+        rules,
+        linter,
+        was_clean,
+        normalizes_eol,
+      ),
+    }
+  }
+
+  /// Converts the line endings of the text to LF, which is written to the
+  /// file with `--fix`, even when there's nothing else to fix.
+  fn normalize_eol(
+    &self,
+    file_path: &Path,
+    file_text: String,
+  ) -> Result<String, AnyError> {
+    let file_text = normalize_line_endings(file_text);
+    if self.lint_options.fix.dry_run {
+      info!(
+        "Would normalize the line endings of '{}'.",
+        file_path.display()
+      );
+    } else if self.lint_options.fix.enabled {
+      fs::write(file_path, &file_text)
+        .context("Failed writing fix to file.")?;
+    }
+    Ok(file_text)
+  }
+
+  /// Gets the diagnostics of a file with an unchanged text from the output
+  /// of the previous run. A file that may have deno-lint-ignore-until
+  /// directives is linted again, as they're read from the parsed file.
+  fn replay_cached_diagnostics(
+    &self,
+    file_path: &Path,
+    file_text: &str,
+    output: &str,
+  ) -> Option<Vec<LintDiagnostic>> {
+    if may_have_ignore_until_directives(file_text) {
+      return None;
+    }
+    let mut file_diagnostics =
+      diagnostics_cache::deserialize_diagnostics(output, file_path, file_text)?;
+    append_rule_messages(
+      &mut file_diagnostics,
+      &self.lint_options.rule_messages,
+    );
+    Some(file_diagnostics)
+  }
+
+  /// Lints the text of the file, applying its fixes, and caches the result
+  /// before reporting it.
+  fn lint_text(
+    &self,
+    file_path: &Path,
+    file_text: String,
+    rules: &[&'static dyn LintRule],
+    linter: &Linter,
+    was_clean: bool,
+    normalizes_eol: bool,
+  ) -> Result<(), AnyError> {
+    let lint_start = Instant::now();
+    let r = self.run_linter(file_path, file_text, rules, linter);
+    if let (Some(timings), Some(Ok(fixed_source))) = (&self.timings, &r) {
+      timings.add_file(file_path, lint_start.elapsed());
+      if let Some(source) = &fixed_source.source {
+        timings.time_rules(rules, source);
+      }
+    }
+    let Some(r) = r else {
+      // reported like a file that couldn't be linted, so that the machine
+      // readable reporters tell which file was skipped
+      let err = generic_error("Linting timed out and the file was skipped.");
+      return self.report_file(file_path, Err(err), vec![]);
+    };
+    let mut diagnostics_output = None;
+    let r = r.map(|fixed_source| {
+      let text_changed = fixed_source.is_text_changed() || normalizes_eol;
+      let FixedSource {
+        text_info,
+        source: file_source,
+        diagnostics: mut file_diagnostics,
+        fixed_count,
+        unconverged_codes,
+        ..
+      } = fixed_source;
+      if !unconverged_codes.is_empty() {
+        // the partially fixed file is kept and its problems are still
+        // reported, while the run fails regardless of --max-warnings
+        log::error!(
+          "{}: Reached maximum number of fix iterations for '{}' with fixable problems left: {}",
+          colors::red_bold("error"),
+          file_path.display(),
+          unconverged_codes.join(", "),
+        );
+        self.has_error.raise();
+        self.has_file_error.raise();
+      }
+      if self.lint_options.fix.dry_run {
+        if text_changed {
+          // the file would change, which fails a dry run
+          self.has_error.raise();
+          self.has_file_error.raise();
+        }
+      } else if fixed_count > 0 {
+        self.reporter_lock.lock().visit_fixes(fixed_count);
+      }
+      if self.replay_diagnostics && !file_diagnostics.is_empty() {
+        // before the rule messages, which aren't part of the cache state
+        diagnostics_output =
+          Some(diagnostics_cache::serialize_diagnostics(&file_diagnostics));
+      }
+      append_rule_messages(
+        &mut file_diagnostics,
+        &self.lint_options.rule_messages,
+      );
+      (text_info, file_source, file_diagnostics)
+    });
+    let banned_import_diagnostics = match &r {
+      Ok((_, Some(file_source), _)) => collect_reported_banned_imports(
+        file_source,
+        &self.banned_imports,
+        &self.hidden_rules,
+      ),
+      Ok((_, None, _)) | Err(_) => Vec::new(),
+    };
+    // before the ignore-until directives apply, so that a file with ignored
+    // problems isn't cached as passing and is linted again once its
+    // directives expire
+    let is_clean = banned_import_diagnostics.is_empty()
+      && matches!(&r, Ok((_, _, file_diagnostics)) if file_diagnostics.is_empty());
+    // an invalid directive fails the file even when it has no problems, so
+    // the file isn't cached either
+    let r = r.and_then(|(text_info, file_source, mut file_diagnostics)| {
+      if let Some(file_source) = &file_source {
+        apply_ignore_until_directives(
+          file_source,
+          &mut file_diagnostics,
+          self.today,
+        )?;
+      }
+      Ok((text_info, file_source, file_diagnostics))
+    });
+    if let Ok((text_info, _, _)) = &r {
+      if was_clean && !is_clean {
+        self.regressed_paths.lock().push(file_path.to_path_buf());
+      }
+      if self.use_cache && (is_clean || diagnostics_output.is_some()) {
+        // the returned text may have been modified via --fix
+        self.update_cache(file_path, text_info.text_str(), diagnostics_output);
+      }
+    }
+    let r = r.map(|(_, file_source, file_diagnostics)| {
+      (file_source, file_diagnostics)
+    });
+    self.report_file(file_path, r, banned_import_diagnostics)
+  }
+
+  /// Lints the text with the inline config of the file, on the blocking
+  /// thread pool with `--timeout-per-file`. Returns `None` when it timed out.
+  fn run_linter(
+    &self,
+    file_path: &Path,
+    file_text: String,
+    rules: &[&'static dyn LintRule],
+    linter: &Linter,
+  ) -> Option<Result<FixedSource, AnyError>> {
+    let linter = match InlineLintConfig::parse(&file_text) {
+      Ok(Some(inline_config)) => {
+        Cow::Owned(create_linter(inline_config.apply(rules)))
+      }
+      Ok(None) => Cow::Borrowed(linter),
+      Err(err) => return Some(Err(err)),
+    };
+    let jsx_in_js = self.lint_options.jsx_in_js;
+    match self.lint_options.timeout_per_file {
+      Some(timeout) => lint_file_with_timeout(
+        linter.into_owned(),
+        file_path.to_path_buf(),
+        file_text,
+        self.fix_options.clone(),
+        jsx_in_js,
+        timeout,
+      ),
+      None => Some(lint_file(
+        &linter,
+        file_path,
+        file_text,
+        self.fix_options.as_deref(),
+        jsx_in_js,
+      )),
+    }
+  }
+
+  /// Updates the incremental cache with the text of the file rather than
+  /// the normalized one. A file that changed while it was being linted isn't
+  /// cached so that its new content is linted on the next run.
+  fn update_cache(
+    &self,
+    file_path: &Path,
+    linted_text: &str,
+    diagnostics_output: Option<String>,
+  ) {
+    let normalize_eol = self.lint_options.normalize_eol;
+    let Some(file_text) =
+      read_unchanged_file_text(file_path, linted_text, normalize_eol)
+    else {
+      if self.lint_options.warn_changed_files {
+        log::warn!(
+          "{} '{}' changed while it was being linted and will be linted again on the next run.",
+          colors::yellow("Warning"),
+          file_path.display(),
+        );
+      }
+      return;
+    };
+    match diagnostics_output {
+      Some(output) => self
+        .incremental_cache
+        .update_output(file_path, &file_text, output),
+      None => self.incremental_cache.update_file(file_path, &file_text),
+    }
+  }
+
+  /// Reports the problems of a file, or the error that it couldn't be
+  /// linted, and records its result. The problems of a linted file and the
+  /// ones replayed from the cache are filtered the same way here.
+  fn report_file(
+    &self,
+    file_path: &Path,
+    r: Result<(Option<ParsedSource>, Vec<LintDiagnostic>), AnyError>,
+    mut banned_import_diagnostics: Vec<BannedImportDiagnostic>,
+  ) -> Result<(), AnyError> {
+    let r = r.map(|(file_source, mut file_diagnostics)| {
+      filter_reported_diagnostics(
+        file_path,
+        &mut file_diagnostics,
+        LintOrCliDiagnostic::Lint,
+        &self.lint_options,
+        &self.hidden_rules,
+        self.baseline.as_deref(),
+      );
+      // the banned imports aren't recorded in the baseline
+      filter_reported_diagnostics(
+        file_path,
+        &mut banned_import_diagnostics,
+        LintOrCliDiagnostic::BannedImport,
+        &self.lint_options,
+        &self.hidden_rules,
+        None,
+      );
+      (file_source, file_diagnostics)
+    });
+    let mut file_problem_count = 0;
+    match &r {
+      Ok((_, file_diagnostics)) => {
+        file_problem_count =
+          file_diagnostics.len() + banned_import_diagnostics.len();
+        self
+          .problem_count
+          .fetch_add(file_problem_count, Ordering::Relaxed);
+        if self.lint_options.reporter.sidecar {
+          let sidecar_diagnostics = file_diagnostics
+            .iter()
+            .map(LintOrCliDiagnostic::Lint)
+            .chain(
+              banned_import_diagnostics
+                .iter()
+                .map(LintOrCliDiagnostic::BannedImport),
+            )
+            .map(|d| JsonLintDiagnostic::new(d, &self.file_display))
+            .collect::<Vec<_>>();
+          write_sidecar_file(
+            file_path,
+            sidecar_diagnostics,
+            self.lint_options.reporter.json_sort,
+            &self.lint_options.warning_rules,
+          )?;
+        }
+      }
+      // the file wasn't checked, so the run can't pass
+      Err(_) => self.has_file_error.raise(),
+    }
+    let success = handle_lint_result(
+      &file_path.to_string_lossy(),
+      r,
+      self.reporter_lock.clone(),
+      &self.lint_options.warning_rules,
+      self.lint_options.reporter.enclosing_symbol,
+      self.lint_options.reporter.one_per_file,
+    );
+    let passes_banned_imports = report_banned_imports(
+      &mut **self.reporter_lock.lock(),
+      &banned_import_diagnostics,
+      &self.lint_options.warning_rules,
+    );
+    let success = success && passes_banned_imports;
+    if !success {
+      self.has_error.raise();
+    }
+    if let Some(file_results) = &self.file_results {
+      file_results.add(file_path, file_problem_count, success);
+    }
+    Ok(())
+  }
+}
+
+/// Size of the source code that was linted, as a throughput metric. Files
+/// that are skipped because they're cached aren't counted.
+#[derive(Default)]
+struct LintedSourceStats {
+  files: AtomicUsize,
+  bytes: AtomicUsize,
+  lines: AtomicUsize,
+}
+
+impl LintedSourceStats {
+  fn add(&self, file_text: &str) {
+    self.files.fetch_add(1, Ordering::Relaxed);
+    self.bytes.fetch_add(file_text.len(), Ordering::Relaxed);
+    self
+      .lines
+      .fetch_add(file_text.lines().count(), Ordering::Relaxed);
+  }
+
+  fn print(&self, elapsed: Duration) {
+    fn plural(count: usize, noun: &str) -> String {
+      match count {
+        1 => format!("1 {noun}"),
+        n => format!("{n} {noun}s"),
+      }
+    }
+
+    eprintln!(
+      "Linted {} ({}) across {} in {}",
+      plural(self.lines.load(Ordering::Relaxed), "line"),
+      human_size(self.bytes.load(Ordering::Relaxed) as f64),
+      plural(self.files.load(Ordering::Relaxed), "file"),
+      human_elapsed(elapsed.as_millis())
+    );
+  }
+}
+
+/// Removes the paths that point to an already included file, such as when
+/// overlapping globs or explicit paths are provided, keeping the order of the
+/// remaining paths. The removed duplicates are returned separately.
+fn dedup_lint_paths(paths: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<PathBuf>) {
+  let mut seen = HashSet::with_capacity(paths.len());
+  paths.into_iter().partition(|path| {
+    let canonicalized =
+      canonicalize_path(path).unwrap_or_else(|_| path.clone());
+    seen.insert(canonicalized)
+  })
+}
+
+/// The collector of the files to lint, which `--print-file-patterns` also
+/// describes.
+fn lint_file_collector(
+  lint_options: &LintOptions,
+) -> FileCollector<impl Fn(WalkEntry) -> bool> {
+  let test_files = lint_options.filter.test_files;
+  let unstable_md = lint_options.unstable_md;
+  FileCollector::new(move |e| {
+    (is_script_ext(e.path) || unstable_md && is_markdown_path(e.path))
+      && matches_test_files(e.path, test_files)
+  })
+  .ignore_git_folder()
+  .ignore_node_modules()
+  .ignore_vendor_folder()
+  .set_ignored_paths(lint_options.filter.third_party.clone())
+  .set_max_depth(lint_options.filter.max_depth)
+}
+
+fn collect_lint_files(
+  lint_options: &LintOptions,
+) -> Result<Vec<PathBuf>, AnyError> {
+  lint_file_collector(lint_options)
+    .collect_file_patterns(lint_options.files.clone())
+    .map(|mut files| {
+      // only the files of the patch that the patterns include are linted
+      if let Some(changed_lines) = &lint_options.filter.changed_lines {
+        files.retain(|path| changed_lines.contains_key(path));
+      }
+      files
+    })
+}
+
+/// Reads the paths of the files to lint from stdin with `--stdin-file-list`,
+/// one per line and relative to the current directory, which skips walking
+/// the tree when the files are already known. The paths that aren't of a
+/// script file are skipped with a warning.
+fn read_stdin_file_list(cwd: &Path) -> Result<Vec<PathBuf>, AnyError> {
+  let mut text = String::new();
+  if stdin().read_to_string(&mut text).is_err() {
+    return Err(generic_error("Failed to read from stdin"));
+  }
+  let mut paths = Vec::new();
+  for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+    let path = canonicalize_path(&cwd.join(line))
+      .with_context(|| format!("Failed resolving '{}'.", line))?;
+    if is_script_ext(&path) {
+      paths.push(path);
+    } else {
+      log::warn!(
+        "{} Skipped '{}' because it isn't a script file.",
+        colors::yellow("Warning"),
+        line
+      );
+    }
+  }
+  Ok(paths)
+}
+
+/// Checks if the path is linted with the `--no-tests` and `--tests-only`
+/// setting.
+fn matches_test_files(path: &Path, test_files: LintTestFiles) -> bool {
+  match test_files {
+    LintTestFiles::Include => true,
+    LintTestFiles::Exclude => !is_test_file_path(path),
+    LintTestFiles::Only => is_test_file_path(path),
+  }
+}
+
+/// Checks if the path follows a conventional test file naming, which is a
+/// file stem of `test` or one ending with `_test`, `.test` or `.spec`.
+fn is_test_file_path(path: &Path) -> bool {
+  path
+    .file_stem()
+    .map(|stem| {
+      let stem = stem.to_string_lossy();
+      stem == "test"
+        || stem.ends_with("_test")
+        || stem.ends_with(".test")
+        || stem.ends_with(".spec")
+    })
+    .unwrap_or(false)
+}
+
+/// The JSON Schema of the output of the JSON reporter, which has to be
+/// updated along with the structs it's serialized from.
+const JSON_OUTPUT_SCHEMA: &str =
+  include_str!("../../schemas/lint-output.v1.json");
+
+pub fn print_json_schema() {
+  print!("{}", JSON_OUTPUT_SCHEMA);
 }
 
 pub fn print_rules_list(json: bool, maybe_rules_tags: Option<Vec<String>>) {
@@ -326,41 +1428,257 @@ pub fn create_linter(rules: Vec<&'static dyn LintRule>) -> Linter {
     .build()
 }
 
+/// How `--fix` applies the fixes of a file.
+pub struct FileFixOptions {
+  /// Errors when fixing doesn't converge rather than only warning.
+  pub fail_on_nonconvergence: bool,
+  /// The rules whose fixes are applied, or every rule when it's empty.
+  pub only_rules: HashSet<String>,
+  /// Prints the changes as a diff rather than writing them to the file.
+  pub dry_run: bool,
+  /// Number of times the fixes are applied again after the first pass, or
+  /// `DEFAULT_MAX_FIX_ITERATIONS` when it's not set.
+  pub max_iterations: Option<usize>,
+  /// Only applies the fixes of `SAFE_FIX_RULES`.
+  pub safe_only: bool,
+  /// The date of the run, as the fixes of the problems ignored by the
+  /// deno-lint-ignore-until directives that didn't expire aren't applied.
+  pub today: NaiveDate,
+}
+
+impl FileFixOptions {
+  /// Whether the fixes of the diagnostic are applied.
+  fn allows_fix(&self, d: &LintDiagnostic) -> bool {
+    is_fix_allowed(d, &self.only_rules)
+      && (!self.safe_only || SAFE_FIX_RULES.contains(&d.code.as_str()))
+  }
+}
+
+/// The rules whose fixes keep the runtime behavior of the code, which are
+/// the only ones applied with `--auto-fix-safe-then-report`.
+const SAFE_FIX_RULES: [&str; 4] = [
+  "no-window",
+  "no-window-prefix",
+  "prefer-const",
+  "verbatim-module-syntax",
+];
+
+/// Number of times the fixes of a file are applied again after the first
+/// pass by default, which is enough unless fixes undo each other.
+const DEFAULT_MAX_FIX_ITERATIONS: usize = 5;
+
+/// Lints source code that's in memory, like the text of an editor buffer or
+/// of another tool embedding the linter, without reading or writing files.
+/// `fix_lint_source` also applies the fixes of the problems.
+pub fn lint_source(
+  linter: &Linter,
+  specifier: &ModuleSpecifier,
+  media_type: MediaType,
+  source_code: String,
+) -> Result<(ParsedSource, Vec<LintDiagnostic>), AnyError> {
+  linter
+    .lint_file(LintFileOptions {
+      specifier: specifier.clone(),
+      media_type,
+      source_code,
+    })
+    .map_err(AnyError::from)
+}
+
 fn lint_file(
   linter: &Linter,
   file_path: &Path,
   source_code: String,
-  fix: bool,
-) -> Result<(ParsedSource, Vec<LintDiagnostic>), AnyError> {
-  let specifier = specifier_from_file_path(file_path)?;
-  let media_type = MediaType::from_specifier(&specifier);
+  fix_options: Option<&FileFixOptions>,
+  jsx_in_js: bool,
+) -> Result<FixedSource, AnyError> {
+  let fixed_source = lint_file_in_memory(
+    linter,
+    file_path,
+    source_code,
+    fix_options,
+    jsx_in_js,
+  )?;
+  write_lint_fixes(file_path, fixed_source, fix_options)
+}
+
+/// Source code that's in memory with the fixes of its problems applied by
+/// `fix_lint_source`.
+pub struct FixedLintSource {
+  /// The parsed module of the fixed text, which the diagnostics point into.
+  pub source: ParsedSource,
+  /// The problems left once the fixes were applied.
+  pub diagnostics: Vec<LintDiagnostic>,
+  pub fixed_count: usize,
+  /// The text before the fixes were applied.
+  pub original_text: String,
+  /// The rules with fixable problems left when the maximum number of fix
+  /// iterations was reached, which happens when fixes undo each other.
+  pub unconverged_codes: Vec<String>,
+}
 
-  if fix {
-    lint_file_and_fix(linter, &specifier, media_type, source_code, file_path)
+/// The result of linting a file with its fixes applied in memory only.
+struct FixedSource {
+  /// The fixed text of the file, which the diagnostics point into.
+  text_info: SourceTextInfo,
+  /// The parsed module of the fixed text. Markdown files have none, as only
+  /// their code blocks are linted.
+  source: Option<ParsedSource>,
+  diagnostics: Vec<LintDiagnostic>,
+  fixed_count: usize,
+  /// The text before the fixes were applied, which is only kept when fixing.
+  original_text: Option<String>,
+  /// The rules with fixable problems left when the maximum number of fix
+  /// iterations was reached. Once the fixes are written, it's only kept when
+  /// that fails the file.
+  unconverged_codes: Vec<String>,
+}
+
+impl From<FixedLintSource> for FixedSource {
+  fn from(fixed_source: FixedLintSource) -> Self {
+    Self {
+      text_info: fixed_source.source.text_info().clone(),
+      source: Some(fixed_source.source),
+      diagnostics: fixed_source.diagnostics,
+      fixed_count: fixed_source.fixed_count,
+      original_text: Some(fixed_source.original_text),
+      unconverged_codes: fixed_source.unconverged_codes,
+    }
+  }
+}
+
+impl FixedSource {
+  /// Whether applying the fixes changed the text of the file.
+  fn is_text_changed(&self) -> bool {
+    self
+      .original_text
+      .as_deref()
+      .is_some_and(|text| text != self.text_info.text_str())
+  }
+}
+
+/// Lints the file and applies its fixes to the text, without writing
+/// anything, so it's safe to run on a thread that may be abandoned.
+fn lint_file_in_memory(
+  linter: &Linter,
+  file_path: &Path,
+  source_code: String,
+  fix_options: Option<&FileFixOptions>,
+  jsx_in_js: bool,
+) -> Result<FixedSource, AnyError> {
+  let specifier = specifier_from_file_path(file_path)?;
+  let (text_info, source, diagnostics) = if is_markdown_path(file_path) {
+    // the fixes of the code blocks aren't applied
+    let (text_info, diagnostics) =
+      lint_markdown_file(linter, &specifier, source_code, jsx_in_js);
+    (text_info, None, diagnostics)
   } else {
-    linter
-      .lint_file(LintFileOptions {
-        specifier,
-        media_type,
-        source_code,
-      })
-      .map_err(AnyError::from)
+    let media_type = lint_media_type(&specifier, jsx_in_js);
+    match fix_options {
+      Some(fix_options) => {
+        return fix_lint_source(
+          linter,
+          &specifier,
+          media_type,
+          source_code,
+          fix_options,
+        )
+        .map(FixedSource::from);
+      }
+      None => {
+        let (source, diagnostics) =
+          lint_source(linter, &specifier, media_type, source_code)?;
+        (source.text_info().clone(), Some(source), diagnostics)
+      }
+    }
+  };
+  Ok(FixedSource {
+    text_info,
+    source,
+    diagnostics,
+    fixed_count: 0,
+    original_text: None,
+    unconverged_codes: Vec::new(),
+  })
+}
+
+fn lint_media_type(specifier: &ModuleSpecifier, jsx_in_js: bool) -> MediaType {
+  match MediaType::from_specifier(specifier) {
+    MediaType::JavaScript | MediaType::Mjs if jsx_in_js => MediaType::Jsx,
+    media_type => media_type,
   }
 }
 
-fn lint_file_and_fix(
+/// Lints the file on the blocking thread pool so a rule that hangs on a
+/// pathological file doesn't block the whole run. Returns `None` when the
+/// timeout elapses.
+fn lint_file_with_timeout(
+  linter: Linter,
+  file_path: PathBuf,
+  source_code: String,
+  fix_options: Option<Arc<FileFixOptions>>,
+  jsx_in_js: bool,
+  timeout: Duration,
+) -> Option<Result<FixedSource, AnyError>> {
+  let (r, _task) =
+    lint_with_timeout(&file_path, fix_options.as_deref(), timeout, {
+      let file_path = file_path.clone();
+      let fix_options = fix_options.clone();
+      move || {
+        lint_file_in_memory(
+          &linter,
+          &file_path,
+          source_code,
+          fix_options.as_deref(),
+          jsx_in_js,
+        )
+      }
+    });
+  r
+}
+
+/// Runs `lint` on the blocking thread pool and waits at most `timeout` for
+/// it, returning its result along with its task. When the timeout elapses,
+/// the task is left to finish in the background. It only fixes the text in
+/// memory, and the fixes are written here once it finished in time, so an
+/// abandoned task never changes the file.
+fn lint_with_timeout(
+  file_path: &Path,
+  fix_options: Option<&FileFixOptions>,
+  timeout: Duration,
+  lint: impl FnOnce() -> Result<FixedSource, AnyError> + Send + 'static,
+) -> (Option<Result<FixedSource, AnyError>>, JoinHandle<()>) {
+  let (tx, rx) = std::sync::mpsc::channel();
+  let task = spawn_blocking(move || {
+    let _ = tx.send(lint());
+  });
+  let r = match rx.recv_timeout(timeout) {
+    Ok(r) => Some(r.and_then(|fixed_source| {
+      write_lint_fixes(file_path, fixed_source, fix_options)
+    })),
+    Err(RecvTimeoutError::Timeout) => None,
+    Err(RecvTimeoutError::Disconnected) => {
+      Some(Err(generic_error("Linting the file panicked.")))
+    }
+  };
+  (r, task)
+}
+
+/// Lints source code that's in memory and applies the fixes of its problems
+/// repeatedly, linting the fixed text again, without writing anything.
+/// `write_lint_fixes` writes the result for `--fix`, while the other options
+/// of `--fix` are up to the caller, like failing on `unconverged_codes`.
+pub fn fix_lint_source(
   linter: &Linter,
   specifier: &ModuleSpecifier,
   media_type: MediaType,
   source_code: String,
-  file_path: &Path,
-) -> Result<(ParsedSource, Vec<LintDiagnostic>), deno_core::anyhow::Error> {
+  options: &FileFixOptions,
+) -> Result<FixedLintSource, AnyError> {
   // initial lint
-  let (source, diagnostics) = linter.lint_file(LintFileOptions {
-    specifier: specifier.clone(),
-    media_type,
-    source_code,
-  })?;
+  let (source, diagnostics) =
+    lint_source(linter, specifier, media_type, source_code)?;
+  let original_text = source.text_info().text_str().to_string();
 
   // Try applying fixes repeatedly until the file has none left or
   // a maximum number of iterations is reached. This is necessary
@@ -368,72 +1686,200 @@ fn lint_file_and_fix(
   // them in one pass.
   let mut source = source;
   let mut diagnostics = diagnostics;
+  let max_iterations =
+    options.max_iterations.unwrap_or(DEFAULT_MAX_FIX_ITERATIONS);
   let mut fix_iterations = 0;
+  let mut fixed_count = 0;
+  let mut reached_max_iterations = false;
   loop {
+    let is_ignored = unexpired_ignores(&source, options.today)?;
     let change = apply_lint_fixes_and_relint(
       specifier,
       media_type,
       linter,
       source.text_info(),
       &diagnostics,
+      &|d| options.allows_fix(d) && !is_ignored(d),
     )?;
     match change {
       Some(change) => {
         source = change.0;
         diagnostics = change.1;
+        fixed_count += change.2;
       }
       None => {
         break;
       }
     }
     fix_iterations += 1;
-    if fix_iterations > 5 {
-      log::warn!(
+    if fix_iterations > max_iterations {
+      reached_max_iterations = true;
+      break;
+    }
+  }
+
+  // the maximum may be reached right when there's nothing left to fix
+  let is_ignored = unexpired_ignores(&source, options.today)?;
+  let mut unconverged_codes = diagnostics
+    .iter()
+    .filter(|d| {
+      reached_max_iterations
+        && !d.fixes.is_empty()
+        && options.allows_fix(d)
+        && !is_ignored(d)
+    })
+    .map(|d| d.code.clone())
+    .collect::<Vec<_>>();
+  unconverged_codes.sort_unstable();
+  unconverged_codes.dedup();
+  // a fix may reintroduce a problem that's also found elsewhere, which
+  // would otherwise be reported and counted twice
+  dedup_diagnostics(&mut diagnostics);
+  Ok(FixedLintSource {
+    source,
+    diagnostics,
+    fixed_count,
+    original_text,
+    unconverged_codes,
+  })
+}
+
+/// Writes the fixed text of the file, or prints its diff with `--dry-run`,
+/// and warns when fixing it didn't converge. This is the only place that
+/// writes the fixes of a file.
+fn write_lint_fixes(
+  file_path: &Path,
+  mut fixed_source: FixedSource,
+  fix_options: Option<&FileFixOptions>,
+) -> Result<FixedSource, AnyError> {
+  let Some(options) = fix_options else {
+    return Ok(fixed_source);
+  };
+  if fixed_source.is_text_changed() {
+    let original_text = fixed_source.original_text.as_deref().unwrap();
+    let fixed_text = fixed_source.text_info.text_str();
+    if options.dry_run {
+      print_fix_diff(file_path, original_text, fixed_text);
+    } else {
+      // everything looks good and the file still parses, so write it out
+      fs::write(file_path, fixed_text)
+        .context("Failed writing fix to file.")?;
+    }
+  }
+
+  let codes = &mut fixed_source.unconverged_codes;
+  if !codes.is_empty() && !options.fail_on_nonconvergence {
+    let specifier = specifier_from_file_path(file_path)?;
+    match options.max_iterations {
+      Some(max_iterations) => log::warn!(
+        concat!(
+          "Reached the maximum of {} fix iterations set by ",
+          "--fix-max-iterations for '{}' with fixable problems left: {}",
+        ),
+        max_iterations,
+        specifier,
+        codes.join(", "),
+      ),
+      None => log::warn!(
         concat!(
           "Reached maximum number of fix iterations for '{}'. There's ",
           "probably a bug in Deno. Please fix this file manually.",
         ),
         specifier,
-      );
-      break;
+      ),
     }
+    codes.clear();
   }
+  Ok(fixed_source)
+}
 
-  if fix_iterations > 0 {
-    // everything looks good and the file still parses, so write it out
-    fs::write(file_path, source.text_info().text_str())
-      .context("Failed writing fix to file.")?;
-  }
+/// Reads the file again after linting it, returning its text only when it's
+/// still the linted one rather than having been changed in the meantime.
+fn read_unchanged_file_text(
+  file_path: &Path,
+  linted_text: &str,
+  normalize_eol: bool,
+) -> Option<String> {
+  let text = fs::read_to_string(file_path).ok()?;
+  let is_unchanged = if normalize_eol {
+    normalize_line_endings(text.clone()) == linted_text
+  } else {
+    text == linted_text
+  };
+  is_unchanged.then_some(text)
+}
+
+/// Removes the diagnostics of the same rule at the same range of a file as
+/// an earlier one, keeping the order of the remaining ones.
+fn dedup_diagnostics(diagnostics: &mut Vec<LintDiagnostic>) {
+  let mut seen = HashSet::with_capacity(diagnostics.len());
+  diagnostics.retain(|d| {
+    let file_start = d.text_info.range().start;
+    seen.insert((
+      d.code.clone(),
+      d.specifier.clone(),
+      d.range.as_byte_range(file_start),
+    ))
+  });
+}
 
-  Ok((source, diagnostics))
+/// Prints the changes of the fixes of a file in `--dry-run` as a unified
+/// diff, in one write so the diffs of files fixed in parallel don't mix.
+fn print_fix_diff(file_path: &Path, original_text: &str, fixed_text: &str) {
+  // relative to the cwd, like the paths `git diff` prints, so the diff can
+  // be applied with `git apply`
+  let cwd = std::env::current_dir().ok();
+  let file_path = cwd
+    .as_deref()
+    .and_then(|cwd| file_path.strip_prefix(cwd).ok())
+    .unwrap_or(file_path);
+  let path = file_path.to_string_lossy().replace('\\', "/");
+  let path = path.trim_start_matches('/');
+  let diff = unified_diff(
+    original_text,
+    fixed_text,
+    &format!("a/{}", path),
+    &format!("b/{}", path),
+  );
+  let mut stdout = std::io::stdout().lock();
+  let _ = stdout.write_all(diff.as_bytes());
 }
 
+/// Applies the fixes of the diagnostics once and lints the fixed text,
+/// returning it along with the number of fixes that were applied.
 fn apply_lint_fixes_and_relint(
   specifier: &ModuleSpecifier,
   media_type: MediaType,
   linter: &Linter,
   text_info: &SourceTextInfo,
   diagnostics: &[LintDiagnostic],
-) -> Result<Option<(ParsedSource, Vec<LintDiagnostic>)>, AnyError> {
-  let Some(new_text) = apply_lint_fixes(text_info, diagnostics) else {
+  allows_fix: &dyn Fn(&LintDiagnostic) -> bool,
+) -> Result<Option<(ParsedSource, Vec<LintDiagnostic>, usize)>, AnyError> {
+  let Some((new_text, fixed_count)) =
+    apply_lint_fixes(text_info, diagnostics, allows_fix)
+  else {
     return Ok(None);
   };
-  linter
-    .lint_file(LintFileOptions {
-      specifier: specifier.clone(),
-      source_code: new_text,
-      media_type,
-    })
-    .map(Some)
+  lint_source(linter, specifier, media_type, new_text)
+    .map(|(source, diagnostics)| Some((source, diagnostics, fixed_count)))
     .context(
       "An applied lint fix caused a syntax error. Please report this bug.",
     )
 }
 
+/// Whether the fixes of the diagnostic are applied with `--fix-only`, which
+/// allows every rule when it's empty.
+fn is_fix_allowed(d: &LintDiagnostic, fix_only: &HashSet<String>) -> bool {
+  fix_only.is_empty() || fix_only.contains(&d.code)
+}
+
+/// Applies the first fix of each allowed diagnostic, returning the fixed
+/// text and the number of fixes that were applied.
 fn apply_lint_fixes(
   text_info: &SourceTextInfo,
   diagnostics: &[LintDiagnostic],
-) -> Option<String> {
+  allows_fix: &dyn Fn(&LintDiagnostic) -> bool,
+) -> Option<(String, usize)> {
   if diagnostics.is_empty() {
     return None;
   }
@@ -441,74 +1887,248 @@ fn apply_lint_fixes(
   let file_start = text_info.range().start;
   let mut quick_fixes = diagnostics
     .iter()
+    .filter(|d| allows_fix(d))
     // use the first quick fix
     .filter_map(|d| d.fixes.first())
-    .flat_map(|fix| fix.changes.iter())
-    .map(|change| deno_ast::TextChange {
-      range: change.range.as_byte_range(file_start),
-      new_text: change.new_text.to_string(),
+    .map(|fix| {
+      fix
+        .changes
+        .iter()
+        .map(|change| deno_ast::TextChange {
+          range: change.range.as_byte_range(file_start),
+          new_text: change.new_text.to_string(),
+        })
+        .collect::<Vec<_>>()
     })
+    .filter(|changes| !changes.is_empty())
     .collect::<Vec<_>>();
   if quick_fixes.is_empty() {
     return None;
   }
-  // remove any overlapping text changes, we'll circle
-  // back for another pass to fix the remaining
-  quick_fixes.sort_by_key(|change| change.range.start);
-  for i in (1..quick_fixes.len()).rev() {
-    let cur = &quick_fixes[i];
-    let previous = &quick_fixes[i - 1];
-    let is_overlapping = cur.range.start < previous.range.end;
-    if is_overlapping {
-      quick_fixes.remove(i);
+  // skip the fixes with a change overlapping one of an earlier fix, we'll
+  // circle back for another pass to fix the remaining
+  quick_fixes.sort_by_key(|changes| {
+    changes.iter().map(|change| change.range.start).min()
+  });
+  let mut text_changes: Vec<deno_ast::TextChange> = Vec::new();
+  let mut fixed_count = 0;
+  for changes in quick_fixes {
+    let is_overlapping = changes.iter().any(|change| {
+      // the changes are kept sorted, so only the first one that ends after
+      // the start of this one may overlap it
+      let index = text_changes
+        .partition_point(|other| other.range.end <= change.range.start);
+      text_changes
+        .get(index)
+        .is_some_and(|other| other.range.start < change.range.end)
+    });
+    if !is_overlapping {
+      for change in changes {
+        let index = text_changes
+          .partition_point(|other| other.range.start <= change.range.start);
+        text_changes.insert(index, change);
+      }
+      fixed_count += 1;
     }
   }
   let new_text =
-    deno_ast::apply_text_changes(text_info.text_str(), quick_fixes);
-  Some(new_text)
+    deno_ast::apply_text_changes(text_info.text_str(), text_changes);
+  Some((new_text, fixed_count))
 }
 
-/// Lint stdin and write result to stdout.
-/// Treats input as TypeScript.
+/// Lint stdin, or the source provided via `--eval`, and write result to
+/// stdout. The dialect is taken from the extension of the file path, which is
+/// TypeScript unless `--stdin-ext` is provided.
 /// Compatible with `--json` flag.
 fn lint_stdin(
   file_path: &Path,
+  maybe_source: Option<String>,
   lint_rules: Vec<&'static dyn LintRule>,
 ) -> Result<(ParsedSource, Vec<LintDiagnostic>), AnyError> {
-  let mut source_code = String::new();
-  if stdin().read_to_string(&mut source_code).is_err() {
-    return Err(generic_error("Failed to read from stdin"));
-  }
+  let source_code = match maybe_source {
+    Some(source_code) => source_code,
+    None => {
+      let mut source_code = String::new();
+      if stdin().read_to_string(&mut source_code).is_err() {
+        return Err(generic_error("Failed to read from stdin"));
+      }
+      source_code
+    }
+  };
 
+  let lint_rules = match InlineLintConfig::parse(&source_code)? {
+    Some(inline_config) => inline_config.apply(&lint_rules),
+    None => lint_rules,
+  };
   let linter = create_linter(lint_rules);
 
-  linter
-    .lint_file(LintFileOptions {
-      specifier: specifier_from_file_path(file_path)?,
-      source_code: source_code.clone(),
-      media_type: MediaType::TypeScript,
+  let specifier = specifier_from_file_path(file_path)?;
+  let media_type = MediaType::from_specifier(&specifier);
+  lint_source(&linter, &specifier, media_type, source_code)
+}
+
+/// Appends the extra text configured via `ruleMessages` to the hints of the
+/// diagnostics of those rules. This only changes what's reported, so it's
+/// not part of the incremental cache state.
+fn append_rule_messages(
+  diagnostics: &mut [LintDiagnostic],
+  rule_messages: &HashMap<String, String>,
+) {
+  if rule_messages.is_empty() {
+    return;
+  }
+  for d in diagnostics {
+    if let Some(extra) = rule_messages.get(&d.code) {
+      d.hint = Some(match d.hint.take() {
+        Some(hint) => format!("{} {}", hint, extra),
+        None => extra.clone(),
+      });
+    }
+  }
+}
+
+/// Gets the codes of the rules whose diagnostics are hidden via
+/// `--hide-tags`. The rules still run, only their diagnostics aren't reported.
+fn get_hidden_rule_codes(hide_tags: &[String]) -> HashSet<&'static str> {
+  if hide_tags.is_empty() {
+    return HashSet::new();
+  }
+  rules::get_all_rules()
+    .into_iter()
+    .filter(|rule| {
+      rule
+        .tags()
+        .iter()
+        .any(|tag| hide_tags.iter().any(|t| t == tag))
     })
-    .map_err(AnyError::from)
+    .map(|rule| rule.code())
+    .chain(
+      // it has no tags, so it's only hidden by its own code
+      hide_tags
+        .iter()
+        .any(|tag| tag == NO_BANNED_IMPORTS_NAME)
+        .then_some(NO_BANNED_IMPORTS_NAME),
+    )
+    .collect()
+}
+
+/// Collects the imports of the source that are banned, unless the problems
+/// of `no-banned-imports` are hidden via `--hide-tags`.
+fn collect_reported_banned_imports(
+  source: &ParsedSource,
+  banned_imports: &[String],
+  hidden_rules: &HashSet<&'static str>,
+) -> Vec<BannedImportDiagnostic> {
+  if banned_imports.is_empty() || hidden_rules.contains(NO_BANNED_IMPORTS_NAME)
+  {
+    return Vec::new();
+  }
+  banned_imports::collect_banned_import_diagnostics(source, banned_imports)
+}
+
+/// Reports the banned imports of a file, returning whether it passed, which
+/// is when there are none or `no-banned-imports` is a warning rule.
+fn report_banned_imports(
+  reporter: &mut dyn LintReporter,
+  diagnostics: &[BannedImportDiagnostic],
+  warning_rules: &HashSet<String>,
+) -> bool {
+  for diagnostic in diagnostics {
+    reporter.visit_diagnostic(LintOrCliDiagnostic::BannedImport(diagnostic));
+  }
+  diagnostics.is_empty() || warning_rules.contains(NO_BANNED_IMPORTS_NAME)
+}
+
+/// Gets if the diagnostic's lines overlap one of the changed line ranges of
+/// the `--patch` diff.
+fn is_in_changed_lines(
+  d: LintOrCliDiagnostic,
+  changed_lines: &[RangeInclusive<usize>],
+) -> bool {
+  let Some((text_info, range)) = d.range() else {
+    return true;
+  };
+  let start = text_info.line_and_column_display(range.start).line_number;
+  let end = text_info.line_and_column_display(range.end).line_number;
+  changed_lines
+    .iter()
+    .any(|lines| start <= *lines.end() && *lines.start() <= end)
+}
+
+/// Gets if the diagnostic's range overlaps one of the byte windows, where an
+/// empty range or window overlaps the ranges it's in or next to.
+fn is_in_byte_windows(
+  d: LintOrCliDiagnostic,
+  windows: &[Range<usize>],
+) -> bool {
+  let Some((text_info, range)) = d.range() else {
+    return true;
+  };
+  let file_start = text_info.range().start;
+  let start = range.start.as_byte_index(file_start);
+  let end = range.end.as_byte_index(file_start);
+  windows.iter().any(|window| {
+    if start == end || window.is_empty() {
+      start <= window.end && window.start <= end
+    } else {
+      start < window.end && window.start < end
+    }
+  })
+}
+
+/// Removes the problems of a file that aren't reported: the ones of the rules
+/// hidden via `--hide-tags`, the ones outside of its byte windows or changed
+/// lines, and the ones in the baseline, which records the rest when it's
+/// updated. The whole file is still linted for correctness.
+fn filter_reported_diagnostics<T>(
+  file_path: &Path,
+  diagnostics: &mut Vec<T>,
+  as_diagnostic: impl Fn(&T) -> LintOrCliDiagnostic<'_>,
+  lint_options: &LintOptions,
+  hidden_rules: &HashSet<&'static str>,
+  baseline: Option<&LintBaseline>,
+) {
+  diagnostics
+    .retain(|d| !hidden_rules.contains(as_diagnostic(d).code().as_ref()));
+  if let Some(windows) = lint_options.filter.byte_windows_of(file_path) {
+    diagnostics.retain(|d| is_in_byte_windows(as_diagnostic(d), windows));
+  }
+  if let Some(lines) = lint_options
+    .filter
+    .changed_lines
+    .as_ref()
+    .and_then(|changed_lines| changed_lines.get(file_path))
+  {
+    diagnostics.retain(|d| is_in_changed_lines(as_diagnostic(d), lines));
+  }
+  // after the other filters, so that an updated baseline only records the
+  // problems that would be reported
+  if let Some(baseline) = baseline {
+    baseline.suppress(diagnostics, as_diagnostic);
+  }
 }
 
+/// Reports the result of linting a file, returning whether it passed, which
+/// is when it has no problems other than the ones of warning rules. Markdown
+/// files have no parsed source, so no enclosing symbols are shown for them.
 fn handle_lint_result(
   file_path: &str,
-  result: Result<(ParsedSource, Vec<LintDiagnostic>), AnyError>,
+  result: Result<(Option<ParsedSource>, Vec<LintDiagnostic>), AnyError>,
   reporter_lock: Arc<Mutex<Box<dyn LintReporter + Send>>>,
+  warning_rules: &HashSet<String>,
+  enclosing_symbol: bool,
+  one_per_file: bool,
 ) -> bool {
   let mut reporter = reporter_lock.lock();
 
   match result {
-    Ok((_source, mut file_diagnostics)) => {
-      file_diagnostics.sort_by(|a, b| match a.specifier.cmp(&b.specifier) {
-        std::cmp::Ordering::Equal => a.range.start.cmp(&b.range.start),
-        file_order => file_order,
-      });
-      for d in &file_diagnostics {
-        reporter.visit_diagnostic(LintOrCliDiagnostic::Lint(d));
-      }
-      file_diagnostics.is_empty()
-    }
+    Ok((source, file_diagnostics)) => report_file_diagnostics(
+      &mut **reporter,
+      file_diagnostics,
+      warning_rules,
+      source.as_ref().filter(|_| enclosing_symbol),
+      one_per_file,
+    ),
     Err(err) => {
       reporter.visit_error(file_path, &err);
       false
@@ -516,10 +2136,85 @@ fn handle_lint_result(
   }
 }
 
+/// Loads the baseline of `--baseline`, or starts recording a new one with
+/// `--update-baseline`.
+fn create_baseline(
+  lint_options: &LintOptions,
+  cwd: &Path,
+) -> Result<Option<LintBaseline>, AnyError> {
+  let Some(path) = &lint_options.baseline else {
+    return Ok(None);
+  };
+  if lint_options.update_baseline {
+    Ok(Some(LintBaseline::for_update(path, cwd.to_path_buf())))
+  } else {
+    LintBaseline::load(path, cwd.to_path_buf()).map(Some)
+  }
+}
+
+fn write_baseline(
+  baseline: &LintBaseline,
+  lint_options: &LintOptions,
+) -> Result<(), AnyError> {
+  if let (Some(count), Some(path)) = (baseline.write()?, &lint_options.baseline)
+  {
+    info!(
+      "Wrote {} problem{} to the baseline '{}'.",
+      count,
+      if count == 1 { "" } else { "s" },
+      path.display()
+    );
+  }
+  Ok(())
+}
+
+/// Reports the diagnostics of a file in order, in their enclosing symbols
+/// when the parsed file is provided, returning whether the file passed.
+/// With `one_per_file`, only the first one is reported, after the number of
+/// the other ones.
+fn report_file_diagnostics(
+  reporter: &mut dyn LintReporter,
+  mut file_diagnostics: Vec<LintDiagnostic>,
+  warning_rules: &HashSet<String>,
+  maybe_source: Option<&ParsedSource>,
+  one_per_file: bool,
+) -> bool {
+  file_diagnostics.sort_by(|a, b| match a.specifier.cmp(&b.specifier) {
+    std::cmp::Ordering::Equal => a.range.start.cmp(&b.range.start),
+    file_order => file_order,
+  });
+  // the collapsed diagnostics still fail the file
+  let success = file_diagnostics
+    .iter()
+    .all(|d| warning_rules.contains(d.code.as_str()));
+  if one_per_file && file_diagnostics.len() > 1 {
+    reporter.visit_collapsed(file_diagnostics.len() - 1);
+    file_diagnostics.truncate(1);
+  }
+  match maybe_source {
+    Some(source) if !file_diagnostics.is_empty() => {
+      let symbols = EnclosingSymbols::collect(source);
+      for d in &file_diagnostics {
+        reporter.visit_diagnostic_in_symbol(
+          LintOrCliDiagnostic::Lint(d),
+          symbols.find(d.range),
+        );
+      }
+    }
+    _ => {
+      for d in &file_diagnostics {
+        reporter.visit_diagnostic(LintOrCliDiagnostic::Lint(d));
+      }
+    }
+  }
+  success
+}
+
 #[derive(Clone, Copy)]
 pub enum LintOrCliDiagnostic<'a> {
   Lint(&'a LintDiagnostic),
   FastCheck(&'a FastCheckDiagnostic),
+  BannedImport(&'a BannedImportDiagnostic),
 }
 
 impl<'a> LintOrCliDiagnostic<'a> {
@@ -527,6 +2222,7 @@ impl<'a> LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => &d.specifier,
       LintOrCliDiagnostic::FastCheck(d) => d.specifier(),
+      LintOrCliDiagnostic::BannedImport(d) => &d.specifier,
     }
   }
 
@@ -536,6 +2232,7 @@ impl<'a> LintOrCliDiagnostic<'a> {
       LintOrCliDiagnostic::FastCheck(d) => {
         d.range().map(|r| (&r.text_info, r.range))
       }
+      LintOrCliDiagnostic::BannedImport(d) => Some((&d.text_info, d.range)),
     }
   }
 }
@@ -545,6 +2242,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.level(),
       LintOrCliDiagnostic::FastCheck(d) => d.level(),
+      LintOrCliDiagnostic::BannedImport(d) => d.level(),
     }
   }
 
@@ -552,6 +2250,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.code(),
       LintOrCliDiagnostic::FastCheck(_) => Cow::Borrowed("no-slow-types"),
+      LintOrCliDiagnostic::BannedImport(d) => d.code(),
     }
   }
 
@@ -559,6 +2258,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.message(),
       LintOrCliDiagnostic::FastCheck(d) => d.message(),
+      LintOrCliDiagnostic::BannedImport(d) => d.message(),
     }
   }
 
@@ -566,6 +2266,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.location(),
       LintOrCliDiagnostic::FastCheck(d) => d.location(),
+      LintOrCliDiagnostic::BannedImport(d) => d.location(),
     }
   }
 
@@ -573,6 +2274,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.snippet(),
       LintOrCliDiagnostic::FastCheck(d) => d.snippet(),
+      LintOrCliDiagnostic::BannedImport(d) => d.snippet(),
     }
   }
 
@@ -580,6 +2282,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.hint(),
       LintOrCliDiagnostic::FastCheck(d) => d.hint(),
+      LintOrCliDiagnostic::BannedImport(d) => d.hint(),
     }
   }
 
@@ -589,6 +2292,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.snippet_fixed(),
       LintOrCliDiagnostic::FastCheck(d) => d.snippet_fixed(),
+      LintOrCliDiagnostic::BannedImport(d) => d.snippet_fixed(),
     }
   }
 
@@ -596,6 +2300,7 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.info(),
       LintOrCliDiagnostic::FastCheck(d) => d.info(),
+      LintOrCliDiagnostic::BannedImport(d) => d.info(),
     }
   }
 
@@ -603,67 +2308,512 @@ impl<'a> deno_ast::diagnostics::Diagnostic for LintOrCliDiagnostic<'a> {
     match self {
       LintOrCliDiagnostic::Lint(d) => d.docs_url(),
       LintOrCliDiagnostic::FastCheck(d) => d.docs_url(),
+      LintOrCliDiagnostic::BannedImport(d) => d.docs_url(),
     }
   }
 }
 
 trait LintReporter {
+  /// Called with the files that are checked before they're linted.
+  fn visit_checked_files(&mut self, _file_paths: &[PathBuf]) {}
   fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic);
+  /// Called instead of `visit_diagnostic` with the name of the declaration
+  /// the diagnostic is in when `--enclosing-symbol` is set.
+  fn visit_diagnostic_in_symbol(
+    &mut self,
+    d: LintOrCliDiagnostic,
+    _enclosing_symbol: Option<&str>,
+  ) {
+    self.visit_diagnostic(d);
+  }
   fn visit_error(&mut self, file_path: &str, err: &AnyError);
+  /// Called with the number of problems that `--fix` fixed in a file.
+  fn visit_fixes(&mut self, _count: usize) {}
+  /// Called with the number of problems of a file that `--one-per-file`
+  /// collapsed, before the one that's reported.
+  fn visit_collapsed(&mut self, _count: usize) {}
   fn close(&mut self, check_count: usize);
 }
 
+/// Forwards everything to several reporters, e.g. to write results to a
+/// database in addition to printing them.
+struct CombinedLintReporter(Vec<Box<dyn LintReporter + Send>>);
+
+impl LintReporter for CombinedLintReporter {
+  fn visit_checked_files(&mut self, file_paths: &[PathBuf]) {
+    for reporter in &mut self.0 {
+      reporter.visit_checked_files(file_paths);
+    }
+  }
+
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    for reporter in &mut self.0 {
+      reporter.visit_diagnostic(d);
+    }
+  }
+
+  fn visit_diagnostic_in_symbol(
+    &mut self,
+    d: LintOrCliDiagnostic,
+    enclosing_symbol: Option<&str>,
+  ) {
+    for reporter in &mut self.0 {
+      reporter.visit_diagnostic_in_symbol(d, enclosing_symbol);
+    }
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    for reporter in &mut self.0 {
+      reporter.visit_error(file_path, err);
+    }
+  }
+
+  fn visit_fixes(&mut self, count: usize) {
+    for reporter in &mut self.0 {
+      reporter.visit_fixes(count);
+    }
+  }
+
+  fn visit_collapsed(&mut self, count: usize) {
+    for reporter in &mut self.0 {
+      reporter.visit_collapsed(count);
+    }
+  }
+
+  fn close(&mut self, check_count: usize) {
+    for reporter in &mut self.0 {
+      reporter.close(check_count);
+    }
+  }
+}
+
 #[derive(Serialize)]
 struct LintError {
   file_path: String,
   message: String,
+  /// Either "io" for files that couldn't be read or "lint" for files that
+  /// couldn't be linted, like because of a syntax error.
+  kind: &'static str,
+}
+
+impl LintError {
+  fn new(file_path: &str, err: &AnyError) -> Self {
+    LintError {
+      file_path: file_path.to_string(),
+      message: err.to_string(),
+      kind: if is_io_error(err) { "io" } else { "lint" },
+    }
+  }
+}
+
+/// Whether the error is from accessing the file, like when it can't be read
+/// because of its permissions, rather than from linting it.
+fn is_io_error(err: &AnyError) -> bool {
+  err.downcast_ref::<std::io::Error>().is_some()
+}
+
+fn print_lint_error(file_path: &str, err: &AnyError) {
+  if is_io_error(err) {
+    eprintln!("Error reading: {file_path}");
+  } else {
+    eprintln!("Error linting: {file_path}");
+  }
+  eprintln!("   {err}");
+}
+
+fn print_fixed_summary(fixed_count: usize, lint_count: u32) {
+  match fixed_count {
+    0 => (),
+    1 => info!("Fixed 1 problem automatically, {} remain.", lint_count),
+    n => info!("Fixed {} problems automatically, {} remain.", n, lint_count),
+  }
+}
+
+fn format_files_with_problems(
+  files_with_problems: &HashMap<String, usize>,
+) -> String {
+  match files_with_problems.len() {
+    1 => "1 file".to_string(),
+    n => format!("{} files", n),
+  }
+}
+
+/// Gets the file with the most problems and their number, preferring the
+/// first file in alphabetical order on ties so the output is deterministic.
+/// There's none when every file has the same number of problems.
+fn worst_file(
+  files_with_problems: &HashMap<String, usize>,
+) -> Option<(&str, usize)> {
+  let mut counts = files_with_problems.values();
+  let first_count = counts.next()?;
+  if counts.all(|count| count == first_count) {
+    return None;
+  }
+  files_with_problems
+    .iter()
+    .map(|(file, count)| (file.as_str(), *count))
+    .min_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)))
+}
+
+/// Points out the file with the most problems, to tackle first, when some
+/// files have more problems than others.
+fn print_worst_file(files_with_problems: &HashMap<String, usize>) {
+  if let Some((file, count)) = worst_file(files_with_problems) {
+    info!(
+      "Most problems in {} ({} {})",
+      file,
+      count,
+      if count == 1 { "problem" } else { "problems" }
+    );
+  }
+}
+
+/// Leaves out the parts of a diagnostic that are disabled in the pretty
+/// reporter's options.
+struct PrettyDiagnostic<'a> {
+  diagnostic: LintOrCliDiagnostic<'a>,
+  options: &'a PrettyLintOptions,
+  files: &'a FileDisplay,
+  enclosing_symbol: Option<&'a str>,
+}
+
+impl<'a> Diagnostic for PrettyDiagnostic<'a> {
+  fn level(&self) -> deno_ast::diagnostics::DiagnosticLevel {
+    self.diagnostic.level()
+  }
+
+  fn code(&self) -> Cow<'_, str> {
+    self.diagnostic.code()
+  }
+
+  fn message(&self) -> Cow<'_, str> {
+    match self.enclosing_symbol {
+      Some(symbol) => {
+        Cow::Owned(format!("{} (in {})", self.diagnostic.message(), symbol))
+      }
+      None => self.diagnostic.message(),
+    }
+  }
+
+  fn location(&self) -> deno_ast::diagnostics::DiagnosticLocation {
+    if self.files.relative_to.is_none() {
+      return self.diagnostic.location();
+    }
+    // the location of a module is displayed as its absolute path, so the
+    // relative path is displayed as a path with the position appended
+    let file = self.files.display(self.diagnostic.specifier());
+    let path = match self.diagnostic.range() {
+      Some((text_info, range)) => {
+        let position = text_info.line_and_column_display(range.start);
+        format!(
+          "{}:{}:{}",
+          file, position.line_number, position.column_number
+        )
+      }
+      None => file,
+    };
+    deno_ast::diagnostics::DiagnosticLocation::Path {
+      path: PathBuf::from(path),
+    }
+  }
+
+  fn snippet(&self) -> Option<deno_ast::diagnostics::DiagnosticSnippet<'_>> {
+    if self.options.show_snippets {
+      self.diagnostic.snippet()
+    } else {
+      None
+    }
+  }
+
+  fn hint(&self) -> Option<Cow<'_, str>> {
+    if self.options.show_hints {
+      self.diagnostic.hint()
+    } else {
+      None
+    }
+  }
+
+  fn snippet_fixed(
+    &self,
+  ) -> Option<deno_ast::diagnostics::DiagnosticSnippet<'_>> {
+    if self.options.show_snippets {
+      self.diagnostic.snippet_fixed()
+    } else {
+      None
+    }
+  }
+
+  fn info(&self) -> Cow<'_, [Cow<'_, str>]> {
+    self.diagnostic.info()
+  }
+
+  fn docs_url(&self) -> Option<Cow<'_, str>> {
+    self.diagnostic.docs_url()
+  }
+}
+
+/// A displayed diagnostic in the group of its rule, with the file, 1-indexed
+/// line and column it's sorted by.
+struct GroupedDiagnostic {
+  location: (String, usize, usize),
+  text: String,
 }
 
 struct PrettyLintReporter {
   lint_count: u32,
   fixable_diagnostics: u32,
+  fixed_count: usize,
+  /// Number of problems by file path.
+  files_with_problems: HashMap<String, usize>,
+  options: PrettyLintOptions,
+  files: FileDisplay,
+  /// Displayed diagnostics by rule code when grouping by rule.
+  groups: HashMap<String, Vec<GroupedDiagnostic>>,
+  /// Number of problems by rule code, for `--stats`.
+  rule_counts: HashMap<String, u32>,
+  /// Number of collapsed problems of the next diagnostic's file.
+  collapsed_count: usize,
 }
 
 impl PrettyLintReporter {
-  fn new() -> PrettyLintReporter {
+  fn new(options: PrettyLintOptions, files: FileDisplay) -> PrettyLintReporter {
     PrettyLintReporter {
       lint_count: 0,
       fixable_diagnostics: 0,
+      fixed_count: 0,
+      files_with_problems: HashMap::new(),
+      options,
+      files,
+      groups: HashMap::new(),
+      rule_counts: HashMap::new(),
+      collapsed_count: 0,
+    }
+  }
+
+  fn hidden_count(&self) -> usize {
+    match self.options.limit {
+      Some(limit) => (self.lint_count as usize).saturating_sub(limit),
+      None => 0,
+    }
+  }
+
+  /// Whether the diagnostic being visited is past the `--compact-after`
+  /// threshold. The reporter switches to compact lines mid-stream rather
+  /// than buffering every diagnostic to decide at the end, so problems are
+  /// still printed as they're found.
+  fn is_past_compact_threshold(&self) -> bool {
+    self
+      .options
+      .compact_after
+      .is_some_and(|compact_after| self.lint_count as usize > compact_after)
+  }
+
+  fn render(
+    &self,
+    d: LintOrCliDiagnostic,
+    enclosing_symbol: Option<&str>,
+  ) -> String {
+    let text = if self.is_past_compact_threshold() {
+      format_compact_diagnostic(d, &self.files, false)
+    } else {
+      let text = PrettyDiagnostic {
+        diagnostic: d,
+        options: &self.options,
+        files: &self.files,
+        enclosing_symbol,
+      }
+      .display()
+      .to_string();
+      restyle_snippet(
+        &text,
+        self.options.underline_char,
+        self.options.unicode_frame,
+      )
+    };
+    // the link's url is absolute even when the location is relative. The
+    // escape codes would clutter output that isn't read in a terminal.
+    if self.options.hyperlinks
+      && colors::use_color()
+      && d.specifier().scheme() == "file"
+    {
+      let mut url = d.specifier().clone();
+      if let Some((text_info, range)) = d.range() {
+        let line = text_info.line_and_column_display(range.start).line_number;
+        url.set_fragment(Some(&format!("L{}", line)));
+      }
+      link_location(&text, url.as_str())
+    } else {
+      text
     }
   }
 }
 
 impl LintReporter for PrettyLintReporter {
   fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.visit_diagnostic_in_symbol(d, None);
+  }
+
+  fn visit_diagnostic_in_symbol(
+    &mut self,
+    d: LintOrCliDiagnostic,
+    enclosing_symbol: Option<&str>,
+  ) {
     self.lint_count += 1;
+    let file = self.files.display(d.specifier());
+    *self.files_with_problems.entry(file.clone()).or_default() += 1;
     if let LintOrCliDiagnostic::Lint(d) = d {
       if !d.fixes.is_empty() {
         self.fixable_diagnostics += 1;
       }
     }
+    if self.options.rule_stats {
+      // the fast check diagnostics are counted as no-slow-types
+      *self.rule_counts.entry(d.code().to_string()).or_default() += 1;
+    }
+
+    let collapsed_count = std::mem::take(&mut self.collapsed_count);
+    if self.hidden_count() > 0 {
+      return;
+    }
+    let (line, column) = match d.range() {
+      Some((text_info, range)) => {
+        let position = text_info.line_and_column_display(range.start);
+        (position.line_number, position.column_number)
+      }
+      None => (0, 0),
+    };
+    let mut text = if self.options.locations_only {
+      match d.range() {
+        Some(_) => format!("{}:{}:{}", file, line, column),
+        None => file.clone(),
+      }
+    } else {
+      self.render(d, enclosing_symbol)
+    };
+    if collapsed_count > 0
+      && (self.options.locations_only || self.is_past_compact_threshold())
+    {
+      text.push_str(&format_collapsed_count(collapsed_count));
+    } else if collapsed_count > 0 {
+      text.push_str(&format!(
+        "\n  {}",
+        colors::gray(format!(
+          "+{} more problem{} in this file",
+          collapsed_count,
+          if collapsed_count == 1 { "" } else { "s" }
+        ))
+      ));
+    }
+    if self.options.group_by_rule {
+      self.groups.entry(d.code().to_string()).or_default().push(
+        GroupedDiagnostic {
+          location: (file, line, column),
+          text,
+        },
+      );
+    } else {
+      eprintln!("{}", text);
+    }
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    print_lint_error(file_path, err);
+  }
+
+  fn visit_fixes(&mut self, count: usize) {
+    self.fixed_count += count;
+  }
+
+  fn visit_collapsed(&mut self, count: usize) {
+    self.collapsed_count = count;
+  }
+
+  fn close(&mut self, check_count: usize) {
+    // the rules with the most problems first, to decide which rule to
+    // tackle first
+    let mut groups = std::mem::take(&mut self.groups)
+      .into_iter()
+      .collect::<Vec<_>>();
+    groups.sort_by(|(a, a_diagnostics), (b, b_diagnostics)| {
+      b_diagnostics
+        .len()
+        .cmp(&a_diagnostics.len())
+        .then_with(|| a.cmp(b))
+    });
+    for (code, mut diagnostics) in groups {
+      // the files are visited in the order they finish linting
+      diagnostics.sort_by(|a, b| a.location.cmp(&b.location));
+      let problems = if diagnostics.len() == 1 {
+        "problem"
+      } else {
+        "problems"
+      };
+      eprintln!(
+        "{}",
+        colors::bold(format!("{} ({} {})", code, diagnostics.len(), problems))
+      );
+      if self.options.locations_only {
+        for diagnostic in diagnostics {
+          eprintln!("  {}", diagnostic.text);
+        }
+        eprintln!();
+      } else {
+        eprintln!();
+        for diagnostic in diagnostics {
+          eprintln!("{}", diagnostic.text);
+        }
+      }
+    }
 
-    eprintln!("{}", d.display());
-  }
-
-  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
-    eprintln!("Error linting: {file_path}");
-    eprintln!("   {err}");
-  }
-
-  fn close(&mut self, check_count: usize) {
     let fixable_suffix = if self.fixable_diagnostics > 0 {
       colors::gray(format!(" ({} fixable via --fix)", self.fixable_diagnostics))
         .to_string()
     } else {
       "".to_string()
     };
+    let hidden_count = self.hidden_count();
+    if hidden_count > 0 {
+      info!(
+        "... and {} more; run with --limit=0 to see all.",
+        hidden_count
+      );
+    }
+    if self.is_past_compact_threshold() {
+      info!(
+        "{}",
+        colors::gray(format!(
+          "Displayed the problems after the first {} in compact format.",
+          self.options.compact_after.unwrap_or_default()
+        ))
+      );
+    }
     match self.lint_count {
       1 => info!("Found 1 problem{}", fixable_suffix),
-      n if n > 1 => {
-        info!("Found {} problems{}", self.lint_count, fixable_suffix)
-      }
+      n if n > 1 => info!(
+        "Found {} problems in {}{}",
+        n,
+        format_files_with_problems(&self.files_with_problems),
+        fixable_suffix
+      ),
       _ => (),
     }
+    for (code, count) in sorted_rule_counts(&self.rule_counts) {
+      info!("  {:>5} {}", count, code);
+    }
+    if let Some(max_warnings) = self.options.max_warnings {
+      if self.lint_count as usize > max_warnings {
+        info!(
+          "{}",
+          colors::red(format!(
+            "Exceeded the maximum of {} problem{} set by --max-warnings.",
+            max_warnings,
+            if max_warnings == 1 { "" } else { "s" }
+          ))
+        );
+      }
+    }
+    print_worst_file(&self.files_with_problems);
+    print_fixed_summary(self.fixed_count, self.lint_count);
 
     match check_count {
       n if n <= 1 => info!("Checked {} file", n),
@@ -673,49 +2823,190 @@ impl LintReporter for PrettyLintReporter {
   }
 }
 
+/// The rule codes with their number of problems, the most frequent first
+/// and by code when they're as frequent.
+fn sorted_rule_counts(rule_counts: &HashMap<String, u32>) -> Vec<(&str, u32)> {
+  let mut counts = rule_counts
+    .iter()
+    .map(|(code, count)| (code.as_str(), *count))
+    .collect::<Vec<_>>();
+  counts.sort_by(|(a, a_count), (b, b_count)| {
+    b_count.cmp(a_count).then_with(|| a.cmp(b))
+  });
+  counts
+}
+
 struct CompactLintReporter {
   lint_count: u32,
+  fixed_count: usize,
+  /// Number of problems by displayed specifier.
+  files_with_problems: HashMap<String, usize>,
+  files: FileDisplay,
+  show_ranges: bool,
+  /// Number of collapsed problems of the next diagnostic's file.
+  collapsed_count: usize,
 }
 
 impl CompactLintReporter {
-  fn new() -> CompactLintReporter {
-    CompactLintReporter { lint_count: 0 }
+  fn new(files: FileDisplay, show_ranges: bool) -> CompactLintReporter {
+    CompactLintReporter {
+      lint_count: 0,
+      fixed_count: 0,
+      files_with_problems: HashMap::new(),
+      files,
+      show_ranges,
+      collapsed_count: 0,
+    }
+  }
+}
+
+/// Formats the position of a problem like `line 3, col 5`, or with its end
+/// like `line 3, col 5-10` and `line 3 col 5 - line 4 col 2` when
+/// `show_end` is set. Both the lines and the columns are 1-indexed, like the
+/// positions editors jump to, unlike the 0-indexed `col` of the JSON output.
+fn format_compact_position(
+  text_info: &SourceTextInfo,
+  range: SourceRange,
+  show_end: bool,
+) -> String {
+  let start = text_info.line_and_column_display(range.start);
+  if !show_end {
+    return format!("line {}, col {}", start.line_number, start.column_number);
+  }
+  let end = text_info.line_and_column_display(range.end);
+  if start.line_number == end.line_number {
+    format!(
+      "line {}, col {}-{}",
+      start.line_number, start.column_number, end.column_number
+    )
+  } else {
+    format!(
+      "line {} col {} - line {} col {}",
+      start.line_number,
+      start.column_number,
+      end.line_number,
+      end.column_number
+    )
+  }
+}
+
+/// Displays the specifier as a native file path when `absolute_paths` is
+/// set, leaving non-file specifiers as urls.
+fn display_specifier(
+  specifier: &ModuleSpecifier,
+  absolute_paths: bool,
+) -> String {
+  if absolute_paths {
+    if let Ok(path) = specifier_to_file_path(specifier) {
+      return path.display().to_string();
+    }
+  }
+  specifier.to_string()
+}
+
+/// How the reporters display the file of a problem: as a url, as a native
+/// path with `--absolute` or relative to the cwd with `--relative`.
+#[derive(Clone, Debug, Default)]
+pub struct FileDisplay {
+  absolute_paths: bool,
+  relative_to: Option<PathBuf>,
+}
+
+impl FileDisplay {
+  fn new(lint_options: &LintOptions) -> Self {
+    Self {
+      absolute_paths: lint_options.reporter.absolute_paths,
+      relative_to: lint_options.reporter.relative_to.clone(),
+    }
+  }
+
+  /// Displays local files as native paths rather than urls, for the
+  /// reporters whose output is read by people.
+  fn with_file_paths(self) -> Self {
+    Self {
+      absolute_paths: true,
+      ..self
+    }
+  }
+
+  fn display(&self, specifier: &ModuleSpecifier) -> String {
+    match &self.relative_to {
+      Some(base) => relative_filename(specifier, base),
+      None => display_specifier(specifier, self.absolute_paths),
+    }
+  }
+
+  fn display_path(&self, file_path: &Path) -> String {
+    match ModuleSpecifier::from_file_path(file_path) {
+      Ok(specifier) => self.display(&specifier),
+      Err(()) => file_path.display().to_string(),
+    }
+  }
+}
+
+/// Formats the number of the problems that `--one-per-file` collapsed into
+/// the reported one, which is appended to it.
+fn format_collapsed_count(count: usize) -> String {
+  format!(" (+{} more)", count)
+}
+
+/// Formats the diagnostic as a single line, like
+/// `file:///main.ts: line 1, col 5 - message (code)`.
+fn format_compact_diagnostic(
+  d: LintOrCliDiagnostic,
+  files: &FileDisplay,
+  show_ranges: bool,
+) -> String {
+  let specifier = files.display(d.specifier());
+  match d.range() {
+    Some((text_info, range)) => format!(
+      "{}: {} - {} ({})",
+      specifier,
+      format_compact_position(text_info, range, show_ranges),
+      d.message(),
+      d.code(),
+    ),
+    None => format!("{}: {} ({})", specifier, d.message(), d.code()),
   }
 }
 
 impl LintReporter for CompactLintReporter {
   fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
     self.lint_count += 1;
-
-    match d.range() {
-      Some((text_info, range)) => {
-        let line_and_column = text_info.line_and_column_display(range.start);
-        eprintln!(
-          "{}: line {}, col {} - {} ({})",
-          d.specifier(),
-          line_and_column.line_number,
-          line_and_column.column_number,
-          d.message(),
-          d.code(),
-        )
-      }
-      None => {
-        eprintln!("{}: {} ({})", d.specifier(), d.message(), d.code())
-      }
+    let specifier = self.files.display(d.specifier());
+    *self.files_with_problems.entry(specifier).or_default() += 1;
+    let mut text = format_compact_diagnostic(d, &self.files, self.show_ranges);
+    let collapsed_count = std::mem::take(&mut self.collapsed_count);
+    if collapsed_count > 0 {
+      text.push_str(&format_collapsed_count(collapsed_count));
     }
+    eprintln!("{}", text);
   }
 
   fn visit_error(&mut self, file_path: &str, err: &AnyError) {
-    eprintln!("Error linting: {file_path}");
-    eprintln!("   {err}");
+    print_lint_error(file_path, err);
+  }
+
+  fn visit_fixes(&mut self, count: usize) {
+    self.fixed_count += count;
+  }
+
+  fn visit_collapsed(&mut self, count: usize) {
+    self.collapsed_count = count;
   }
 
   fn close(&mut self, check_count: usize) {
     match self.lint_count {
       1 => info!("Found 1 problem"),
-      n if n > 1 => info!("Found {} problems", self.lint_count),
+      n if n > 1 => info!(
+        "Found {} problems in {}",
+        n,
+        format_files_with_problems(&self.files_with_problems)
+      ),
       _ => (),
     }
+    print_worst_file(&self.files_with_problems);
+    print_fixed_summary(self.fixed_count, self.lint_count);
 
     match check_count {
       n if n <= 1 => info!("Checked {} file", n),
@@ -753,6 +3044,36 @@ struct JsonLintDiagnosticRange {
   pub end: JsonDiagnosticLintPosition,
 }
 
+impl JsonLintDiagnosticRange {
+  fn new(text_info: &SourceTextInfo, range: SourceRange) -> Self {
+    JsonLintDiagnosticRange {
+      start: JsonDiagnosticLintPosition::new(
+        range.start.as_byte_index(text_info.range().start),
+        text_info.line_and_column_index(range.start),
+      ),
+      end: JsonDiagnosticLintPosition::new(
+        range.end.as_byte_index(text_info.range().start),
+        text_info.line_and_column_index(range.end),
+      ),
+    }
+  }
+}
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLintTextChange {
+  pub range: JsonLintDiagnosticRange,
+  pub new_text: String,
+}
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Clone, Serialize)]
+struct JsonLintSuggestion {
+  pub description: String,
+  pub changes: Vec<JsonLintTextChange>,
+}
+
 // WARNING: Ensure doesn't change because it's used in the JSON output
 #[derive(Clone, Serialize)]
 struct JsonLintDiagnostic {
@@ -761,100 +3082,312 @@ struct JsonLintDiagnostic {
   pub message: String,
   pub code: String,
   pub hint: Option<String>,
+  /// Only set with `--enclosing-symbol` for diagnostics inside a named
+  /// declaration.
+  #[serde(rename = "enclosingSymbol", skip_serializing_if = "Option::is_none")]
+  pub enclosing_symbol: Option<String>,
+  /// The candidate fixes of the diagnostic, so editors can offer them as
+  /// quick fixes without linting again.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub suggestions: Vec<JsonLintSuggestion>,
+}
+
+impl JsonLintDiagnostic {
+  fn new(d: LintOrCliDiagnostic, files: &FileDisplay) -> Self {
+    JsonLintDiagnostic {
+      filename: files.display(d.specifier()),
+      range: d.range().map(|(text_info, range)| {
+        JsonLintDiagnosticRange::new(text_info, range)
+      }),
+      message: d.message().to_string(),
+      code: d.code().to_string(),
+      hint: d.hint().map(|h| h.to_string()),
+      enclosing_symbol: None,
+      suggestions: match d {
+        LintOrCliDiagnostic::Lint(d) => d
+          .fixes
+          .iter()
+          .map(|fix| JsonLintSuggestion {
+            description: fix.description.to_string(),
+            changes: fix
+              .changes
+              .iter()
+              .map(|change| JsonLintTextChange {
+                range: JsonLintDiagnosticRange::new(&d.text_info, change.range),
+                new_text: change.new_text.to_string(),
+              })
+              .collect(),
+          })
+          .collect(),
+        // only the rules of deno_lint have fixes
+        LintOrCliDiagnostic::FastCheck(_)
+        | LintOrCliDiagnostic::BannedImport(_) => Vec::new(),
+      },
+    }
+  }
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLintStats {
+  files_with_problems: usize,
+  /// The file with the most problems, if any.
+  worst_file: Option<JsonWorstFile>,
+}
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Serialize)]
+struct JsonWorstFile {
+  filename: String,
+  count: usize,
 }
 
 #[derive(Serialize)]
 struct JsonLintReporter {
   diagnostics: Vec<JsonLintDiagnostic>,
   errors: Vec<LintError>,
+  stats: JsonLintStats,
+  /// Number of files that were checked, which tells a run without problems
+  /// apart from one that didn't lint anything.
+  checked: usize,
+  #[serde(skip)]
+  files: FileDisplay,
+  #[serde(skip)]
+  sort: LintJsonSort,
+  /// The rules whose problems are warnings, for sorting by severity.
+  #[serde(skip)]
+  warning_rules: HashSet<String>,
 }
 
 impl JsonLintReporter {
-  fn new() -> JsonLintReporter {
+  fn new(
+    files: FileDisplay,
+    sort: LintJsonSort,
+    warning_rules: HashSet<String>,
+  ) -> JsonLintReporter {
     JsonLintReporter {
       diagnostics: Vec::new(),
       errors: Vec::new(),
+      stats: Default::default(),
+      checked: 0,
+      files,
+      sort,
+      warning_rules,
     }
   }
 }
 
 impl LintReporter for JsonLintReporter {
   fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
-    self.diagnostics.push(JsonLintDiagnostic {
-      filename: d.specifier().to_string(),
-      range: d.range().map(|(text_info, range)| JsonLintDiagnosticRange {
-        start: JsonDiagnosticLintPosition::new(
-          range.start.as_byte_index(text_info.range().start),
-          text_info.line_and_column_index(range.start),
-        ),
-        end: JsonDiagnosticLintPosition::new(
-          range.end.as_byte_index(text_info.range().start),
-          text_info.line_and_column_index(range.end),
-        ),
-      }),
-      message: d.message().to_string(),
-      code: d.code().to_string(),
-      hint: d.hint().map(|h| h.to_string()),
-    });
+    let diagnostic = JsonLintDiagnostic::new(d, &self.files);
+    self.diagnostics.push(diagnostic);
+  }
+
+  fn visit_diagnostic_in_symbol(
+    &mut self,
+    d: LintOrCliDiagnostic,
+    enclosing_symbol: Option<&str>,
+  ) {
+    let diagnostic = JsonLintDiagnostic {
+      enclosing_symbol: enclosing_symbol.map(ToString::to_string),
+      ..JsonLintDiagnostic::new(d, &self.files)
+    };
+    self.diagnostics.push(diagnostic);
   }
 
   fn visit_error(&mut self, file_path: &str, err: &AnyError) {
-    self.errors.push(LintError {
-      file_path: file_path.to_string(),
-      message: err.to_string(),
-    });
+    self.errors.push(LintError::new(file_path, err));
   }
 
-  fn close(&mut self, _check_count: usize) {
-    sort_diagnostics(&mut self.diagnostics);
+  fn close(&mut self, check_count: usize) {
+    self.checked = check_count;
+    sort_diagnostics(&mut self.diagnostics, self.sort, &self.warning_rules);
+    let mut files_with_problems = HashMap::new();
+    for d in &self.diagnostics {
+      *files_with_problems.entry(d.filename.clone()).or_default() += 1;
+    }
+    self.stats.files_with_problems = files_with_problems.len();
+    self.stats.worst_file =
+      worst_file(&files_with_problems).map(|(filename, count)| JsonWorstFile {
+        filename: filename.to_string(),
+        count,
+      });
     let json = serde_json::to_string_pretty(&self);
     println!("{}", json.unwrap());
   }
 }
 
-fn sort_diagnostics(diagnostics: &mut [JsonLintDiagnostic]) {
+/// Writes the diagnostics of a file to a `<file>.lint.json` file next to it,
+/// which is an empty array when the file has no problems.
+fn write_sidecar_file(
+  file_path: &Path,
+  mut diagnostics: Vec<JsonLintDiagnostic>,
+  sort: LintJsonSort,
+  warning_rules: &HashSet<String>,
+) -> Result<(), AnyError> {
+  sort_diagnostics(&mut diagnostics, sort, warning_rules);
+  let mut sidecar_path = file_path.as_os_str().to_owned();
+  sidecar_path.push(".lint.json");
+  let json = serde_json::to_string_pretty(&diagnostics)?;
+  fs::write(&sidecar_path, json).with_context(|| {
+    format!(
+      "Failed writing '{}'.",
+      PathBuf::from(&sidecar_path).display()
+    )
+  })
+}
+
+/// Sorts the diagnostics in the order of `sort`, where the severity is the
+/// one they're reported with, so the problems of the warning rules come
+/// after the errors.
+fn sort_diagnostics(
+  diagnostics: &mut [JsonLintDiagnostic],
+  sort: LintJsonSort,
+  warning_rules: &HashSet<String>,
+) {
   // Sort so that we guarantee a deterministic output which is useful for tests
   diagnostics.sort_by(|a, b| {
-    use std::cmp::Ordering;
-    let file_order = a.filename.cmp(&b.filename);
-    match file_order {
-      Ordering::Equal => match &a.range {
-        Some(a_range) => match &b.range {
-          Some(b_range) => {
-            let line_order = a_range.start.line.cmp(&b_range.start.line);
-            match line_order {
-              Ordering::Equal => a_range.start.col.cmp(&b_range.start.col),
-              _ => line_order,
-            }
+    let order = match sort {
+      LintJsonSort::Position => std::cmp::Ordering::Equal,
+      LintJsonSort::Code => a.code.cmp(&b.code),
+      LintJsonSort::Severity => severity_rank(&a.code, warning_rules)
+        .cmp(&severity_rank(&b.code, warning_rules)),
+    };
+    // the code and message break the ties of problems at the same position,
+    // so that each ordering is total
+    order
+      .then_with(|| compare_position(a, b))
+      .then_with(|| a.code.cmp(&b.code))
+      .then_with(|| a.message.cmp(&b.message))
+  });
+}
+
+fn compare_position(
+  a: &JsonLintDiagnostic,
+  b: &JsonLintDiagnostic,
+) -> std::cmp::Ordering {
+  use std::cmp::Ordering;
+  let file_order = a.filename.cmp(&b.filename);
+  match file_order {
+    Ordering::Equal => match &a.range {
+      Some(a_range) => match &b.range {
+        Some(b_range) => {
+          let line_order = a_range.start.line.cmp(&b_range.start.line);
+          match line_order {
+            Ordering::Equal => a_range.start.col.cmp(&b_range.start.col),
+            _ => line_order,
           }
-          None => Ordering::Less,
-        },
-        None => match &b.range {
-          Some(_) => Ordering::Greater,
-          None => Ordering::Equal,
-        },
+        }
+        None => Ordering::Less,
       },
-      _ => file_order,
-    }
-  });
+      None => match &b.range {
+        Some(_) => Ordering::Greater,
+        None => Ordering::Equal,
+      },
+    },
+    _ => file_order,
+  }
+}
+
+fn severity_rank(code: &str, warning_rules: &HashSet<String>) -> u8 {
+  if warning_rules.contains(code) {
+    1
+  } else {
+    0
+  }
 }
 
 fn get_config_rules_err_empty(
-  rules: LintRulesConfig,
+  lint_options: &LintOptions,
   maybe_config_file: Option<&deno_config::ConfigFile>,
 ) -> Result<ConfiguredRules, AnyError> {
-  let lint_rules = get_configured_rules(rules, maybe_config_file);
-  if lint_rules.rules.is_empty() {
+  if let Some(code) = &lint_options.only_rule {
+    let is_known = CLI_RULE_NAMES.contains(&code.as_str())
+      || rules::get_all_rules()
+        .iter()
+        .any(|rule| rule.code() == code);
+    if !is_known {
+      bail!(
+        "Unknown lint rule '{}'. Run `deno lint --rules` to list the available rules.",
+        code
+      );
+    }
+  }
+  let unknown_rules = find_unknown_rules(&lint_options.rules);
+  if !unknown_rules.is_empty() {
+    let message = format_unknown_rules(&unknown_rules);
+    if lint_options.strict_rules {
+      bail!("{}", message);
+    }
+    log::warn!("{} {}", colors::yellow("Warning"), message);
+  }
+  let lint_rules = get_configured_rules(
+    lint_options.rules.clone(),
+    lint_options.banned_imports.clone(),
+    maybe_config_file,
+  );
+  if lint_rules.rules.is_empty()
+    && !lint_rules.no_slow_types
+    && lint_rules.banned_imports.is_empty()
+  {
     bail!("No rules have been configured")
   }
   Ok(lint_rules)
 }
 
+/// Prints the rules the linter will actually run with, along with the
+/// configuration they were resolved from.
+fn print_effective_config(
+  rules_config: &LintRulesConfig,
+  lint_rules: &ConfiguredRules,
+  maybe_config_file: Option<&deno_config::ConfigFile>,
+) {
+  let mut rule_codes = lint_rules
+    .rules
+    .iter()
+    .map(|r| r.code())
+    .collect::<Vec<_>>();
+  rule_codes.sort_unstable();
+  let tags = rules_config
+    .tags
+    .clone()
+    .unwrap_or_else(|| get_default_tags(maybe_config_file));
+  let json = serde_json::json!({
+    "rules": rule_codes,
+    "noSlowTypes": lint_rules.no_slow_types,
+    "tags": tags,
+    "include": rules_config.include,
+    "exclude": rules_config.exclude,
+  });
+  // print to stderr so it doesn't interfere with the json reporter
+  eprintln!("{}", serde_json::to_string_pretty(&json).unwrap());
+}
+
+/// Prints how many rules are enabled and the tags they were selected by.
+fn print_enabled_rules_summary(
+  rules_config: &LintRulesConfig,
+  lint_rules: &ConfiguredRules,
+  maybe_config_file: Option<&deno_config::ConfigFile>,
+) {
+  let tags = rules_config
+    .tags
+    .clone()
+    .unwrap_or_else(|| get_default_tags(maybe_config_file));
+  let rules_count = lint_rules.rules.len();
+  eprintln!(
+    "Linting with {} rule{} (tags: {})",
+    rules_count,
+    if rules_count == 1 { "" } else { "s" },
+    tags.join(", ")
+  );
+}
+
 #[derive(Debug, Clone)]
 pub struct ConfiguredRules {
   pub rules: Vec<&'static dyn LintRule>,
   // cli specific rules
   pub no_slow_types: bool,
+  pub banned_imports: Vec<String>,
 }
 
 impl ConfiguredRules {
@@ -866,24 +3399,41 @@ impl ConfiguredRules {
     if self.no_slow_types {
       names.push("no-slow-types");
     }
+    if !self.banned_imports.is_empty() {
+      names.push(NO_BANNED_IMPORTS_NAME);
+      names.extend(self.banned_imports.iter().map(|s| s.as_str()));
+    }
     names
   }
 }
 
+const NO_SLOW_TYPES_NAME: &str = "no-slow-types";
+/// Rules that are implemented by the CLI rather than deno_lint.
+pub const CLI_RULE_NAMES: [&str; 2] =
+  [NO_SLOW_TYPES_NAME, NO_BANNED_IMPORTS_NAME];
+
 pub fn get_configured_rules(
   rules: LintRulesConfig,
+  banned_imports: Vec<String>,
   maybe_config_file: Option<&deno_config::ConfigFile>,
 ) -> ConfiguredRules {
-  const NO_SLOW_TYPES_NAME: &str = "no-slow-types";
+  let is_excluded = |name: &str| {
+    rules
+      .exclude
+      .as_ref()
+      .map(|exclude| exclude.iter().any(|i| i == name))
+      .unwrap_or(false)
+  };
   let implicit_no_slow_types = maybe_config_file
     .map(|c| c.is_package() || !c.json.workspaces.is_empty())
     .unwrap_or(false);
-  let no_slow_types = implicit_no_slow_types
-    && !rules
-      .exclude
-      .as_ref()
-      .map(|exclude| exclude.iter().any(|i| i == NO_SLOW_TYPES_NAME))
-      .unwrap_or(false);
+  let no_slow_types =
+    implicit_no_slow_types && !is_excluded(NO_SLOW_TYPES_NAME);
+  let banned_imports = if is_excluded(NO_BANNED_IMPORTS_NAME) {
+    Vec::new()
+  } else {
+    banned_imports
+  };
   let rules = rules::get_filtered_rules(
     rules
       .tags
@@ -891,19 +3441,20 @@ pub fn get_configured_rules(
     rules.exclude.map(|exclude| {
       exclude
         .into_iter()
-        .filter(|c| c != NO_SLOW_TYPES_NAME)
+        .filter(|c| !CLI_RULE_NAMES.contains(&c.as_str()))
         .collect()
     }),
     rules.include.map(|include| {
       include
         .into_iter()
-        .filter(|c| c != NO_SLOW_TYPES_NAME)
+        .filter(|c| !CLI_RULE_NAMES.contains(&c.as_str()))
         .collect()
     }),
   );
   ConfiguredRules {
     rules,
     no_slow_types,
+    banned_imports,
   }
 }
 
@@ -920,7 +3471,10 @@ fn get_default_tags(
 
 #[cfg(test)]
 mod test {
+  use deno_lint::diagnostic::LintFix;
+  use deno_lint::diagnostic::LintFixChange;
   use deno_lint::rules::get_recommended_rules;
+  use test_util::TempDir;
 
   use super::*;
   use crate::args::LintRulesConfig;
@@ -932,7 +3486,7 @@ mod test {
       include: None,
       tags: None,
     };
-    let rules = get_configured_rules(rules_config, None);
+    let rules = get_configured_rules(rules_config, Vec::new(), None);
     let mut rule_names = rules
       .rules
       .into_iter()
@@ -947,4 +3501,564 @@ mod test {
     recommended_rule_names.sort();
     assert_eq!(rule_names, recommended_rule_names);
   }
+
+  #[test]
+  fn test_file_paths() {
+    assert!(is_test_file_path(Path::new("/a/mod_test.ts")));
+    assert!(is_test_file_path(Path::new("/a/mod.test.tsx")));
+    assert!(is_test_file_path(Path::new("/a/mod.spec.js")));
+    assert!(is_test_file_path(Path::new("/a/test.mjs")));
+    assert!(!is_test_file_path(Path::new("/a/mod.ts")));
+    assert!(!is_test_file_path(Path::new("/a/testing.ts")));
+    assert!(!is_test_file_path(Path::new("/test/mod.ts")));
+  }
+
+  #[test]
+  fn dedup_paths() {
+    let temp_dir = TempDir::new();
+    temp_dir.write("a.ts", "");
+    temp_dir.write("b.ts", "");
+    let a = temp_dir.path().join("a.ts").to_path_buf();
+    let b = temp_dir.path().join("b.ts").to_path_buf();
+    let a_via_dot = temp_dir.path().join("./a.ts").to_path_buf();
+    assert_eq!(
+      dedup_lint_paths(vec![
+        b.clone(),
+        a.clone(),
+        a_via_dot.clone(),
+        b.clone()
+      ]),
+      (vec![b.clone(), a], vec![a_via_dot, b])
+    );
+  }
+
+  fn matches_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    root: &serde_json::Value,
+  ) -> bool {
+    use serde_json::Value;
+    if let Some(Value::String(reference)) = schema.get("$ref") {
+      let name = reference.trim_start_matches("#/definitions/");
+      return matches_schema(value, &root["definitions"][name], root);
+    }
+    if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+      return schemas.iter().any(|s| matches_schema(value, s, root));
+    }
+    let types = match &schema["type"] {
+      Value::String(t) => vec![t.as_str()],
+      Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+      _ => panic!("unsupported schema: {}", schema),
+    };
+    let matches_type = match value {
+      Value::Null => types.contains(&"null"),
+      Value::Bool(_) => types.contains(&"boolean"),
+      Value::Number(n) => types.contains(&"integer") && n.is_u64(),
+      Value::String(_) => types.contains(&"string"),
+      Value::Array(items) => {
+        types.contains(&"array")
+          && items
+            .iter()
+            .all(|i| matches_schema(i, &schema["items"], root))
+      }
+      Value::Object(object) => {
+        let properties = schema["properties"].as_object().unwrap();
+        types.contains(&"object")
+          && schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|key| object.contains_key(key.as_str().unwrap()))
+          && object.iter().all(|(key, value)| match properties.get(key) {
+            Some(property) => matches_schema(value, property, root),
+            None => false,
+          })
+      }
+    };
+    if !matches_type {
+      eprintln!("{} doesn't match {}", value, schema);
+    }
+    matches_type
+  }
+
+  #[test]
+  fn json_output_matches_schema() {
+    let cwd = std::env::current_dir().unwrap();
+    let text_info = SourceTextInfo::from_string("import \"lodash\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::from_file_path(cwd.join("main.ts")).unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 7, start + 15),
+      imported: "lodash".to_string(),
+      pattern: "lodash".to_string(),
+    };
+    let d = LintOrCliDiagnostic::BannedImport(&diagnostic);
+    let mut reporter = JsonLintReporter::new(
+      Default::default(),
+      Default::default(),
+      HashSet::new(),
+    );
+    reporter.visit_diagnostic(d);
+    reporter.visit_diagnostic_in_symbol(d, Some("main"));
+    reporter.visit_error("other.ts", &generic_error("Expected ';'"));
+    reporter.stats.files_with_problems = 1;
+
+    let output = serde_json::to_value(&reporter).unwrap();
+    let schema: serde_json::Value =
+      serde_json::from_str(JSON_OUTPUT_SCHEMA).unwrap();
+    assert!(matches_schema(&output, &schema, &schema));
+  }
+
+  #[test]
+  fn finds_worst_file() {
+    assert_eq!(worst_file(&HashMap::new()), None);
+    let files_with_problems = HashMap::from([
+      ("c.ts".to_string(), 2),
+      ("b.ts".to_string(), 3),
+      ("a.ts".to_string(), 3),
+    ]);
+    assert_eq!(worst_file(&files_with_problems), Some(("a.ts", 3)));
+    let files_with_problems =
+      HashMap::from([("a.ts".to_string(), 2), ("b.ts".to_string(), 2)]);
+    assert_eq!(worst_file(&files_with_problems), None);
+    let files_with_problems = HashMap::from([("a.ts".to_string(), 2)]);
+    assert_eq!(worst_file(&files_with_problems), None);
+  }
+
+  #[test]
+  fn dedups_diagnostics() {
+    let text_info = SourceTextInfo::from_string("let a = 1;".into());
+    let start = text_info.range().start;
+    let diagnostic = |code: &str, message: &str, end: usize| LintDiagnostic {
+      specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+      range: SourceRange::new(start + 4, start + end),
+      text_info: text_info.clone(),
+      message: message.to_string(),
+      code: code.to_string(),
+      hint: None,
+      fixes: Vec::new(),
+    };
+    let mut diagnostics = vec![
+      diagnostic("prefer-const", "first", 5),
+      diagnostic("no-unused-vars", "other rule", 5),
+      diagnostic("prefer-const", "duplicate", 5),
+      diagnostic("prefer-const", "other range", 9),
+    ];
+    dedup_diagnostics(&mut diagnostics);
+    let messages = diagnostics
+      .iter()
+      .map(|d| d.message.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["first", "other rule", "other range"]);
+  }
+
+  #[test]
+  fn sorts_warnings_after_errors() {
+    let text_info = SourceTextInfo::from_string("let a = 1;\ndebugger;".into());
+    let start = text_info.range().start;
+    let diagnostic = |code: &str, range: SourceRange| LintDiagnostic {
+      specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+      range,
+      text_info: text_info.clone(),
+      message: String::new(),
+      code: code.to_string(),
+      hint: None,
+      fixes: Vec::new(),
+    };
+    let warning =
+      diagnostic("prefer-const", SourceRange::new(start + 4, start + 5));
+    let error =
+      diagnostic("no-debugger", SourceRange::new(start + 11, start + 20));
+    let files = FileDisplay::default();
+    let mut diagnostics = vec![
+      JsonLintDiagnostic::new(LintOrCliDiagnostic::Lint(&warning), &files),
+      JsonLintDiagnostic::new(LintOrCliDiagnostic::Lint(&error), &files),
+    ];
+    let codes = |diagnostics: &[JsonLintDiagnostic]| {
+      diagnostics
+        .iter()
+        .map(|d| d.code.clone())
+        .collect::<Vec<_>>()
+    };
+    let warning_rules = HashSet::from(["prefer-const".to_string()]);
+    sort_diagnostics(&mut diagnostics, LintJsonSort::Severity, &warning_rules);
+    assert_eq!(codes(&diagnostics), vec!["no-debugger", "prefer-const"]);
+    sort_diagnostics(&mut diagnostics, LintJsonSort::Position, &warning_rules);
+    assert_eq!(codes(&diagnostics), vec!["prefer-const", "no-debugger"]);
+  }
+
+  #[test]
+  fn counts_applied_fixes() {
+    let text_info = SourceTextInfo::from_string("let a = b;".into());
+    let start = text_info.range().start;
+    let diagnostic = |code: &str, changes: &[(usize, usize, &'static str)]| {
+      let changes = changes
+        .iter()
+        .map(|(change_start, change_end, new_text)| LintFixChange {
+          new_text: Cow::Borrowed(*new_text),
+          range: SourceRange::new(start + *change_start, start + *change_end),
+        })
+        .collect();
+      LintDiagnostic {
+        specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+        range: SourceRange::new(start, start + 3),
+        text_info: text_info.clone(),
+        message: String::new(),
+        code: code.to_string(),
+        hint: None,
+        fixes: vec![LintFix {
+          description: Cow::Borrowed(""),
+          changes,
+        }],
+      }
+    };
+    let diagnostics = vec![
+      diagnostic("prefer-const", &[(0, 3, "const")]),
+      // one of its changes overlaps the first fix, so the whole fix is left
+      // for the next pass
+      diagnostic("other-rule", &[(8, 9, "c"), (2, 5, "x")]),
+      diagnostic("other-rule", &[(8, 9, "c")]),
+    ];
+    assert_eq!(
+      apply_lint_fixes(&text_info, &diagnostics, &|_| true),
+      Some(("const a = c;".to_string(), 2))
+    );
+
+    let fix_options = FileFixOptions {
+      fail_on_nonconvergence: false,
+      only_rules: HashSet::new(),
+      dry_run: false,
+      max_iterations: None,
+      safe_only: true,
+      today: utc_now().date_naive(),
+    };
+    assert_eq!(
+      apply_lint_fixes(&text_info, &diagnostics, &|d| fix_options
+        .allows_fix(d)),
+      Some(("const a = b;".to_string(), 1))
+    );
+  }
+
+  #[test]
+  fn unchanged_file_text() {
+    let temp_dir = TempDir::new();
+    temp_dir.write("main.ts", "let a = 1;\r\n");
+    let file_path = temp_dir.path().join("main.ts").to_path_buf();
+    assert_eq!(
+      read_unchanged_file_text(&file_path, "let a = 1;\r\n", false),
+      Some("let a = 1;\r\n".to_string())
+    );
+    // the text of the file is returned rather than the normalized one
+    assert_eq!(
+      read_unchanged_file_text(&file_path, "let a = 1;\n", true),
+      Some("let a = 1;\r\n".to_string())
+    );
+    assert_eq!(
+      read_unchanged_file_text(&file_path, "let a = 1;\n", false),
+      None
+    );
+
+    // changed while it was being linted
+    temp_dir.write("main.ts", "let a = 2;\r\n");
+    assert_eq!(
+      read_unchanged_file_text(&file_path, "let a = 1;\r\n", false),
+      None
+    );
+    temp_dir.remove_file("main.ts");
+    assert_eq!(
+      read_unchanged_file_text(&file_path, "let a = 1;\r\n", false),
+      None
+    );
+  }
+
+  #[test]
+  fn unconverged_fixes_keep_diagnostics() {
+    let temp_dir = TempDir::new();
+    let source_code = "let a = 1;\nconsole.log(a);\n";
+    temp_dir.write("main.ts", source_code);
+    let file_path = temp_dir.path().join("main.ts").to_path_buf();
+    let linter = create_linter(get_recommended_rules());
+    let write = |fail_on_nonconvergence| {
+      let mut fixed_source = lint_file_in_memory(
+        &linter,
+        &file_path,
+        source_code.to_string(),
+        None,
+        false,
+      )
+      .unwrap();
+      // as if the maximum number of fix iterations was reached
+      fixed_source.original_text = Some(source_code.to_string());
+      fixed_source.unconverged_codes = vec!["prefer-const".to_string()];
+      let fix_options = FileFixOptions {
+        fail_on_nonconvergence,
+        only_rules: HashSet::new(),
+        dry_run: false,
+        max_iterations: None,
+        safe_only: false,
+        today: utc_now().date_naive(),
+      };
+      write_lint_fixes(&file_path, fixed_source, Some(&fix_options)).unwrap()
+    };
+
+    // the problems are still reported along with the failure
+    let fixed_source = write(true);
+    assert_eq!(fixed_source.diagnostics.len(), 1);
+    assert_eq!(fixed_source.unconverged_codes, vec!["prefer-const"]);
+
+    // only warned about
+    let fixed_source = write(false);
+    assert_eq!(fixed_source.diagnostics.len(), 1);
+    assert!(fixed_source.unconverged_codes.is_empty());
+  }
+
+  #[tokio::test]
+  async fn timed_out_file_is_not_fixed() {
+    let temp_dir = TempDir::new();
+    let source_code = "let a = 1;\nconsole.log(a);\n";
+    temp_dir.write("main.ts", source_code);
+    let file_path = temp_dir.path().join("main.ts").to_path_buf();
+    let fix_options = Arc::new(FileFixOptions {
+      fail_on_nonconvergence: false,
+      only_rules: HashSet::new(),
+      dry_run: false,
+      max_iterations: None,
+      safe_only: false,
+      today: utc_now().date_naive(),
+    });
+    // the linting waits for the receiver to be signaled
+    let lint = |rx: std::sync::mpsc::Receiver<()>| {
+      let file_path = file_path.clone();
+      let fix_options = fix_options.clone();
+      move || {
+        rx.recv().unwrap();
+        lint_file_in_memory(
+          &create_linter(get_recommended_rules()),
+          &file_path,
+          source_code.to_string(),
+          Some(fix_options.as_ref()),
+          false,
+        )
+      }
+    };
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let (r, task) = lint_with_timeout(
+      &file_path,
+      Some(fix_options.as_ref()),
+      Duration::from_millis(10),
+      lint(rx),
+    );
+    assert!(r.is_none());
+    // the abandoned task finishes without writing the fixes
+    tx.send(()).unwrap();
+    task.await.unwrap();
+    assert_eq!(temp_dir.read_to_string("main.ts"), source_code);
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    tx.send(()).unwrap();
+    let (r, task) = lint_with_timeout(
+      &file_path,
+      Some(fix_options.as_ref()),
+      Duration::from_secs(30),
+      lint(rx),
+    );
+    task.await.unwrap();
+    let fixed_source = r.unwrap().unwrap();
+    assert!(fixed_source.diagnostics.is_empty());
+    assert_eq!(fixed_source.fixed_count, 1);
+    assert_eq!(
+      temp_dir.read_to_string("main.ts"),
+      "const a = 1;\nconsole.log(a);\n"
+    );
+  }
+
+  #[test]
+  fn sorts_rule_counts() {
+    let rule_counts = HashMap::from([
+      ("no-var".to_string(), 2),
+      ("prefer-const".to_string(), 5),
+      ("eqeqeq".to_string(), 2),
+    ]);
+    assert_eq!(
+      sorted_rule_counts(&rule_counts),
+      vec![("prefer-const", 5), ("eqeqeq", 2), ("no-var", 2)]
+    );
+  }
+
+  #[test]
+  fn byte_windows_overlap() {
+    let text_info = SourceTextInfo::from_string("import \"lodash\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 7, start + 15),
+      imported: "lodash".to_string(),
+      pattern: "lodash".to_string(),
+    };
+    let d = LintOrCliDiagnostic::BannedImport(&diagnostic);
+    assert!(is_in_byte_windows(d, &[0..8]));
+    assert!(is_in_byte_windows(d, &[14..20]));
+    assert!(is_in_byte_windows(d, &[0..1, 10..10]));
+    assert!(!is_in_byte_windows(d, &[0..7]));
+    assert!(!is_in_byte_windows(d, &[15..20]));
+  }
+
+  #[test]
+  fn compact_positions() {
+    let text_info =
+      SourceTextInfo::from_string("let a = 1;\nlet b = {\n};".into());
+    let start = text_info.range().start;
+    let range = SourceRange::new(start + 4, start + 5);
+    assert_eq!(
+      format_compact_position(&text_info, range, false),
+      "line 1, col 5"
+    );
+    assert_eq!(
+      format_compact_position(&text_info, range, true),
+      "line 1, col 5-6"
+    );
+    let range = SourceRange::new(start + 19, start + 22);
+    assert_eq!(
+      format_compact_position(&text_info, range, true),
+      "line 2 col 9 - line 3 col 2"
+    );
+  }
+
+  #[test]
+  fn lints_source_in_memory() {
+    let linter = create_linter(get_recommended_rules());
+    let specifier = ModuleSpecifier::parse("file:///virtual/main.ts").unwrap();
+    let (_, diagnostics) = lint_source(
+      &linter,
+      &specifier,
+      MediaType::TypeScript,
+      "let a: any = 1;\ndebugger;\nexport { a };\n".to_string(),
+    )
+    .unwrap();
+    let mut codes = diagnostics
+      .iter()
+      .map(|d| d.code.as_str())
+      .collect::<Vec<_>>();
+    codes.sort_unstable();
+    assert_eq!(
+      codes,
+      vec!["no-debugger", "no-explicit-any", "prefer-const"]
+    );
+
+    assert!(lint_source(
+      &linter,
+      &specifier,
+      MediaType::TypeScript,
+      "let = ;".to_string(),
+    )
+    .is_err());
+
+    // the fixed text is returned rather than written
+    let fix_options = FileFixOptions {
+      fail_on_nonconvergence: false,
+      only_rules: HashSet::new(),
+      dry_run: false,
+      max_iterations: None,
+      safe_only: false,
+      today: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+    };
+    let fixed_source = fix_lint_source(
+      &linter,
+      &specifier,
+      MediaType::TypeScript,
+      "let a: any = 1;\ndebugger;\nexport { a };\n".to_string(),
+      &fix_options,
+    )
+    .unwrap();
+    assert_eq!(
+      fixed_source.source.text_info().text_str(),
+      "const a: any = 1;\n\nexport { a };\n"
+    );
+    assert_eq!(
+      fixed_source.original_text,
+      "let a: any = 1;\ndebugger;\nexport { a };\n"
+    );
+    assert_eq!(fixed_source.fixed_count, 2);
+    assert!(fixed_source.unconverged_codes.is_empty());
+    assert_eq!(
+      fixed_source
+        .diagnostics
+        .iter()
+        .map(|d| d.code.as_str())
+        .collect::<Vec<_>>(),
+      vec!["no-explicit-any"]
+    );
+  }
+
+  #[test]
+  fn lint_error_kinds() {
+    let err = AnyError::from(std::io::Error::from(
+      std::io::ErrorKind::PermissionDenied,
+    ));
+    assert_eq!(LintError::new("main.ts", &err).kind, "io");
+    let err = generic_error("Expected ';', got 'a'");
+    assert_eq!(LintError::new("main.ts", &err).kind, "lint");
+  }
+
+  #[test]
+  fn pretty_reporter_options() {
+    let cwd = std::env::current_dir().unwrap();
+    let text_info = SourceTextInfo::from_string("import \"lodash\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::from_file_path(cwd.join("main.ts")).unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 7, start + 15),
+      imported: "lodash".to_string(),
+      pattern: "lodash".to_string(),
+    };
+    let d = LintOrCliDiagnostic::BannedImport(&diagnostic);
+
+    let reporter = PrettyLintReporter::new(
+      Default::default(),
+      FileDisplay::default().with_file_paths(),
+    );
+    let relative = FileDisplay {
+      absolute_paths: true,
+      relative_to: Some(cwd.clone()),
+    };
+    let text =
+      test_util::strip_ansi_codes(&reporter.render(d, None)).to_string();
+    assert!(text.contains(&cwd.join("main.ts").display().to_string()));
+    assert!(text.contains("hint:"));
+    assert!(text.contains("import \"lodash\";"));
+
+    let reporter = PrettyLintReporter::new(
+      PrettyLintOptions {
+        show_snippets: false,
+        show_hints: false,
+        ..Default::default()
+      },
+      relative.clone(),
+    );
+    let text =
+      test_util::strip_ansi_codes(&reporter.render(d, None)).to_string();
+    assert!(text.contains(" main.ts:1:8"));
+    assert!(!text.contains(&cwd.display().to_string()));
+    assert!(!text.contains("hint:"));
+    assert!(!text.contains("import \"lodash\";"));
+
+    let mut reporter = PrettyLintReporter::new(
+      PrettyLintOptions {
+        compact_after: Some(1),
+        ..Default::default()
+      },
+      relative,
+    );
+    reporter.lint_count = 1;
+    assert!(reporter.render(d, None).contains("hint:"));
+    reporter.lint_count = 2;
+    assert_eq!(
+      reporter.render(d, None),
+      "main.ts: line 1, col 8 - Importing 'lodash' is not allowed (no-banned-imports)"
+    );
+  }
 }
@@ -0,0 +1,64 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Serialize;
+
+use super::FileDisplay;
+use super::JsonLintDiagnostic;
+use super::LintError;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+// WARNING: Ensure doesn't change because it's used in the JSON output
+#[derive(Serialize)]
+struct NdJsonLintError {
+  /// Tells the error lines apart from the diagnostic ones.
+  #[serde(rename = "type")]
+  kind: &'static str,
+  #[serde(flatten)]
+  error: LintError,
+}
+
+/// Prints each diagnostic and error as a JSON object on its own line as
+/// soon as it's visited, rather than buffering the whole output like the
+/// JSON reporter, so that the output of large runs can be streamed. The
+/// diagnostics of a file are printed in order, but the files are in the
+/// order they finish linting.
+pub struct NdJsonLintReporter {
+  files: FileDisplay,
+}
+
+impl NdJsonLintReporter {
+  pub fn new(files: FileDisplay) -> Self {
+    Self { files }
+  }
+}
+
+impl LintReporter for NdJsonLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    self.visit_diagnostic_in_symbol(d, None);
+  }
+
+  fn visit_diagnostic_in_symbol(
+    &mut self,
+    d: LintOrCliDiagnostic,
+    enclosing_symbol: Option<&str>,
+  ) {
+    let diagnostic = JsonLintDiagnostic {
+      enclosing_symbol: enclosing_symbol.map(ToString::to_string),
+      ..JsonLintDiagnostic::new(d, &self.files)
+    };
+    println!("{}", serde_json::to_string(&diagnostic).unwrap());
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    let error = NdJsonLintError {
+      kind: "error",
+      error: LintError::new(file_path, err),
+    };
+    println!("{}", serde_json::to_string(&error).unwrap());
+  }
+
+  fn close(&mut self, _check_count: usize) {}
+}
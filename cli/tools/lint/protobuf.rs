@@ -0,0 +1,255 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! The `--protobuf` output, whose schema is in
+//! `cli/schemas/lint-output.v1.proto`. The messages are written by hand
+//! rather than generated so that building the CLI doesn't need `protoc`.
+
+use std::collections::HashSet;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+
+use deno_ast::diagnostics::Diagnostic as _;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use prost::Message;
+
+use super::JsonDiagnosticLintPosition;
+use super::JsonLintDiagnostic;
+use super::LintError;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct LintRecord {
+  #[prost(oneof = "Record", tags = "1, 2")]
+  pub record: Option<Record>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum Record {
+  #[prost(message, tag = "1")]
+  Diagnostic(Diagnostic),
+  #[prost(message, tag = "2")]
+  Error(FileError),
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct Diagnostic {
+  #[prost(string, tag = "1")]
+  pub filename: String,
+  #[prost(string, tag = "2")]
+  pub code: String,
+  #[prost(string, tag = "3")]
+  pub message: String,
+  #[prost(message, optional, tag = "4")]
+  pub range: Option<Range>,
+  #[prost(enumeration = "Severity", tag = "5")]
+  pub severity: i32,
+  #[prost(string, optional, tag = "6")]
+  pub hint: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct Range {
+  #[prost(message, optional, tag = "1")]
+  pub start: Option<Position>,
+  #[prost(message, optional, tag = "2")]
+  pub end: Option<Position>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct Position {
+  #[prost(uint32, tag = "1")]
+  pub line: u32,
+  #[prost(uint32, tag = "2")]
+  pub col: u32,
+  #[prost(uint32, tag = "3")]
+  pub byte_pos: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, prost::Enumeration)]
+#[repr(i32)]
+pub enum Severity {
+  Unspecified = 0,
+  Error = 1,
+  Warning = 2,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct FileError {
+  #[prost(string, tag = "1")]
+  pub file_path: String,
+  #[prost(string, tag = "2")]
+  pub message: String,
+  #[prost(string, tag = "3")]
+  pub kind: String,
+}
+
+impl From<JsonDiagnosticLintPosition> for Position {
+  fn from(position: JsonDiagnosticLintPosition) -> Self {
+    Position {
+      line: position.line as u32,
+      col: position.col as u32,
+      byte_pos: position.byte_pos as u32,
+    }
+  }
+}
+
+/// Writes each diagnostic as a length-delimited `LintRecord` as soon as it's
+/// visited, so the output of large runs is never held in memory and the
+/// output of several runs can be concatenated.
+pub struct ProtobufLintReporter {
+  writer: BufWriter<Box<dyn Write + Send>>,
+  /// The rules whose problems are warnings rather than errors.
+  warning_rules: HashSet<String>,
+  files: FileDisplay,
+  has_failed: bool,
+}
+
+impl ProtobufLintReporter {
+  /// Writes to the file at `output`, or to stdout when it's not set.
+  pub fn new(
+    output: Option<&Path>,
+    warning_rules: HashSet<String>,
+    files: FileDisplay,
+  ) -> Result<Self, AnyError> {
+    let writer: Box<dyn Write + Send> = match output {
+      Some(path) => {
+        Box::new(std::fs::File::create(path).with_context(|| {
+          format!("Failed creating lint output file '{}'.", path.display())
+        })?)
+      }
+      None => Box::new(std::io::stdout()),
+    };
+    Ok(Self {
+      writer: BufWriter::new(writer),
+      warning_rules,
+      files,
+      has_failed: false,
+    })
+  }
+
+  fn write_record(&mut self, record: Record) {
+    let bytes = LintRecord {
+      record: Some(record),
+    }
+    .encode_length_delimited_to_vec();
+    let result = self.writer.write_all(&bytes);
+    self.warn_on_failure(result);
+  }
+
+  fn warn_on_failure(&mut self, result: std::io::Result<()>) {
+    if let Err(err) = result {
+      if !self.has_failed {
+        log::warn!("Failed writing protobuf lint output: {:#}", err);
+      }
+      self.has_failed = true;
+    }
+  }
+}
+
+impl LintReporter for ProtobufLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    let severity = if self.warning_rules.contains(d.code().as_ref()) {
+      Severity::Warning
+    } else {
+      Severity::Error
+    };
+    let d = JsonLintDiagnostic::new(d, &self.files);
+    self.write_record(Record::Diagnostic(Diagnostic {
+      filename: d.filename,
+      code: d.code,
+      message: d.message,
+      range: d.range.map(|range| Range {
+        start: Some(range.start.into()),
+        end: Some(range.end.into()),
+      }),
+      severity: severity as i32,
+      hint: d.hint,
+    }));
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    let error = LintError::new(file_path, err);
+    self.write_record(Record::Error(FileError {
+      file_path: error.file_path,
+      message: error.message,
+      kind: error.kind.to_string(),
+    }));
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    let result = self.writer.flush();
+    self.warn_on_failure(result);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::ModuleSpecifier;
+  use deno_ast::SourceRange;
+  use deno_ast::SourceTextInfo;
+  use deno_core::error::generic_error;
+  use test_util::TempDir;
+
+  use super::super::banned_imports::BannedImportDiagnostic;
+  use super::*;
+
+  #[test]
+  fn writes_length_delimited_records() {
+    let temp_dir = TempDir::new();
+    let output = temp_dir.path().join("lint.pb");
+    let text_info = SourceTextInfo::from_string("import \"lodash\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 7, start + 15),
+      imported: "lodash".to_string(),
+      pattern: "lodash".to_string(),
+    };
+    let mut reporter = ProtobufLintReporter::new(
+      Some(output.as_path()),
+      HashSet::new(),
+      Default::default(),
+    )
+    .unwrap();
+    reporter.visit_diagnostic(LintOrCliDiagnostic::BannedImport(&diagnostic));
+    reporter.visit_error("other.ts", &generic_error("Expected ';'"));
+    reporter.close(2);
+
+    // concatenated outputs decode as one stream
+    let bytes = output.read_to_bytes_if_exists().unwrap().repeat(2);
+    let mut buf = bytes.as_slice();
+    let mut records = Vec::new();
+    while !buf.is_empty() {
+      records.push(LintRecord::decode_length_delimited(&mut buf).unwrap());
+    }
+    assert_eq!(records.len(), 4);
+    let Some(Record::Diagnostic(d)) = &records[0].record else {
+      panic!("expected a diagnostic");
+    };
+    assert_eq!(d.filename, "file:///main.ts");
+    assert_eq!(d.code, "no-banned-imports");
+    assert_eq!(d.severity, Severity::Error as i32);
+    let range = d.range.as_ref().unwrap();
+    assert_eq!(
+      range.start,
+      Some(Position {
+        line: 1,
+        col: 7,
+        byte_pos: 7,
+      })
+    );
+    assert_eq!(
+      records[1].record,
+      Some(Record::Error(FileError {
+        file_path: "other.ts".to_string(),
+        message: "Expected ';'".to_string(),
+        kind: "lint".to_string(),
+      }))
+    );
+    assert_eq!(records[2], records[0]);
+  }
+}
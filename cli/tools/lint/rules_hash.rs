@@ -0,0 +1,57 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_lint::rules;
+use deno_lint::rules::LintRule;
+
+use crate::util::checksum;
+
+/// Hashes the codes and tags of the rules, sorted so the hash doesn't depend
+/// on the order deno_lint declares them in. It's SHA-256 rather than a fast
+/// hash so it stays the same across Deno versions with the same rules.
+fn hash_rules(rules: &[&'static dyn LintRule]) -> String {
+  let mut lines = rules
+    .iter()
+    .map(|rule| {
+      let mut tags = rule.tags().to_vec();
+      tags.sort_unstable();
+      format!("{} [{}]\n", rule.code(), tags.join(","))
+    })
+    .collect::<Vec<_>>();
+  lines.sort();
+  checksum::gen(&lines)
+}
+
+/// Prints the hash of the rules compiled into this build, so a bug report
+/// can state which rules were active, or fails when it isn't `expected`
+/// so CI notices the rule set changing between Deno versions.
+pub fn check_rules_hash(expected: Option<&str>) -> Result<(), AnyError> {
+  let hash = hash_rules(&rules::get_all_rules());
+  match expected {
+    Some(expected) if !expected.eq_ignore_ascii_case(&hash) => bail!(
+      "The lint rule set hash is {}, but {} was expected. Run `deno lint --rules` to list the rules of this version.",
+      hash,
+      expected
+    ),
+    // printed even with `--quiet`, like the rules list
+    _ => println!("{}", hash),
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn hashes_rules_in_any_order() {
+    let mut all_rules = rules::get_all_rules();
+    let hash = hash_rules(&all_rules);
+    assert_eq!(hash.len(), 64);
+    all_rules.reverse();
+    assert_eq!(hash_rules(&all_rules), hash);
+    all_rules.pop();
+    assert_ne!(hash_rules(&all_rules), hash);
+  }
+}
@@ -0,0 +1,160 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Restyles the snippets of the pretty reporter, which `Diagnostic::display`
+//! renders with ASCII characters, for terminals and log viewers that render
+//! them poorly.
+
+use console_static_text::ansi::strip_ansi_codes;
+
+#[derive(Debug, PartialEq, Eq)]
+enum LineKind {
+  /// The ` --> file:line:col` line.
+  Location,
+  /// A source line or an empty line of the gutter.
+  Gutter,
+  /// The line of the gutter that underlines the range.
+  Underline,
+  /// An `= hint:` or `= info:` line.
+  Note,
+  Other,
+}
+
+fn line_kind(line: &str) -> LineKind {
+  let line = strip_ansi_codes(line);
+  let trimmed = line.trim_start();
+  if trimmed.starts_with("--> ") {
+    return LineKind::Location;
+  }
+  if trimmed.starts_with("= ") {
+    return LineKind::Note;
+  }
+  let after_number = trimmed.trim_start_matches(|c: char| c.is_ascii_digit());
+  let Some(rest) = after_number.trim_start().strip_prefix('|') else {
+    return LineKind::Other;
+  };
+  let is_underline = after_number.len() == trimmed.len()
+    && rest.contains(['^', '-', '~'])
+    && rest.chars().all(|c| matches!(c, ' ' | '^' | '-' | '~'));
+  if is_underline {
+    LineKind::Underline
+  } else {
+    LineKind::Gutter
+  }
+}
+
+/// Restyles the rendered diagnostic, underlining with `underline` rather
+/// than `^` and drawing the frame with box-drawing characters when
+/// `unicode_frame` is set. The text is returned as is with the defaults.
+/// The replaced characters are never part of a color escape code.
+pub fn restyle_snippet(
+  text: &str,
+  underline: Option<char>,
+  unicode_frame: bool,
+) -> String {
+  if underline.is_none() && !unicode_frame {
+    return text.to_string();
+  }
+  text
+    .split('\n')
+    .map(|line| match line_kind(line) {
+      LineKind::Location if unicode_frame => line.replacen("-->", "╭─▶", 1),
+      LineKind::Note if unicode_frame => line.replacen('=', "·", 1),
+      LineKind::Gutter if unicode_frame => line.replacen('|', "│", 1),
+      LineKind::Underline => {
+        let line = match underline {
+          Some(c) => line.replace('^', &c.to_string()),
+          None => line.to_string(),
+        };
+        if unicode_frame {
+          line.replacen('|', "│", 1)
+        } else {
+          line
+        }
+      }
+      _ => line.to_string(),
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Makes the location of the rendered diagnostic an OSC 8 hyperlink to
+/// `url`, which terminals that support them open on click. Those that don't
+/// display the location as is.
+pub fn link_location(text: &str, url: &str) -> String {
+  text
+    .split('\n')
+    .map(|line| {
+      if line_kind(line) != LineKind::Location {
+        return line.to_string();
+      }
+      let arrow_end = ["--> ", "╭─▶ "]
+        .iter()
+        .find_map(|arrow| line.find(arrow).map(|index| index + arrow.len()));
+      match arrow_end {
+        Some(index) => format!(
+          "{}\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
+          &line[..index],
+          url,
+          &line[index..]
+        ),
+        None => line.to_string(),
+      }
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const RENDERED: &str =
+    "error[no-debugger]: `debugger` statement is not allowed
+ --> /main.ts:1:1
+  |
+1 | debugger; // ^ |
+  | ^^^^^^^^^
+  = hint: Remove the `debugger` statement
+
+  docs: https://lint.deno.land/rules/no-debugger";
+
+  #[test]
+  fn keeps_text_by_default() {
+    assert_eq!(restyle_snippet(RENDERED, None, false), RENDERED);
+  }
+
+  #[test]
+  fn restyles_snippets() {
+    assert_eq!(
+      restyle_snippet(RENDERED, Some('~'), true),
+      "error[no-debugger]: `debugger` statement is not allowed
+ ╭─▶ /main.ts:1:1
+  │
+1 │ debugger; // ^ |
+  │ ~~~~~~~~~
+  · hint: Remove the `debugger` statement
+
+  docs: https://lint.deno.land/rules/no-debugger"
+    );
+    // colors don't prevent the lines from being restyled
+    assert_eq!(
+      restyle_snippet("  \x1b[31m| ^^^\x1b[0m", Some('-'), false),
+      "  \x1b[31m| ---\x1b[0m"
+    );
+  }
+
+  #[test]
+  fn links_location() {
+    let text = link_location(RENDERED, "file:///main.ts#L1");
+    assert!(text.contains(
+      "\n --> \x1b]8;;file:///main.ts#L1\x1b\\/main.ts:1:1\x1b]8;;\x1b\\\n"
+    ));
+    // only the location is linked
+    assert_eq!(text.matches("\x1b]8;;").count(), 2);
+    let text = link_location(
+      &restyle_snippet(RENDERED, None, true),
+      "file:///main.ts#L1",
+    );
+    assert!(text.contains("╭─▶ \x1b]8;;file:///main.ts#L1\x1b\\/main.ts"));
+  }
+}
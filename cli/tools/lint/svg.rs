@@ -0,0 +1,258 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_ast::SourceRange;
+use deno_ast::SourceTextInfo;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+
+use super::fingerprint::FingerprintIds;
+use super::xml::escape_xml;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+const FONT_SIZE: f64 = 14.0;
+const CHAR_WIDTH: f64 = 8.4;
+const LINE_HEIGHT: f64 = 20.0;
+const PADDING: f64 = 12.0;
+/// Lines of the range displayed before the frame is cut off.
+const MAX_RANGE_LINES: usize = 10;
+
+/// Renders the code frame of each diagnostic, like the pretty reporter's
+/// snippet with carets, as an SVG file named by the diagnostic's
+/// fingerprint, so it can be embedded in dashboards and PR comments.
+pub struct SvgLintReporter {
+  dir: PathBuf,
+  cwd: PathBuf,
+  ids: FingerprintIds,
+  has_failed: bool,
+}
+
+impl SvgLintReporter {
+  pub fn new(dir: &Path) -> Result<Self, AnyError> {
+    std::fs::create_dir_all(dir).with_context(|| {
+      format!("Failed creating SVG output directory '{}'.", dir.display())
+    })?;
+    Ok(Self {
+      dir: dir.to_path_buf(),
+      cwd: std::env::current_dir().unwrap_or_default(),
+      ids: Default::default(),
+      has_failed: false,
+    })
+  }
+}
+
+impl LintReporter for SvgLintReporter {
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    // diagnostics without a range have no code frame
+    let Some((text_info, range)) = d.range() else {
+      return;
+    };
+    let id = self.ids.next_id(&d, &self.cwd);
+    let path = self.dir.join(format!("{}.svg", id));
+    let result = std::fs::write(&path, render_code_frame(&d, text_info, range));
+    if let Err(err) = result {
+      if !self.has_failed {
+        log::warn!(
+          "Failed writing SVG code frame '{}': {}",
+          path.display(),
+          err
+        );
+      }
+      self.has_failed = true;
+    }
+  }
+
+  fn visit_error(&mut self, _file_path: &str, _err: &AnyError) {}
+
+  fn close(&mut self, _check_count: usize) {}
+}
+
+struct FrameLine<'a> {
+  number: usize,
+  text: &'a str,
+  /// Byte range of the line's text that the diagnostic covers.
+  highlight: Option<(usize, usize)>,
+}
+
+/// Gets the lines of the diagnostic's range with a line of context before
+/// and after it.
+fn frame_lines<'a>(
+  text: &'a str,
+  start: usize,
+  end: usize,
+) -> Vec<FrameLine<'a>> {
+  let mut lines = Vec::new();
+  let mut line_start = 0;
+  for (index, line) in text.split('\n').enumerate() {
+    let line_end = line_start + line.len();
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let is_covered =
+      start <= line_end && (end > line_start || start >= line_start);
+    let highlight = is_covered.then(|| {
+      let from = start.saturating_sub(line_start).min(line.len());
+      let to = end.saturating_sub(line_start).min(line.len());
+      (from, to)
+    });
+    lines.push(FrameLine {
+      number: index + 1,
+      text: line,
+      highlight,
+    });
+    line_start = line_end + 1;
+  }
+  let first = lines
+    .iter()
+    .position(|l| l.highlight.is_some())
+    .unwrap_or(0);
+  let last = lines
+    .iter()
+    .rposition(|l| l.highlight.is_some())
+    .unwrap_or(first)
+    .min(first + MAX_RANGE_LINES - 1);
+  let from = first.saturating_sub(1);
+  let to = (last + 2).min(lines.len());
+  lines.drain(to..);
+  lines.drain(..from);
+  lines
+}
+
+/// Escapes text for the SVG, where tabs would misalign the carets.
+fn escape_text(text: &str) -> String {
+  escape_xml(&text.replace('\t', " "))
+}
+
+fn render_code_frame(
+  d: &LintOrCliDiagnostic,
+  text_info: &SourceTextInfo,
+  range: SourceRange,
+) -> String {
+  let file_start = text_info.range().start;
+  let lines = frame_lines(
+    text_info.text_str(),
+    range.start.as_byte_index(file_start),
+    range.end.as_byte_index(file_start),
+  );
+  let gutter_width = lines
+    .last()
+    .map(|l| l.number.to_string().len())
+    .unwrap_or(1);
+  let title = format!("({}) {}", d.code(), d.message());
+  let start = text_info.line_and_column_display(range.start);
+  let location = format!(
+    "at {}:{}:{}",
+    d.specifier(),
+    start.line_number,
+    start.column_number
+  );
+
+  // the attributes and content of each row of text
+  let mut rows = vec![
+    ("fill=\"#f14c4c\" font-weight=\"bold\"", escape_text(&title)),
+    ("fill=\"#3b8eea\"", escape_text(&location)),
+  ];
+  let mut max_chars = title.chars().count().max(location.chars().count());
+  let last_highlighted = lines.iter().rposition(|l| l.highlight.is_some());
+  for (index, line) in lines.iter().enumerate() {
+    let gutter = format!("{:>gutter_width$} | ", line.number);
+    max_chars =
+      max_chars.max(gutter.chars().count() + line.text.chars().count());
+    let content = match line.highlight {
+      Some((from, to)) => format!(
+        "{}<tspan fill=\"#f14c4c\">{}</tspan>{}",
+        escape_text(&line.text[..from]),
+        escape_text(&line.text[from..to]),
+        escape_text(&line.text[to..])
+      ),
+      None => escape_text(line.text),
+    };
+    rows.push((
+      "xml:space=\"preserve\"",
+      format!(
+        "<tspan fill=\"#808080\">{}</tspan>{}",
+        escape_text(&gutter),
+        content
+      ),
+    ));
+
+    // carets under the covered text of the range's last line
+    if Some(index) == last_highlighted {
+      if let Some((from, to)) = line.highlight {
+        rows.push((
+          "xml:space=\"preserve\" fill=\"#f14c4c\"",
+          format!(
+            "{}{}{}",
+            " ".repeat(gutter.chars().count()),
+            " ".repeat(line.text[..from].chars().count()),
+            "^".repeat(line.text[from..to].chars().count().max(1))
+          ),
+        ));
+      }
+    }
+  }
+
+  let width = PADDING * 2.0 + max_chars as f64 * CHAR_WIDTH;
+  let height = PADDING * 2.0 + rows.len() as f64 * LINE_HEIGHT;
+  let mut svg = format!(
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width:.0}\" height=\"{height:.0}\" font-family=\"monospace\" font-size=\"{FONT_SIZE}\">\n<rect width=\"100%\" height=\"100%\" fill=\"#1e1e1e\"/>\n<g fill=\"#d4d4d4\">\n"
+  );
+  for (index, (attributes, content)) in rows.iter().enumerate() {
+    let y = PADDING + (index + 1) as f64 * LINE_HEIGHT - 5.0;
+    svg.push_str(&format!(
+      "<text x=\"{PADDING}\" y=\"{y:.0}\" {attributes}>{content}</text>\n"
+    ));
+  }
+  svg.push_str("</g>\n</svg>\n");
+  svg
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::ModuleSpecifier;
+
+  use super::super::banned_imports::BannedImportDiagnostic;
+  use super::*;
+
+  #[test]
+  fn collects_frame_lines() {
+    let text = "a\r\nconst x = 1;\nb\nc\n";
+    let start = text.find('x').unwrap();
+    let lines = frame_lines(text, start, start + 1);
+    let lines = lines
+      .iter()
+      .map(|l| (l.number, l.text, l.highlight))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      lines,
+      vec![
+        (1, "a", None),
+        (2, "const x = 1;", Some((6, 7))),
+        (3, "b", None),
+      ]
+    );
+  }
+
+  #[test]
+  fn renders_code_frame() {
+    let text_info = SourceTextInfo::from_string("import \"<a>\";".into());
+    let start = text_info.range().start;
+    let diagnostic = BannedImportDiagnostic {
+      specifier: ModuleSpecifier::parse("file:///main.ts").unwrap(),
+      text_info: text_info.clone(),
+      range: SourceRange::new(start + 7, start + 12),
+      imported: "<a>".to_string(),
+      pattern: "<a>".to_string(),
+    };
+    let d = LintOrCliDiagnostic::BannedImport(&diagnostic);
+    let svg = render_code_frame(&d, &text_info, diagnostic.range);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.contains("at file:///main.ts:1:8"));
+    assert!(svg.contains(
+      "import <tspan fill=\"#f14c4c\">&quot;&lt;a&gt;&quot;</tspan>;"
+    ));
+    assert!(svg.contains(">           ^^^^^</text>"));
+  }
+}
@@ -0,0 +1,196 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_ast::diagnostics::Diagnostic;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+
+use super::FileDisplay;
+use super::LintOrCliDiagnostic;
+use super::LintReporter;
+
+struct TapProblem {
+  code: String,
+  message: String,
+  /// The 1-indexed line and column, when the problem has a range.
+  position: Option<(usize, usize)>,
+}
+
+/// Prints a TAP version 13 report with a test point per checked file, where
+/// the problems of a failed file are in a YAML block.
+pub struct TapLintReporter {
+  file_display: FileDisplay,
+  /// The checked files in the order they're linted in.
+  checked: Vec<String>,
+  files: BTreeMap<String, Vec<TapProblem>>,
+  errors: BTreeMap<String, String>,
+}
+
+impl TapLintReporter {
+  pub fn new(file_display: FileDisplay) -> Self {
+    Self {
+      file_display,
+      checked: Vec::new(),
+      files: BTreeMap::new(),
+      errors: BTreeMap::new(),
+    }
+  }
+}
+
+impl LintReporter for TapLintReporter {
+  fn visit_checked_files(&mut self, file_paths: &[PathBuf]) {
+    self.checked.extend(
+      file_paths
+        .iter()
+        .map(|file_path| self.file_display.display_path(file_path)),
+    );
+  }
+
+  fn visit_diagnostic(&mut self, d: LintOrCliDiagnostic) {
+    let position = d.range().map(|(text_info, range)| {
+      let position = text_info.line_and_column_display(range.start);
+      (position.line_number, position.column_number)
+    });
+    self
+      .files
+      .entry(self.file_display.display(d.specifier()))
+      .or_default()
+      .push(TapProblem {
+        code: d.code().to_string(),
+        message: d.message().to_string(),
+        position,
+      });
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    let filename = self.file_display.display_path(Path::new(file_path));
+    self.errors.insert(filename, err.to_string());
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    print!("{}", format_tap(&self.checked, &self.files, &self.errors));
+  }
+}
+
+/// Formats a test point for each checked file, followed by the ones of the
+/// files with problems or errors that weren't checked as files of the run,
+/// like the modules of the package of no-slow-types.
+fn format_tap(
+  checked: &[String],
+  files: &BTreeMap<String, Vec<TapProblem>>,
+  errors: &BTreeMap<String, String>,
+) -> String {
+  let checked_set = checked.iter().collect::<HashSet<_>>();
+  let mut filenames = checked.iter().collect::<Vec<_>>();
+  filenames.extend(
+    files
+      .keys()
+      .chain(errors.keys())
+      .filter(|filename| !checked_set.contains(filename))
+      .collect::<BTreeSet<_>>(),
+  );
+  let mut tap = String::new();
+  writeln!(tap, "TAP version 13").unwrap();
+  writeln!(tap, "1..{}", filenames.len()).unwrap();
+  for (index, filename) in filenames.into_iter().enumerate() {
+    let number = index + 1;
+    let description = escape_description(filename);
+    if let Some(message) = errors.get(filename) {
+      writeln!(tap, "not ok {} - {} # ERROR", number, description).unwrap();
+      writeln!(tap, "  ---").unwrap();
+      writeln!(tap, "  message: {}", yaml_string(message)).unwrap();
+      writeln!(tap, "  ...").unwrap();
+      continue;
+    }
+    let Some(problems) = files.get(filename) else {
+      writeln!(tap, "ok {} - {}", number, description).unwrap();
+      continue;
+    };
+    writeln!(tap, "not ok {} - {}", number, description).unwrap();
+    writeln!(tap, "  ---").unwrap();
+    writeln!(tap, "  problems:").unwrap();
+    for problem in problems {
+      writeln!(tap, "    - code: {}", yaml_string(&problem.code)).unwrap();
+      writeln!(tap, "      message: {}", yaml_string(&problem.message))
+        .unwrap();
+      if let Some((line, column)) = problem.position {
+        writeln!(tap, "      line: {}", line).unwrap();
+        writeln!(tap, "      column: {}", column).unwrap();
+      }
+    }
+    writeln!(tap, "  ...").unwrap();
+  }
+  tap
+}
+
+/// Escapes the `#` that would start a directive in the description of a
+/// test point.
+fn escape_description(text: &str) -> String {
+  text.replace('\\', "\\\\").replace('#', "\\#")
+}
+
+/// Quotes the text as a YAML double quoted scalar, whose escapes are a
+/// superset of JSON's.
+fn yaml_string(text: &str) -> String {
+  serde_json::to_string(text).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn formats_tap() {
+    let files = BTreeMap::from([(
+      "file:///main.ts".to_string(),
+      vec![
+        TapProblem {
+          code: "no-unused-vars".to_string(),
+          message: "`a` is never used".to_string(),
+          position: Some((2, 7)),
+        },
+        TapProblem {
+          code: "no-slow-types".to_string(),
+          message: "missing \"return\" type".to_string(),
+          position: None,
+        },
+      ],
+    )]);
+    let errors = BTreeMap::from([(
+      "/other#1.ts".to_string(),
+      "Failed parsing".to_string(),
+    )]);
+    let checked = vec![
+      "/other#1.ts".to_string(),
+      "file:///main.ts".to_string(),
+      "file:///clean.ts".to_string(),
+    ];
+    assert_eq!(
+      format_tap(&checked, &files, &errors),
+      r#"TAP version 13
+1..3
+not ok 1 - /other\#1.ts # ERROR
+  ---
+  message: "Failed parsing"
+  ...
+not ok 2 - file:///main.ts
+  ---
+  problems:
+    - code: "no-unused-vars"
+      message: "`a` is never used"
+      line: 2
+      column: 7
+    - code: "no-slow-types"
+      message: "missing \"return\" type"
+  ...
+ok 3 - file:///clean.ts
+"#
+    );
+  }
+}
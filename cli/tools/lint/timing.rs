@@ -0,0 +1,135 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use deno_ast::ParsedSource;
+use deno_core::parking_lot::Mutex;
+use deno_lint::rules::LintRule;
+
+use super::create_linter;
+
+/// Number of the slowest files that are printed.
+const SLOWEST_FILES_COUNT: usize = 10;
+
+/// Time spent on each rule and file with `--timing`, like ESLint's
+/// `TIMING=1`. As deno_lint runs the rules of a file in a single pass over
+/// its AST, each rule is run again on its own against the parsed file to
+/// time it, which doesn't affect the reported problems.
+#[derive(Default)]
+pub struct LintTimings {
+  rules: Mutex<HashMap<&'static str, Duration>>,
+  files: Mutex<Vec<(PathBuf, Duration)>>,
+}
+
+impl LintTimings {
+  /// Adds the time spent parsing, linting and fixing a file.
+  pub fn add_file(&self, file_path: &Path, elapsed: Duration) {
+    self.files.lock().push((file_path.to_path_buf(), elapsed));
+  }
+
+  /// Times each rule on the parsed file.
+  pub fn time_rules(
+    &self,
+    rules: &[&'static dyn LintRule],
+    parsed_source: &ParsedSource,
+  ) {
+    let mut rule_times = Vec::with_capacity(rules.len());
+    for rule in rules {
+      let linter = create_linter(vec![*rule]);
+      let start = Instant::now();
+      linter.lint_with_ast(parsed_source);
+      rule_times.push((rule.code(), start.elapsed()));
+    }
+    let mut totals = self.rules.lock();
+    for (code, elapsed) in rule_times {
+      *totals.entry(code).or_default() += elapsed;
+    }
+  }
+
+  /// Prints the rules by total time and the slowest files to stderr, so
+  /// it doesn't mix with the JSON output.
+  pub fn print(&self) {
+    let rules = sorted_by_time(
+      self
+        .rules
+        .lock()
+        .iter()
+        .map(|(code, elapsed)| (*code, *elapsed)),
+    );
+    let total = rules.iter().map(|(_, elapsed)| *elapsed).sum::<Duration>();
+    let code_width = rules
+      .iter()
+      .map(|(code, _)| code.len())
+      .chain(["Rule".len()])
+      .max()
+      .unwrap();
+    eprintln!("{:<code_width$} | Time (ms) | Relative", "Rule");
+    eprintln!(":{}|----------:|--------:", "-".repeat(code_width));
+    for (code, elapsed) in &rules {
+      let relative = if total.is_zero() {
+        0.0
+      } else {
+        elapsed.as_secs_f64() / total.as_secs_f64() * 100.0
+      };
+      eprintln!(
+        "{:<code_width$} | {:>9.3} | {:>7.1}%",
+        code,
+        elapsed.as_secs_f64() * 1000.0,
+        relative
+      );
+    }
+
+    let files = sorted_by_time(
+      self
+        .files
+        .lock()
+        .iter()
+        .map(|(path, elapsed)| (path.display().to_string(), *elapsed)),
+    );
+    if !files.is_empty() {
+      eprintln!();
+      eprintln!("Slowest files:");
+      for (path, elapsed) in files.iter().take(SLOWEST_FILES_COUNT) {
+        eprintln!("{:>9.3}ms {}", elapsed.as_secs_f64() * 1000.0, path);
+      }
+    }
+  }
+}
+
+/// Sorts the entries with the longest time first, and by name when the
+/// times are equal.
+fn sorted_by_time<T: Ord>(
+  entries: impl Iterator<Item = (T, Duration)>,
+) -> Vec<(T, Duration)> {
+  let mut entries = entries.collect::<Vec<_>>();
+  entries.sort_by(|(a, a_elapsed), (b, b_elapsed)| {
+    b_elapsed.cmp(a_elapsed).then_with(|| a.cmp(b))
+  });
+  entries
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn sorts_by_time() {
+    let entries = [
+      ("no-var", Duration::from_millis(2)),
+      ("prefer-const", Duration::from_millis(5)),
+      ("eqeqeq", Duration::from_millis(2)),
+    ];
+    assert_eq!(
+      sorted_by_time(entries.into_iter()),
+      vec![
+        ("prefer-const", Duration::from_millis(5)),
+        ("eqeqeq", Duration::from_millis(2)),
+        ("no-var", Duration::from_millis(2)),
+      ]
+    );
+  }
+}
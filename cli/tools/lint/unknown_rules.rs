@@ -0,0 +1,139 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Validation of the rule codes of the include and exclude lists, which
+//! deno_lint silently ignores when they don't exist, like when a rule name
+//! has a typo.
+
+use deno_lint::rules;
+
+use super::CLI_RULE_NAMES;
+use crate::args::LintRulesConfig;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownRule {
+  code: String,
+  /// The known rule code that's the closest to the code, if any is close.
+  suggestion: Option<&'static str>,
+}
+
+/// Gets the rules of the include and exclude lists that don't exist.
+pub fn find_unknown_rules(rules_config: &LintRulesConfig) -> Vec<UnknownRule> {
+  let all_rules = rules::get_all_rules();
+  let known_codes = all_rules
+    .iter()
+    .map(|rule| rule.code())
+    .chain(CLI_RULE_NAMES)
+    .collect::<Vec<_>>();
+  let mut unknown_rules = Vec::new();
+  let codes = rules_config
+    .include
+    .iter()
+    .flatten()
+    .chain(rules_config.exclude.iter().flatten());
+  for code in codes {
+    if known_codes.contains(&code.as_str())
+      || unknown_rules.iter().any(|r: &UnknownRule| &r.code == code)
+    {
+      continue;
+    }
+    unknown_rules.push(UnknownRule {
+      code: code.clone(),
+      suggestion: closest_code(code, &known_codes),
+    });
+  }
+  unknown_rules
+}
+
+pub fn format_unknown_rules(unknown_rules: &[UnknownRule]) -> String {
+  let mut message =
+    "Unknown lint rules in the include or exclude lists:".to_string();
+  for rule in unknown_rules {
+    message.push_str("\n  ");
+    message.push_str(&rule.code);
+    if let Some(suggestion) = rule.suggestion {
+      message.push_str(&format!(" (did you mean '{}'?)", suggestion));
+    }
+  }
+  message.push_str("\nRun `deno lint --rules` to list the available rules.");
+  message
+}
+
+/// Gets the known code with the fewest edits from the code, when the edits
+/// are few enough for the code to likely be a typo of it.
+fn closest_code(
+  code: &str,
+  known_codes: &[&'static str],
+) -> Option<&'static str> {
+  let max_distance = (code.chars().count() / 3).max(1);
+  known_codes
+    .iter()
+    .map(|known_code| (edit_distance(code, known_code), *known_code))
+    .filter(|(distance, _)| *distance <= max_distance)
+    .min()
+    .map(|(_, known_code)| known_code)
+}
+
+/// The Levenshtein distance between the texts.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a_char) in a.chars().enumerate() {
+    let mut row = Vec::with_capacity(b.len() + 1);
+    row.push(i + 1);
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = previous_row[j] + usize::from(a_char != *b_char);
+      let insertion = row[j] + 1;
+      let deletion = previous_row[j + 1] + 1;
+      row.push(substitution.min(insertion).min(deletion));
+    }
+    previous_row = row;
+  }
+  previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn finds_unknown_rules() {
+    let rules_config = LintRulesConfig {
+      tags: None,
+      include: Some(vec![
+        "no-debuger".to_string(),
+        "no-slow-types".to_string(),
+        "my-custom-rule".to_string(),
+      ]),
+      exclude: Some(vec!["no-debugger".to_string(), "no-debuger".to_string()]),
+    };
+    let unknown_rules = find_unknown_rules(&rules_config);
+    assert_eq!(
+      unknown_rules,
+      vec![
+        UnknownRule {
+          code: "no-debuger".to_string(),
+          suggestion: Some("no-debugger"),
+        },
+        UnknownRule {
+          code: "my-custom-rule".to_string(),
+          suggestion: None,
+        },
+      ]
+    );
+    assert_eq!(
+      format_unknown_rules(&unknown_rules),
+      "Unknown lint rules in the include or exclude lists:
+  no-debuger (did you mean 'no-debugger'?)
+  my-custom-rule
+Run `deno lint --rules` to list the available rules."
+    );
+  }
+
+  #[test]
+  fn edit_distances() {
+    assert_eq!(edit_distance("no-var", "no-var"), 0);
+    assert_eq!(edit_distance("no-bar", "no-var"), 1);
+    assert_eq!(edit_distance("no-eval", "no-evals"), 1);
+    assert_eq!(edit_distance("", "abc"), 3);
+  }
+}
@@ -0,0 +1,43 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+/// Escapes text for the content or a double quoted attribute of an XML 1.0
+/// document, including its line breaks, which attributes would otherwise
+/// normalize to spaces. The control characters that XML 1.0 doesn't allow,
+/// not even as character references, are replaced with U+FFFD.
+pub fn escape_xml(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\n' => escaped.push_str("&#10;"),
+      '\r' => escaped.push_str("&#13;"),
+      '\t' => escaped.push(c),
+      '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => {
+        escaped.push(char::REPLACEMENT_CHARACTER)
+      }
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn escapes_xml() {
+    assert_eq!(
+      escape_xml("a < b && \"c\" > d\r\n\te"),
+      "a &lt; b &amp;&amp; &quot;c&quot; &gt; d&#13;&#10;\te"
+    );
+    // not allowed in XML 1.0
+    assert_eq!(
+      escape_xml("a\u{0}b\u{1b}[31mc\u{ffff}"),
+      "a\u{fffd}b\u{fffd}[31mc\u{fffd}"
+    );
+  }
+}
@@ -3,8 +3,12 @@
 use crate::colors;
 use dissimilar::diff as difference;
 use dissimilar::Chunk;
+use std::collections::HashMap;
 use std::fmt::Write as _;
 
+/// Number of unchanged lines around the changes of a unified diff hunk.
+const UNIFIED_DIFF_CONTEXT: usize = 3;
+
 /// Print diff of the same file_path, before and after formatting.
 ///
 /// Diff format is loosely based on GitHub diff formatting.
@@ -145,6 +149,125 @@ impl DiffBuilder {
   }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineChange {
+  Equal,
+  Delete,
+  Insert,
+}
+
+/// Creates an uncolored unified diff of the lines of the texts, like
+/// `diff -u`, which tools like `patch` and `git apply` accept. It's empty
+/// when the texts are equal.
+pub fn unified_diff(
+  orig_text: &str,
+  edit_text: &str,
+  orig_name: &str,
+  edit_name: &str,
+) -> String {
+  if orig_text == edit_text {
+    return String::new();
+  }
+
+  // each distinct line is mapped to a char, so that the char diff of the
+  // texts is a diff of their lines
+  let mut line_chars = HashMap::new();
+  let mut lines = Vec::new();
+  let mut to_chars = |text: &'_ str| -> String {
+    text
+      .split_inclusive('\n')
+      .map(|line| {
+        *line_chars.entry(line.to_string()).or_insert_with(|| {
+          lines.push(line.to_string());
+          // the supplementary planes have room for more than a million
+          // lines, and no surrogates
+          char::from_u32(0x10000 + lines.len() as u32 - 1).unwrap()
+        })
+      })
+      .collect()
+  };
+  let orig_chars = to_chars(orig_text);
+  let edit_chars = to_chars(edit_text);
+  let line_of = |c: char| lines[(c as u32 - 0x10000) as usize].as_str();
+  let mut changes = Vec::new();
+  for chunk in difference(&orig_chars, &edit_chars) {
+    let (change, chars) = match chunk {
+      Chunk::Equal(chars) => (LineChange::Equal, chars),
+      Chunk::Delete(chars) => (LineChange::Delete, chars),
+      Chunk::Insert(chars) => (LineChange::Insert, chars),
+    };
+    changes.extend(chars.chars().map(|c| (change, line_of(c))));
+  }
+
+  // the ranges of the changes with their context, merged when they overlap
+  let mut hunks: Vec<std::ops::Range<usize>> = Vec::new();
+  for (index, _) in changes
+    .iter()
+    .enumerate()
+    .filter(|(_, (change, _))| *change != LineChange::Equal)
+  {
+    let start = index.saturating_sub(UNIFIED_DIFF_CONTEXT);
+    let end = (index + 1 + UNIFIED_DIFF_CONTEXT).min(changes.len());
+    match hunks.last_mut() {
+      Some(last) if last.end >= start => last.end = end,
+      _ => hunks.push(start..end),
+    }
+  }
+
+  let mut output = format!("--- {}\n+++ {}\n", orig_name, edit_name);
+  // the 0-indexed lines of the texts at the start of the current change
+  let (mut orig_line, mut edit_line) = (0, 0);
+  let mut index = 0;
+  for hunk in hunks {
+    for (change, _) in &changes[index..hunk.start] {
+      if *change != LineChange::Insert {
+        orig_line += 1;
+      }
+      if *change != LineChange::Delete {
+        edit_line += 1;
+      }
+    }
+    let hunk_changes = &changes[hunk.clone()];
+    let orig_count = hunk_changes
+      .iter()
+      .filter(|(change, _)| *change != LineChange::Insert)
+      .count();
+    let edit_count = hunk_changes
+      .iter()
+      .filter(|(change, _)| *change != LineChange::Delete)
+      .count();
+    // an empty range starts at the line before it
+    let start_line = |line: usize, count: usize| match count {
+      0 => line,
+      _ => line + 1,
+    };
+    writeln!(
+      output,
+      "@@ -{},{} +{},{} @@",
+      start_line(orig_line, orig_count),
+      orig_count,
+      start_line(edit_line, edit_count),
+      edit_count
+    )
+    .unwrap();
+    for (change, line) in hunk_changes {
+      output.push(match change {
+        LineChange::Equal => ' ',
+        LineChange::Delete => '-',
+        LineChange::Insert => '+',
+      });
+      output.push_str(line);
+      if !line.ends_with('\n') {
+        output.push_str("\n\\ No newline at end of file\n");
+      }
+    }
+    orig_line += orig_count;
+    edit_line += edit_count;
+    index = hunk.end;
+  }
+  output
+}
+
 fn fmt_add() -> String {
   colors::green_bold("+").to_string()
 }
@@ -214,6 +337,37 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_unified_diff() {
+    let orig = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+    let edit = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk";
+    assert_eq!(
+      unified_diff(orig, edit, "a/main.ts", "b/main.ts"),
+      concat!(
+        "--- a/main.ts\n",
+        "+++ b/main.ts\n",
+        "@@ -1,5 +1,5 @@\n",
+        " a\n",
+        "-b\n",
+        "+B\n",
+        " c\n",
+        " d\n",
+        " e\n",
+        "@@ -8,3 +8,4 @@\n",
+        " h\n",
+        " i\n",
+        " j\n",
+        "+k\n",
+        "\\ No newline at end of file\n",
+      )
+    );
+    assert_eq!(
+      unified_diff("a\n", "", "a/main.ts", "b/main.ts"),
+      "--- a/main.ts\n+++ b/main.ts\n@@ -1,1 +0,0 @@\n-a\n"
+    );
+    assert_eq!(unified_diff("a\n", "a\n", "a", "b"), "");
+  }
+
   #[test]
   fn test_newlines_differing() {
     run_test("test\n", "test\r\n", " | Text differed by line endings.\n");
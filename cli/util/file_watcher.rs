@@ -13,6 +13,8 @@ use deno_runtime::fmt_errors::format_js_error;
 use log::info;
 use notify::event::Event as NotifyEvent;
 use notify::event::EventKind;
+use notify::event::ModifyKind;
+use notify::event::RenameMode;
 use notify::Error as NotifyError;
 use notify::RecommendedWatcher;
 use notify::RecursiveMode;
@@ -20,6 +22,7 @@ use notify::Watcher;
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::io::IsTerminal;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -382,6 +385,40 @@ fn new_watcher(
   )?)
 }
 
+/// Watches a directory, but not its subdirectories, for the files that are
+/// created in it, including the ones that are moved into it. Unlike the
+/// other watchers, the changes of existing files aren't reported, so each
+/// file is received once. The returned watcher stops watching when dropped.
+pub fn watch_created_files(
+  dir: &Path,
+) -> Result<(RecommendedWatcher, UnboundedReceiver<PathBuf>), AnyError> {
+  let (sender, receiver) = mpsc::unbounded_channel();
+  let mut watcher: RecommendedWatcher = Watcher::new(
+    move |res: Result<NotifyEvent, NotifyError>| {
+      let Ok(event) = res else {
+        return;
+      };
+      let path = match event.kind {
+        EventKind::Create(_)
+        | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+          event.paths.first()
+        }
+        // the paths are the old and then the new one
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+          event.paths.get(1)
+        }
+        _ => None,
+      };
+      if let Some(path) = path.filter(|path| path.is_file()) {
+        let _ = sender.send(path.clone());
+      }
+    },
+    Default::default(),
+  )?;
+  watcher.watch(dir, RecursiveMode::NonRecursive)?;
+  Ok((watcher, receiver))
+}
+
 fn add_paths_to_watcher(watcher: &mut RecommendedWatcher, paths: &[PathBuf]) {
   // Ignore any error e.g. `PathNotFound`
   for path in paths {
@@ -262,7 +262,9 @@ pub struct FileCollector<TFilter: Fn(WalkEntry) -> bool> {
   ignore_node_modules: bool,
   ignore_vendor_folder: bool,
   vendor_folder: Option<PathBuf>,
+  ignored_paths: Option<PathOrPatternSet>,
   use_gitignore: bool,
+  max_depth: Option<usize>,
 }
 
 impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
@@ -273,7 +275,9 @@ impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
       ignore_node_modules: false,
       ignore_vendor_folder: false,
       vendor_folder: None,
+      ignored_paths: None,
       use_gitignore: false,
+      max_depth: None,
     }
   }
 
@@ -292,6 +296,16 @@ impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
     self
   }
 
+  /// Ignores the directories and files matched by the patterns, like the
+  /// vendor folder, unless they're the base of a walk.
+  pub fn set_ignored_paths(
+    mut self,
+    ignored_paths: Option<PathOrPatternSet>,
+  ) -> Self {
+    self.ignored_paths = ignored_paths;
+    self
+  }
+
   pub fn ignore_git_folder(mut self) -> Self {
     self.ignore_git_folder = true;
     self
@@ -302,6 +316,13 @@ impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
     self
   }
 
+  /// Limits how many directories deep the collector descends below each
+  /// base, where a depth of 0 only collects the base's direct files.
+  pub fn set_max_depth(mut self, max_depth: Option<usize>) -> Self {
+    self.max_depth = max_depth;
+    self
+  }
+
   pub fn collect_file_patterns(
     &self,
     file_patterns: FilePatterns,
@@ -366,9 +387,13 @@ impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
     for file_patterns in file_patterns_by_base {
       let file = normalize_path(&file_patterns.base);
       // use an iterator in order to minimize the number of file system operations
-      let mut iterator = WalkDir::new(&file)
-        .follow_links(false) // the default, but be explicit
-        .into_iter();
+      // following links is the default, but be explicit
+      let mut walk_dir = WalkDir::new(&file).follow_links(false);
+      if let Some(max_depth) = self.max_depth {
+        // the walk's depth 0 is the base itself
+        walk_dir = walk_dir.max_depth(max_depth + 1);
+      }
+      let mut iterator = walk_dir.into_iter();
       loop {
         let e = match iterator.next() {
           None => break,
@@ -402,11 +427,13 @@ impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
           if should_ignore_dir || !visited_paths.insert(path.clone()) {
             iterator.skip_current_dir();
           }
-        } else if (self.file_filter)(WalkEntry {
-          path: &path,
-          file_type: &file_type,
-          patterns: &file_patterns,
-        }) && visited_paths.insert(path.clone())
+        } else if (file == path || !self.is_ignored_path(&path))
+          && (self.file_filter)(WalkEntry {
+            path: &path,
+            file_type: &file_type,
+            patterns: &file_patterns,
+          })
+          && visited_paths.insert(path.clone())
         {
           target_files.push(path);
         }
@@ -415,21 +442,35 @@ impl<TFilter: Fn(WalkEntry) -> bool> FileCollector<TFilter> {
     Ok(target_files)
   }
 
+  /// The names of the directories that are skipped wherever they are.
+  pub fn ignored_dir_names(&self) -> impl Iterator<Item = &'static str> {
+    [
+      ("node_modules", self.ignore_node_modules),
+      ("vendor", self.ignore_vendor_folder),
+      (".git", self.ignore_git_folder),
+    ]
+    .into_iter()
+    .filter_map(|(name, is_ignored)| is_ignored.then_some(name))
+  }
+
   fn is_ignored_dir(&self, path: &Path) -> bool {
     path
       .file_name()
       .map(|dir_name| {
         let dir_name = dir_name.to_string_lossy().to_lowercase();
-        let is_ignored_file = match dir_name.as_str() {
-          "node_modules" => self.ignore_node_modules,
-          "vendor" => self.ignore_vendor_folder,
-          ".git" => self.ignore_git_folder,
-          _ => false,
-        };
-        is_ignored_file
+        self.ignored_dir_names().any(|name| name == dir_name)
       })
       .unwrap_or(false)
       || self.is_vendor_folder(path)
+      || self.is_ignored_path(path)
+  }
+
+  fn is_ignored_path(&self, path: &Path) -> bool {
+    self
+      .ignored_paths
+      .as_ref()
+      .map(|ignored_paths| ignored_paths.matches_path(path))
+      .unwrap_or(false)
   }
 
   fn is_vendor_folder(&self, path: &Path) -> bool {
@@ -1008,6 +1049,24 @@ mod tests {
       .collect::<Vec<_>>();
     file_names.sort();
     assert_eq!(file_names, expected);
+
+    // test limiting the depth to the base's direct files
+    let file_patterns = FilePatterns {
+      base: root_dir_path.to_path_buf(),
+      include: None,
+      exclude: PathOrPatternSet::new(vec![]),
+    };
+    let result = file_collector
+      .set_max_depth(Some(0))
+      .collect_file_patterns(file_patterns)
+      .unwrap();
+    let expected = ["a.ts", "b.js", "c.tsx", "d.jsx"];
+    let mut file_names = result
+      .into_iter()
+      .map(|r| r.file_name().unwrap().to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    file_names.sort();
+    assert_eq!(file_names, expected);
   }
 
   #[test]
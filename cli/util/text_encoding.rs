@@ -35,10 +35,25 @@ pub fn code_without_source_map(mut code: ModuleCodeString) -> ModuleCodeString {
   code
 }
 
+/// Converts CRLF and lone CR line endings to LF.
+pub fn normalize_line_endings(text: String) -> String {
+  if !text.contains('\r') {
+    return text;
+  }
+  text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_normalize_line_endings() {
+    assert_eq!(normalize_line_endings("a\nb\n".to_string()), "a\nb\n");
+    assert_eq!(normalize_line_endings("a\r\nb\r\n".to_string()), "a\nb\n");
+    assert_eq!(normalize_line_endings("a\rb\r\n\r".to_string()), "a\nb\n\n");
+  }
+
   #[test]
   fn test_source_without_source_map() {
     run_test("", "");
@@ -1,5 +1,6 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use deno_core::serde_json;
 use deno_core::serde_json::json;
 use test_util::assert_contains;
 use test_util::assert_not_contains;
@@ -24,6 +25,12 @@ itest!(quiet {
   exit_code: 1,
 });
 
+itest!(limit {
+  args: "lint --limit=1 lint/without_config/file1.js",
+  output: "lint/expected_limit.out",
+  exit_code: 1,
+});
+
 itest!(json {
   args:
     "lint --json lint/without_config/file1.js lint/without_config/file2.ts lint/without_config/ignored_file.ts lint/without_config/malformed.js",
@@ -121,6 +128,30 @@ itest!(lint_with_malformed_config2 {
   exit_code: 1,
 });
 
+itest!(lint_with_banned_imports {
+  args: "lint --config lint/banned_imports/deno.json lint/banned_imports/",
+  output: "lint/banned_imports/main.out",
+  exit_code: 1,
+});
+
+itest!(lint_with_rule_messages {
+  args: "lint --config lint/rule_messages/deno.json lint/rule_messages/",
+  output: "lint/rule_messages/main.out",
+  exit_code: 1,
+});
+
+itest!(lint_hide_tags {
+  args: "lint --hide-tags=recommended lint/without_config/file1.js",
+  output_str: Some("Checked 1 file\n"),
+  exit_code: 0,
+});
+
+itest!(lint_jsx_in_js {
+  args: "lint --config lint/jsx_in_js/deno.json lint/jsx_in_js/",
+  output_str: Some("Checked 1 file\n"),
+  exit_code: 0,
+});
+
 #[test]
 fn lint_with_glob_config() {
   let context = TestContextBuilder::new().cwd("lint").build();
@@ -277,3 +308,2103 @@ fn opt_out_top_level_exclude_via_lint_unexclude() {
   assert_contains!(output, "excluded.ts");
   assert_not_contains!(output, "actually_excluded.ts");
 }
+
+#[test]
+fn lint_compact_absolute_paths() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("const a = 1;");
+
+  let output = context
+    .new_command()
+    .args("lint --compact --absolute main.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "main.ts: line 1, col 7");
+  assert_not_contains!(output, "file://");
+}
+
+#[test]
+fn lint_include_extra() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write_json(&json!({
+    "lint": {
+      "include": ["src/"],
+      "exclude": ["scripts/excluded.ts"]
+    }
+  }));
+  temp_dir.join("src").create_dir_all();
+  temp_dir.join("src/main.ts").write("const a = 1;");
+  temp_dir.join("scripts").create_dir_all();
+  temp_dir.join("scripts/build.ts").write("const b = 1;");
+  temp_dir.join("scripts/excluded.ts").write("const c = 1;");
+
+  let output = context
+    .new_command()
+    .args("lint --include-extra=scripts/")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "main.ts");
+  assert_contains!(output, "build.ts");
+  assert_not_contains!(output, "excluded.ts");
+  assert_contains!(output, "Checked 2 files");
+}
+
+#[test]
+fn lint_pretty_reporter_options_combined() {
+  let context = TestContextBuilder::new().cwd("lint").build();
+  let output = context
+    .new_command()
+    .args("lint --config Deno.jsonc --relative --group-by=rule --no-snippets --no-hints with_config/")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "ban-untagged-todo (1 problem)");
+  assert_contains!(output, "no-unused-vars (1 problem)");
+  assert_contains!(output, " with_config/a.ts:1:1");
+  assert_not_contains!(output, "hint:");
+  assert_not_contains!(output, "// TODO: foo");
+  assert_contains!(output, "Found 2 problems in 1 file");
+}
+
+#[test]
+fn lint_relative_paths_in_every_reporter() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("src").create_dir_all();
+  temp_dir.join("src/a.ts").write("debugger;\n");
+  let temp_dir_path = temp_dir.to_string_lossy().to_string();
+
+  let output = context.new_command().args("lint --relative src/").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, " src/a.ts:1:1");
+  assert_not_contains!(output, &temp_dir_path);
+
+  let output = context
+    .new_command()
+    .args("lint --relative --tap src/")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.stdout(), "not ok 1 - src/a.ts");
+  assert_not_contains!(output.stdout(), &temp_dir_path);
+
+  let output = context
+    .new_command()
+    .args("lint --relative --ndjson src/")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let line = output.stdout().lines().next().unwrap();
+  let diagnostic: serde_json::Value = serde_json::from_str(line).unwrap();
+  assert_eq!(diagnostic["filename"], "src/a.ts");
+}
+
+#[test]
+fn lint_eval() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let output = context
+    .new_command()
+    .args_vec(["lint", "--eval=let x: any = 1; console.log(x);"])
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "no-explicit-any");
+  assert_contains!(output, "$deno$eval.ts");
+
+  let output = context
+    .new_command()
+    .args_vec(["lint", "--stdin-ext=tsx", "--eval=console.log(<div />);"])
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Checked 1 file");
+}
+
+#[test]
+fn lint_json_absolute_paths() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("const a = 1;");
+
+  let output = context
+    .new_command()
+    .args("lint --json --absolute main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let output = output.stdout();
+  let json: serde_json::Value = serde_json::from_str(output).unwrap();
+  let filename = json["diagnostics"][0]["filename"].as_str().unwrap();
+  assert!(!filename.starts_with("file://"));
+  assert!(std::path::Path::new(filename).is_absolute());
+  assert!(filename.ends_with("main.ts"));
+}
+
+#[test]
+fn lint_ndjson() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("a.ts").write("debugger;\ndebugger;\n");
+  temp_dir.join("b.ts").write("const = ;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --ndjson")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let lines = output
+    .stdout()
+    .lines()
+    .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(lines.len(), 3);
+  let diagnostics = lines
+    .iter()
+    .filter(|line| line["code"] == "no-debugger")
+    .collect::<Vec<_>>();
+  assert_eq!(diagnostics.len(), 2);
+  // the diagnostics of a file are in order
+  assert_eq!(diagnostics[0]["range"]["start"]["line"], 1);
+  assert_eq!(diagnostics[1]["range"]["start"]["line"], 2);
+  let error = lines.iter().find(|line| line["type"] == "error").unwrap();
+  assert_contains!(error["file_path"].as_str().unwrap(), "b.ts");
+  assert_eq!(error["kind"], "lint");
+}
+
+#[test]
+fn lint_file_problem_threshold() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("a.ts")
+    .write("debugger;\ndebugger;\ndebugger;\n");
+  temp_dir.join("b.ts").write("debugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --file-problem-threshold=2")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "a.ts has 3 problems; consider refactoring");
+  assert_not_contains!(output, "b.ts has");
+  assert_contains!(output, "Found 4 problems");
+}
+
+#[test]
+fn lint_no_error_on_issues() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --no-error-on-issues main.ts")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "no-debugger");
+
+  let output = context
+    .new_command()
+    .args("lint --no-error-on-issues -")
+    .stdin_text("debugger;\n")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "no-debugger");
+}
+
+#[test]
+fn lint_ignore_file() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join(".denolintignore")
+    .write("# generated code\n/gen/\n*.pb.ts\n!src/keep.pb.ts\n");
+  temp_dir.join("main.ts").write("debugger;\n");
+  temp_dir.join("gen").create_dir_all();
+  temp_dir.join("gen/a.ts").write("debugger;\n");
+  temp_dir.join("src").create_dir_all();
+  temp_dir.join("src/b.pb.ts").write("debugger;\n");
+  temp_dir.join("src/keep.pb.ts").write("debugger;\n");
+
+  let output = context.new_command().args("lint").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "main.ts");
+  assert_not_contains!(output, "a.ts");
+  assert_not_contains!(output, "b.pb.ts");
+  // included again by the negated glob after the one that excludes it
+  assert_contains!(output, "keep.pb.ts");
+  assert_contains!(output, "Checked 2 files");
+}
+
+#[test]
+fn lint_fix_only() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\ndebugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --fix --fix-only=prefer-const main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "no-debugger");
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "const a = 1;\nconsole.log(a);\ndebugger;\n"
+  );
+
+  // there's nothing left to fix with the allowed rules
+  let output = context
+    .new_command()
+    .args("lint --fix --fix-only=prefer-const main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-debugger]: `debugger` statement is not allowed
+ --> [WILDCARD]main.ts:3:1
+[WILDCARD]
+Found 1 problem[WILDLINE]
+Checked 1 file
+",
+  );
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "const a = 1;\nconsole.log(a);\ndebugger;\n"
+  );
+}
+
+#[test]
+fn lint_fix_dry_run() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\n");
+
+  let output = context
+    .new_command()
+    .args("lint --fix --dry-run main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.assert_stdout_matches_text(
+    "--- a/main.ts
++++ b/main.ts
+@@ -1,2 +1,2 @@
+-let a = 1;
++const a = 1;
+ console.log(a);
+",
+  );
+  output.assert_stderr_matches_text("Checked 1 file\n");
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "let a = 1;\nconsole.log(a);\n"
+  );
+
+  // nothing would change once the file is fixed
+  context
+    .new_command()
+    .args("lint --fix main.ts")
+    .run()
+    .assert_exit_code(0)
+    .assert_matches_text(
+      "Fixed 1 problem automatically, 0 remain.\nChecked 1 file\n",
+    );
+  let output = context
+    .new_command()
+    .args("lint --fix --dry-run main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(0);
+  output.assert_stdout_matches_text("");
+  output.assert_stderr_matches_text("Checked 1 file\n");
+
+  // the line endings that would be normalized fail it without being written
+  temp_dir
+    .join("main.ts")
+    .write("const a = 1;\r\nconsole.log(a);\r\n");
+  let output = context
+    .new_command()
+    .args("lint --fix --dry-run --normalize-eol main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.assert_stdout_matches_text("");
+  output.assert_stderr_matches_text(
+    "Would normalize the line endings of '[WILDLINE]main.ts'.\nChecked 1 file\n",
+  );
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "const a = 1;\r\nconsole.log(a);\r\n"
+  );
+}
+
+#[test]
+fn lint_timing() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\n");
+
+  let output = context
+    .new_command()
+    .args("lint --json --timing main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  // the timings don't break the JSON output
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  assert_eq!(json["diagnostics"].as_array().unwrap().len(), 1);
+  let stderr = output.stderr();
+  assert_contains!(stderr, "| Time (ms) | Relative");
+  assert_contains!(stderr, "prefer-const");
+  assert_contains!(stderr, "Slowest files:");
+  assert_contains!(stderr, "main.ts");
+}
+
+#[test]
+fn lint_stdin_file_list() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("src").create_dir_all();
+  temp_dir.join("src/a.ts").write("debugger;\n");
+  temp_dir.join("src/b.ts").write("debugger;\n");
+  temp_dir.join("README.md").write("# readme\n");
+
+  let output = context
+    .new_command()
+    .args("lint --stdin-file-list")
+    .stdin_text("src/a.ts\n\nREADME.md\n")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "a.ts");
+  assert_not_contains!(output, "b.ts");
+  assert_contains!(
+    output,
+    "Skipped 'README.md' because it isn't a script file."
+  );
+  assert_contains!(output, "Checked 1 file");
+
+  let output = context
+    .new_command()
+    .args("lint --stdin-file-list")
+    .stdin_text("missing.ts\n")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "Failed resolving 'missing.ts'.");
+}
+
+#[test]
+fn lint_checkstyle() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("const a = 1;\ndebugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --checkstyle --absolute main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.skip_stderr_check();
+  let stdout = output.stdout();
+  assert_contains!(stdout, "<checkstyle version=\"4.3\">\n  <file name=\"");
+  assert_contains!(stdout, "main.ts\">");
+  assert_contains!(
+    stdout,
+    "<error line=\"1\" column=\"7\" severity=\"error\" message=\"`a` is never used\" source=\"no-unused-vars\"/>"
+  );
+  assert_contains!(stdout, "<error line=\"2\" column=\"1\" severity=\"error\"");
+  assert_contains!(stdout, "</checkstyle>");
+}
+
+#[test]
+fn lint_stats() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("debugger;\ndebugger;\nconst a = 1;\n");
+
+  let output = context.new_command().args("lint --stats main.ts").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "Found 3 problems in 1 file");
+  assert_contains!(output, "\n      2 no-debugger\n      1 no-unused-vars\n");
+
+  let output = context
+    .new_command()
+    .args("lint --stats --quiet main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "      2 no-debugger");
+}
+
+#[test]
+fn lint_fix_max_iterations() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\n");
+
+  // a single pass fixes everything, which isn't reported as reaching the
+  // maximum
+  let output = context
+    .new_command()
+    .args("lint --fix --fix-max-iterations=0 main.ts")
+    .run();
+  output.assert_exit_code(0);
+  assert_not_contains!(output.combined_output(), "fix iterations");
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "const a = 1;\nconsole.log(a);\n"
+  );
+}
+
+#[test]
+fn lint_fail_on_fix_nonconvergence() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\nexport const b: any = a;\n");
+
+  // fixes that converge don't fail the run, and the problems that can't be
+  // fixed are still reported
+  let output = context
+    .new_command()
+    .args("lint --fix --fail-on-fix-nonconvergence main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-explicit-any]: [WILDCARD]
+Found 1 problem
+Fixed 1 problem automatically, 1 remain.
+Checked 1 file
+",
+  );
+  assert_not_contains!(output.combined_output(), "fix iterations");
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "const a = 1;\nconsole.log(a);\nexport const b: any = a;\n"
+  );
+}
+
+#[test]
+fn lint_concurrency() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  for name in ["a.ts", "b.ts", "c.ts", "d.ts"] {
+    temp_dir.join(name).write("debugger;\n");
+  }
+
+  // the files are linted one after another, so they're reported in order
+  let output = context
+    .new_command()
+    .args("lint --concurrency=1 --compact a.ts b.ts c.ts d.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "Found 4 problems");
+  let positions = ["a.ts", "b.ts", "c.ts", "d.ts"]
+    .map(|name| output.find(&format!("{name}: line 1, col 1")).unwrap());
+  assert!(positions.windows(2).all(|w| w[0] < w[1]), "{output}");
+}
+
+#[test]
+fn lint_format_relative_paths() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --compact --format=relative-paths main.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(
+    output,
+    "main.ts: line 1, col 1 - `debugger` statement is not allowed"
+  );
+  assert_not_contains!(output, "file://");
+
+  let output = context
+    .new_command()
+    .args("lint --json --format=relative-paths main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.skip_stderr_check();
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  assert_eq!(json["diagnostics"][0]["filename"], "main.ts");
+}
+
+#[test]
+fn lint_tap() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("a.ts").write("debugger;\n");
+  temp_dir.join("b.ts").write("console.log(1);\n");
+  std::fs::write(temp_dir.join("broken.ts"), [0xff, 0xfe]).unwrap();
+
+  let output = context
+    .new_command()
+    .args("lint --tap a.ts b.ts broken.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.skip_stderr_check();
+  let stdout = output.stdout();
+  assert_contains!(stdout, "TAP version 13\n1..3\nnot ok 1 - file:///");
+  assert_contains!(
+    stdout,
+    "a.ts
+  ---
+  problems:
+    - code: \"no-debugger\"
+      message: \"`debugger` statement is not allowed\"
+      line: 1
+      column: 1
+  ...
+ok 2 - file:///"
+  );
+  assert_contains!(stdout, "b.ts\nnot ok 3 - file:///");
+  assert_contains!(stdout, "broken.ts # ERROR\n");
+}
+
+#[test]
+fn lint_ignore_until() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("future.ts")
+    .write("// deno-lint-ignore-until 2999-01-01 no-debugger\ndebugger;\n");
+  temp_dir
+    .join("expired.ts")
+    .write("// deno-lint-ignore-until 2000-01-01 no-debugger\ndebugger;\n");
+  temp_dir
+    .join("malformed.ts")
+    .write("// deno-lint-ignore-until 2000-13-01 no-debugger\ndebugger;\n");
+
+  context
+    .new_command()
+    .args("lint future.ts")
+    .run()
+    .assert_exit_code(0)
+    .skip_output_check();
+
+  let output = context
+    .new_command()
+    .args("lint --json expired.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.skip_stderr_check();
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  let codes = json["diagnostics"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|d| d["code"].as_str().unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(codes, vec!["expired-ignore-directive", "no-debugger"]);
+
+  let output = context.new_command().args("lint malformed.ts").run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Invalid date '2000-13-01' in the deno-lint-ignore-until directive on line 1."
+  );
+
+  // a file without problems isn't cached as passing either
+  temp_dir.join("clean.ts").write(
+    "// deno-lint-ignore-until 2000-13-01 no-debugger\nconsole.log(1);\n",
+  );
+  for _ in 0..2 {
+    let output = context.new_command().args("lint clean.ts").run();
+    output.assert_exit_code(1);
+    assert_contains!(output.combined_output(), "Invalid date '2000-13-01'");
+  }
+
+  // the text of strings and templates isn't a directive
+  temp_dir.join("string.ts").write(
+    "console.log(`\n// deno-lint-ignore-until 2000-13-01\n`);\nconsole.log(\"// deno-lint-ignore-until\");\n",
+  );
+  context
+    .new_command()
+    .args("lint string.ts")
+    .run()
+    .assert_exit_code(0)
+    .skip_output_check();
+
+  // the fixes of the ignored problems aren't applied
+  let ignored_text =
+    "// deno-lint-ignore-until 2999-01-01 prefer-const\nlet a = 1;\nconsole.log(a);\n";
+  temp_dir.join("fix.ts").write(ignored_text);
+  context
+    .new_command()
+    .args("lint --fix fix.ts")
+    .run()
+    .assert_exit_code(0)
+    .skip_output_check();
+  assert_eq!(temp_dir.join("fix.ts").read_to_string(), ignored_text);
+}
+
+#[test]
+fn lint_by_rule() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("a.ts")
+    .write("debugger;\nlet a = 1;\nconsole.log(a);\n");
+  temp_dir.join("b.ts").write("debugger;\n");
+
+  context
+    .new_command()
+    .args("lint --by-rule --format=relative-paths a.ts b.ts")
+    .run()
+    .assert_matches_text(
+      "no-debugger (2 problems)
+  a.ts:1:1
+  b.ts:1:1
+
+prefer-const (1 problem)
+  a.ts:2:5
+
+Found 3 problems in 2 files[WILDCARD]
+Checked 2 files
+",
+    )
+    .assert_exit_code(1);
+}
+
+#[test]
+fn lint_no_slow_types_quiet() {
+  let context = TestContextBuilder::new()
+    .use_http_server()
+    .use_temp_cwd()
+    .build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write_json(&json!({
+    "name": "@pkg/pkg",
+    "version": "1.0.0",
+    "exports": "./mod.ts"
+  }));
+  temp_dir.join("mod.ts").write(
+    "export { printHello } from \"http://localhost:4545/subdir/print_hello.ts\";\nexport function add(a: number, b: number) {\n  return a + b;\n}\n",
+  );
+
+  let output = context.new_command().args("lint --quiet").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  // the graph is loaded without logging its progress
+  assert_not_contains!(output, "Download");
+  assert_contains!(output, "error[no-slow-types]");
+  assert_not_contains!(output, "Checked");
+}
+
+#[test]
+fn lint_unstable_md() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;\n");
+  temp_dir.join("README.md").write(
+    "# Example\n\n```ts\nconsole.log(1);\n  debugger;\n```\n\n```ts ignore\ndebugger;\n```\n\n```sh\ndebugger;\n```\n",
+  );
+
+  let output = context
+    .new_command()
+    .args("lint --compact --format=relative-paths --unstable-md")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(
+    output,
+    "README.md: line 5, col 3 - `debugger` statement is not allowed"
+  );
+  assert_contains!(output, "Found 1 problem");
+  assert_contains!(output, "Checked 2 files");
+
+  // Markdown files aren't linted without the flag
+  context
+    .new_command()
+    .args("lint")
+    .run()
+    .assert_matches_text("Checked 1 file\n")
+    .assert_exit_code(0);
+
+  // a block that doesn't parse doesn't hide the problems of the others
+  temp_dir
+    .join("broken.md")
+    .write("```ts\nconst = 1;\n```\n\n```ts\ndebugger;\n```\n");
+  let output = context
+    .new_command()
+    .args("lint --compact --format=relative-paths --unstable-md broken.md")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "broken.md: line 2, col ");
+  assert_contains!(output, "(code-block-syntax-error)");
+  assert_contains!(
+    output,
+    "broken.md: line 6, col 1 - `debugger` statement is not allowed"
+  );
+  assert_contains!(output, "Found 2 problems");
+}
+
+#[test]
+fn lint_cache_path() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --timing --cache-path=lint.db main.ts")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Slowest files:");
+  assert!(temp_dir.join("lint.db").exists());
+
+  // the second run skips the file cached in lint.db, so no file is timed
+  let output = context
+    .new_command()
+    .args("lint --timing --cache-path=lint.db main.ts")
+    .run();
+  output.assert_exit_code(0);
+  assert_not_contains!(output.combined_output(), "Slowest files:");
+  assert_contains!(output.combined_output(), "Checked 1 file");
+  // the default cache of the deno dir isn't used
+  assert!(!context
+    .deno_dir()
+    .path()
+    .join("lint_incremental_cache_v1")
+    .exists());
+
+  // main.ts was cached as clean in lint.db only
+  temp_dir.join("main.ts").write("const a = 1;\n");
+  let output = context
+    .new_command()
+    .args("lint --fail-on-regressions main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "previously clean");
+  let output = context
+    .new_command()
+    .args("lint --fail-on-regressions --cache-path=lint.db main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "previously clean");
+}
+
+#[test]
+fn lint_strict_rules() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write_json(&json!({
+    "lint": {
+      "rules": {
+        "exclude": ["no-debuger"]
+      }
+    }
+  }));
+  temp_dir.join("main.ts").write("export const a = 1;\n");
+
+  let output = context.new_command().args("lint").run();
+  output.assert_exit_code(0);
+  let output = output.combined_output();
+  assert_contains!(
+    output,
+    "Unknown lint rules in the include or exclude lists:\n  no-debuger (did you mean 'no-debugger'?)"
+  );
+  assert_contains!(output, "Checked 1 file");
+
+  let output = context.new_command().args("lint --strict-rules").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "no-debuger (did you mean 'no-debugger'?)");
+  assert_not_contains!(output, "Checked");
+}
+
+#[test]
+fn lint_banned_imports_stdin() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write_json(&json!({
+    "lint": { "bannedImports": ["lodash"] }
+  }));
+
+  let output = context
+    .new_command()
+    .args("lint -")
+    .stdin_text("import \"lodash/fp\";\n")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-banned-imports]: Importing 'lodash/fp' is not allowed
+ --> [WILDCARD]$deno$stdin.ts:1:8
+[WILDCARD]
+Found 1 problem
+Checked 1 file
+",
+  );
+
+  let output = context
+    .new_command()
+    .args_vec([
+      "lint",
+      "--hide-tags=no-banned-imports",
+      "--eval=import 'lodash';",
+    ])
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 1 file\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn lint_edit() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("a.ts")
+    .write("export const a = 1;\nconst b: any = 1;\n");
+  temp_dir.join("b.ts").write("const c = 1;\n");
+
+  // the editor is given the line of the first problem and the file
+  let output = context
+    .new_command()
+    .env("EDITOR", "echo")
+    .args("lint --edit a.ts b.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.assert_stdout_matches_text("+2 [WILDLINE]a.ts\n");
+  output.assert_stderr_matches_text(
+    "[WILDCARD]Checked 2 files
+Opening [WILDLINE]a.ts:2:7
+",
+  );
+
+  temp_dir.join("a.ts").write("export const a = 1;\n");
+  let output = context
+    .new_command()
+    .env("EDITOR", "echo")
+    .args("lint --edit a.ts")
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 1 file\nNo problems to edit.\n");
+}
+
+#[test]
+fn lint_duplicate_paths() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --stdin-file-list")
+    .stdin_text("main.ts\n./main.ts\n")
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 1 file\n");
+
+  let output = context
+    .new_command()
+    .args("lint --verbose --stdin-file-list")
+    .stdin_text("main.ts\n./main.ts\n")
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text(
+    "Warning '[WILDLINE]main.ts' was included more than once and is only linted once.
+[WILDCARD]Checked 1 file
+",
+  );
+}
+
+#[test]
+fn lint_auto_fix_safe_then_report() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconst b: any = a;\nconsole.log(b);\n");
+
+  let output = context
+    .new_command()
+    .args("lint --auto-fix-safe-then-report main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-explicit-any]: `any` type is not allowed
+ --> [WILDCARD]main.ts:2:10
+[WILDCARD]
+Found 1 problem
+Fixed 1 problem automatically, 1 remain.
+Checked 1 file
+",
+  );
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "const a = 1;\nconst b: any = a;\nconsole.log(b);\n"
+  );
+}
+
+#[test]
+fn lint_json_suggestions() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\nconst a = {};\n");
+
+  let output = context
+    .new_command()
+    .args("lint --json main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  let diagnostics = json["diagnostics"].as_array().unwrap();
+  let no_debugger = diagnostics
+    .iter()
+    .find(|d| d["code"] == "no-debugger")
+    .unwrap();
+  let change = &no_debugger["suggestions"][0]["changes"][0];
+  assert_eq!(change["range"]["start"]["bytePos"], 0);
+  assert_eq!(change["newText"], "");
+  // left out for diagnostics without fixes
+  let no_unused_vars = diagnostics
+    .iter()
+    .find(|d| d["code"] == "no-unused-vars")
+    .unwrap();
+  assert!(no_unused_vars.get("suggestions").is_none());
+}
+
+#[test]
+fn lint_json_sort_by_code() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("let a = 1;\ndebugger;\n");
+
+  let codes = |args: &str| {
+    let output = context.new_command().args(args).split_output().run();
+    output.assert_exit_code(1);
+    let json: serde_json::Value =
+      serde_json::from_str(output.stdout()).unwrap();
+    json["diagnostics"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|d| d["code"].as_str().unwrap().to_string())
+      .collect::<Vec<_>>()
+  };
+  let by_position = codes("lint --json main.ts");
+  assert_eq!(by_position.last().unwrap(), "no-debugger");
+  let by_code = codes("lint --json --json-sort=code main.ts");
+  let mut sorted = by_code.clone();
+  sorted.sort();
+  assert_eq!(by_code, sorted);
+  assert_eq!(by_code[0], "no-debugger");
+}
+
+#[test]
+fn lint_generated_files() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("gen").create_dir_all();
+  temp_dir
+    .join("gen/schema.ts")
+    .write("const unused = 1;\ndebugger;\n");
+
+  let output = context.new_command().args("lint --generated=gen/").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "no-debugger");
+  assert_not_contains!(output, "no-unused-vars");
+
+  let output = context
+    .new_command()
+    .args("lint --generated=gen/ --skip-generated")
+    .run();
+  output.assert_exit_code(0);
+  assert_not_contains!(output.combined_output(), "no-debugger");
+}
+
+#[test]
+fn lint_response_file() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("a.ts").write("export const a = 1;");
+  temp_dir.join("b.ts").write("export const b = 1;");
+  temp_dir.join("c.ts").write("const c = 1;");
+  temp_dir.join("my dir").create_dir_all();
+  temp_dir.join("my dir/d.ts").write("export const d = 1;");
+  temp_dir.join("@scope").create_dir_all();
+  temp_dir.join("@scope/e.ts").write("export const e = 1;");
+  temp_dir
+    .join("files.txt")
+    .write("a.ts\nb.ts\n\"my dir/d.ts\"\n@@scope/e.ts\n");
+
+  let output = context.new_command().args("lint @files.txt").run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 4 files\n");
+
+  let output = context.new_command().args("lint @missing.txt").run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Failed reading response file 'missing.txt'."
+  );
+}
+
+#[test]
+fn lint_third_party() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write(
+    r#"{ "lint": { "thirdParty": ["vendor2/", "packages/**/third_party/**"] } }"#,
+  );
+  temp_dir.join("main.ts").write("export const a = 1;");
+  temp_dir.join("vendor2").create_dir_all();
+  temp_dir.join("vendor2/lib.ts").write("debugger;");
+  temp_dir.join("packages/ui/third_party").create_dir_all();
+  temp_dir
+    .join("packages/ui/third_party/lib.ts")
+    .write("debugger;");
+
+  let output = context.new_command().args("lint").run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Checked 1 file");
+
+  // explicitly specified third party files are still linted
+  let output = context.new_command().args("lint vendor2/lib.ts").run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "no-debugger");
+}
+
+#[test]
+fn lint_normalize_eol() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("main.ts")
+    .write("export const a = 1;\r\nexport const b: any = 1;\r\n");
+
+  let output = context
+    .new_command()
+    .args("lint --json main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.stdout(), r#""bytePos": 37"#);
+
+  // not replayed from the cache of the previous run
+  let output = context
+    .new_command()
+    .args("lint --json --normalize-eol main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  // the offset in the normalized text, where the line ending is one byte
+  assert_contains!(output.stdout(), r#""bytePos": 36"#);
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "export const a = 1;\r\nexport const b: any = 1;\r\n"
+  );
+
+  let output = context
+    .new_command()
+    .args("lint --fix --normalize-eol main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-explicit-any]: `any` type is not allowed
+ --> [WILDCARD]main.ts:2:17
+[WILDCARD]
+Found 1 problem
+Checked 1 file
+",
+  );
+  // written even though no problem was fixed
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "export const a = 1;\nexport const b: any = 1;\n"
+  );
+}
+
+#[test]
+fn lint_budgets() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("deno.json")
+    .write(r#"{ "lint": { "budgets": { "no-debugger": 1 } } }"#);
+  temp_dir.join("main.ts").write("let a = 1;\ndebugger;\n");
+
+  // prefer-const has no budget, so it doesn't fail the run
+  let output = context.new_command().args("lint").run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "prefer-const");
+
+  temp_dir.join("other.ts").write("debugger;\n");
+  let output = context.new_command().args("lint").run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "1 rule is over budget:");
+  assert_contains!(
+    output.combined_output(),
+    "  no-debugger: 2 problems, budget 1"
+  );
+
+  // the problems of a file that can't be parsed are unknown
+  temp_dir.join("other.ts").write("let a = ;\n");
+  let output = context.new_command().args("lint").run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "Error linting:");
+
+  let output = context.new_command().args("lint --max-warnings=10").run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "--max-warnings can't be used with the \"budgets\" lint configuration."
+  );
+}
+
+#[test]
+fn lint_rule_pack() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("deno.json")
+    .write(r#"{ "lint": { "rules": { "exclude": ["no-debugger"] } } }"#);
+  temp_dir.join("pack.json").write(
+    r#"{ "exclude": ["prefer-const"], "severities": { "no-debugger": "warning" } }"#,
+  );
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\ndebugger;\n");
+
+  // the pack's exclude overrides the one of the config file, while its
+  // warnings are reported without failing the run
+  let output = context
+    .new_command()
+    .args("lint --rule-pack=pack.json main.ts")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "no-debugger");
+  assert_not_contains!(output.combined_output(), "prefer-const");
+
+  // the flags take precedence over the pack
+  let output = context
+    .new_command()
+    .args("lint --rule-pack=pack.json --rules-include=prefer-const main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "prefer-const");
+
+  temp_dir
+    .join("bad.json")
+    .write(r#"{ "include": ["no-such-rule"] }"#);
+  let output = context
+    .new_command()
+    .args("lint --rule-pack=bad.json main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Unknown rule 'no-such-rule' in \"include\"."
+  );
+}
+
+#[test]
+fn lint_rules_flags_merge_with_config() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write(
+    r#"{ "lint": { "rules": { "exclude": ["no-debugger", "prefer-const"] } } }"#,
+  );
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\ndebugger;\n");
+
+  // the config file still excludes prefer-const, while the flag includes
+  // the no-debugger rule it excludes
+  let output = context
+    .new_command()
+    .args("lint --rules-include=no-debugger --rules-exclude=no-eval main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "no-debugger");
+  assert_not_contains!(output.combined_output(), "prefer-const");
+}
+
+#[test]
+fn lint_file_results() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("a.ts").write("debugger;\n");
+  temp_dir.join("b.ts").write("console.log(1);\n");
+
+  // the second run skips the cached clean file, which is still recorded
+  for _ in 0..2 {
+    let output = context
+      .new_command()
+      .args("lint --absolute --file-results=results.json")
+      .run();
+    output.assert_exit_code(1);
+    output.assert_matches_text(
+      "error[no-debugger]: `debugger` statement is not allowed
+ --> [WILDCARD]a.ts:1:1
+[WILDCARD]
+Found 1 problem[WILDLINE]
+Checked 2 files
+",
+    );
+    let results = temp_dir.join("results.json").read_to_string();
+    let results: Vec<serde_json::Value> =
+      serde_json::from_str(&results).unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results[0]["filename"].as_str().unwrap().ends_with("a.ts"));
+    assert_eq!(results[0]["status"], "fail");
+    assert_eq!(results[0]["problemCount"], 1);
+    assert!(results[1]["filename"].as_str().unwrap().ends_with("b.ts"));
+    assert_eq!(results[1]["status"], "pass");
+    assert_eq!(results[1]["problemCount"], 0);
+  }
+
+  // the problems of no-slow-types fail the file they're in
+  temp_dir.join("deno.json").write(
+    r#"{ "name": "@scope/pkg", "version": "1.0.0", "exports": "./b.ts" }"#,
+  );
+  temp_dir
+    .join("b.ts")
+    .write("export function f() {\n  return 1;\n}\n");
+  let output = context
+    .new_command()
+    .args("lint --file-results=results.json")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "error[no-slow-types]");
+  let results = temp_dir.join("results.json").read_to_string();
+  let results: Vec<serde_json::Value> = serde_json::from_str(&results).unwrap();
+  assert!(results[1]["filename"].as_str().unwrap().ends_with("b.ts"));
+  assert_eq!(results[1]["status"], "fail");
+  assert_eq!(results[1]["problemCount"], 1);
+}
+
+#[test]
+fn lint_ci_reporter() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\n");
+
+  let output = context
+    .new_command()
+    .env_remove("DENO_LINT_REPORTER")
+    .env_remove("GITHUB_ACTIONS")
+    .env_remove("GITLAB_CI")
+    .env("CI", "true")
+    .args("lint main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "main.ts: line 1, col 1 - `debugger` statement is not allowed (no-debugger)"
+  );
+
+  // the override wins over the detection
+  let output = context
+    .new_command()
+    .env("DENO_LINT_REPORTER", "gitlab")
+    .env("CI", "true")
+    .args("lint main.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "\"check_name\": \"no-debugger\"");
+  assert_contains!(output, "\"path\": \"main.ts\"");
+}
+
+#[test]
+fn lint_github_actions_reporter() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\n");
+  temp_dir.join("broken.ts").write("const = ;\n");
+
+  let output = context
+    .new_command()
+    .env("DENO_LINT_REPORTER", "github")
+    .args("lint main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.assert_stdout_matches_text(
+    "::error file=main.ts,line=1,col=1,endLine=1,endColumn=10,title=no-debugger::`debugger` statement is not allowed[WILDLINE]\n",
+  );
+  output.assert_stderr_matches_text("Found 1 problem\nChecked 1 file\n");
+
+  let output = context
+    .new_command()
+    .env("DENO_LINT_REPORTER", "github")
+    .args("lint broken.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  output.assert_stdout_matches_text(
+    "::error::Error linting [WILDLINE]broken.ts: [WILDLINE]\n",
+  );
+  output.assert_stderr_matches_text("Checked 1 file\n");
+}
+
+#[test]
+fn lint_svg_dir() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\ndebugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --svg-dir=frames main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-debugger]: `debugger` statement is not allowed
+ --> [WILDCARD]main.ts:1:1
+[WILDCARD]
+error[no-debugger]: `debugger` statement is not allowed
+ --> [WILDCARD]main.ts:2:1
+[WILDCARD]
+Found 2 problems in 1 file[WILDLINE]
+Checked 1 file
+",
+  );
+  let mut frames = std::fs::read_dir(temp_dir.join("frames"))
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .collect::<Vec<_>>();
+  frames.sort();
+  assert_eq!(frames.len(), 2);
+  for frame in &frames {
+    assert_eq!(frame.extension().unwrap(), "svg");
+    let svg = std::fs::read_to_string(frame).unwrap();
+    assert_contains!(svg, "(no-debugger)");
+    assert_contains!(svg, "^^^^^^^^^");
+  }
+}
+
+#[test]
+fn lint_byte_window() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  // the debugger statements are at bytes 0-9 and 10-19
+  temp_dir.join("main.ts").write("debugger;\ndebugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --compact main.ts@12-14")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "line 2, col 1");
+  assert_not_contains!(output, "line 1, col 1");
+  assert_contains!(output, "Found 1 problem");
+
+  // nothing is reported outside of the diagnostics
+  let output = context
+    .new_command()
+    .args("lint --compact main.ts@9-10")
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 1 file\n");
+
+  // the window of a path out of the current directory
+  temp_dir.join("sub").create_dir_all();
+  let output = context
+    .new_command()
+    .current_dir("sub")
+    .args("lint --compact ../main.ts@12-14")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "line 2, col 1");
+  assert_not_contains!(output, "line 1, col 1");
+  assert_contains!(output, "Found 1 problem");
+}
+
+#[test]
+fn lint_patch() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\ndebugger;\n");
+  temp_dir.join("new.ts").write("debugger;\n");
+  temp_dir.join("other.ts").write("debugger;\n");
+  temp_dir.join("changes.diff").write(concat!(
+    "--- a/main.ts\n",
+    "+++ b/main.ts\n",
+    "@@ -1 +1,2 @@\n",
+    " debugger;\n",
+    "+debugger;\n",
+    "--- /dev/null\n",
+    "+++ b/new.ts\n",
+    "@@ -0,0 +1 @@\n",
+    "+debugger;\n",
+  ));
+
+  let output = context
+    .new_command()
+    .args("lint --compact --patch=changes.diff")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "main.ts: line 2, col 1");
+  assert_not_contains!(output, "main.ts: line 1, col 1");
+  assert_contains!(output, "new.ts: line 1, col 1");
+  assert_not_contains!(output, "other.ts");
+  assert_contains!(output, "Checked 2 files");
+}
+
+#[test]
+fn lint_max_warnings() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\ndebugger;\n");
+
+  let output = context.new_command().args("lint --max-warnings=2").run();
+  output.assert_exit_code(0);
+  let output = output.combined_output();
+  assert_contains!(output, "Found 2 problems");
+  assert_not_contains!(output, "--max-warnings");
+
+  let output = context.new_command().args("lint --max-warnings=1").run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Exceeded the maximum of 1 problem set by --max-warnings."
+  );
+
+  // files that can't be linted still fail the run
+  temp_dir.join("broken.ts").write("const = ;");
+  let output = context.new_command().args("lint --max-warnings=5").run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "[WILDCARD]Error linting: [WILDCARD]broken.ts
+[WILDCARD]
+Found 2 problems in 1 file[WILDLINE]
+Checked 2 files
+",
+  );
+
+  // so do files that can't be read
+  std::fs::write(temp_dir.join("broken.ts"), [0xff, 0xfe]).unwrap();
+  let output = context.new_command().args("lint --max-warnings=5").run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "broken.ts");
+
+  // and the changes of a dry run
+  temp_dir.join("broken.ts").remove_file();
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nconsole.log(a);\n");
+  let output = context
+    .new_command()
+    .args("lint --max-warnings=5 --fix --dry-run")
+    .run();
+  output.assert_exit_code(1);
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "let a = 1;\nconsole.log(a);\n"
+  );
+}
+
+#[test]
+fn lint_require_coverage() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write(
+    r#"{ "lint": { "include": ["src/"], "exclude": ["scripts/legacy/"] } }"#,
+  );
+  temp_dir.join("src").create_dir_all();
+  temp_dir.join("scripts/legacy").create_dir_all();
+  temp_dir.join("src/main.ts").write("export const a = 1;\n");
+  temp_dir
+    .join("scripts/build.ts")
+    .write("export const b = 1;\n");
+  temp_dir
+    .join("scripts/legacy/old.js")
+    .write("export const c = 1;\n");
+
+  let output = context.new_command().args("lint --require-coverage").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(
+    output,
+    "Found 1 script file not covered by the lint include patterns:"
+  );
+  assert_contains!(output, "scripts");
+  assert_contains!(output, "build.ts");
+  assert_not_contains!(output, "old.js");
+
+  temp_dir.join("deno.json").write(
+    r#"{ "lint": { "include": ["src/", "scripts/"], "exclude": ["scripts/legacy/"] } }"#,
+  );
+  let output = context.new_command().args("lint --require-coverage").run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 2 files\n");
+}
+
+#[test]
+fn lint_blame() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("debugger;\ndebugger;\n");
+
+  // the temp dir isn't a git repository, so nobody can be blamed
+  let output = context.new_command().args("lint --blame main.ts").run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "Problems by author:");
+  assert_contains!(output, "Unknown (2 problems)");
+  assert_contains!(
+    output,
+    "main.ts:2:1 - `debugger` statement is not allowed (no-debugger)"
+  );
+}
+
+#[test]
+fn lint_compare() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("let a = 1;\ndebugger;\n");
+  let output = context
+    .new_command()
+    .args("lint --json main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  temp_dir.join("old.json").write(output.stdout());
+
+  // only resolved and unchanged problems
+  temp_dir
+    .join("main.ts")
+    .write("\nexport let a = 1;\ndebugger;\n");
+  let output = context
+    .new_command()
+    .args("lint --compare=old.json main.ts")
+    .run();
+  output.assert_exit_code(0);
+  let output = output.combined_output();
+  assert_contains!(output, "Resolved problems (1):");
+  assert_contains!(output, "main.ts no-unused-vars:");
+  assert_contains!(output, "1 resolved, 2 unchanged");
+
+  temp_dir
+    .join("main.ts")
+    .write("let a = 1;\nlet b = 2;\ndebugger;\n");
+  let output = context
+    .new_command()
+    .args("lint --compare=old.json main.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "New problems (2):");
+  assert_contains!(output, "2 new, 0 resolved, 3 unchanged");
+
+  // the problems of a file that can't be parsed are unknown
+  temp_dir.join("main.ts").write("let a = ;\n");
+  let output = context
+    .new_command()
+    .args("lint --compare=old.json main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "Error linting:");
+}
+
+#[test]
+fn lint_only_rule() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("let a = 1;\ndebugger;\n");
+
+  let output = context
+    .new_command()
+    .args("lint --only=no-debugger main.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "no-debugger");
+  assert_not_contains!(output, "prefer-const");
+  assert_contains!(output, "Found 1 problem");
+
+  let output = context.new_command().args("lint --only=no-such-rule").run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Unknown lint rule 'no-such-rule'."
+  );
+}
+
+#[test]
+fn lint_success_marker() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;");
+
+  let output = context
+    .new_command()
+    .args("lint --success-marker=.lint-ok main.ts")
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 1 file\n");
+  assert!(temp_dir.join(".lint-ok").exists());
+
+  temp_dir.join("main.ts").write("const a = 1;");
+  let output = context
+    .new_command()
+    .args("lint --success-marker=.lint-ok main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-unused-vars]: `a` is never used
+ --> [WILDCARD]main.ts:1:7
+[WILDCARD]
+Found 1 problem
+Checked 1 file
+",
+  );
+  assert!(!temp_dir.join(".lint-ok").exists());
+
+  // removed even when the config file is invalid
+  temp_dir.join(".lint-ok").write("");
+  temp_dir.join("deno.json").write("{");
+  let output = context
+    .new_command()
+    .args("lint --success-marker=.lint-ok main.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text("error: [WILDCARD]deno.json[WILDCARD]");
+  assert!(!temp_dir.join(".lint-ok").exists());
+}
+
+#[test]
+fn lint_explain() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let output = context
+    .new_command()
+    .args("lint --explain=no-debugger")
+    .run();
+  output.assert_exit_code(0);
+  let output = output.combined_output();
+  assert_contains!(output, "no-debugger");
+  assert_contains!(output, "recommended");
+
+  let output = context.new_command().args("lint --explain=no-window").run();
+  output.assert_exit_code(0);
+  let output = output.combined_output();
+  assert_contains!(output, "Example fix:");
+  assert_contains!(output, "globalThis");
+
+  let output = context
+    .new_command()
+    .args("lint --explain=no-explicit-any")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(
+    output.combined_output(),
+    "`--fix` doesn't fix the problems of this rule."
+  );
+
+  let output = context
+    .new_command()
+    .args("lint --explain=no-such-rule")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Unknown lint rule 'no-such-rule'."
+  );
+}
+
+#[test]
+fn lint_print_file_patterns() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir
+    .join("deno.json")
+    .write(r#"{ "lint": { "include": ["src/"], "exclude": ["src/gen/"] } }"#);
+
+  let output = context
+    .new_command()
+    .args("lint --print-file-patterns")
+    .split_output()
+    .run();
+  output.assert_exit_code(0);
+  output.assert_stdout_matches_text(
+    "Base: [WILDLINE]
+Include:
+  [WILDLINE]src
+Exclude:
+  [WILDLINE]gen
+Skipped folders: node_modules, vendor, .git
+",
+  );
+
+  let output = context
+    .new_command()
+    .args("lint --print-file-patterns --json")
+    .split_output()
+    .run();
+  output.assert_exit_code(0);
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  let include = json["include"][0]["value"].as_str().unwrap();
+  assert!(include.ends_with("src"));
+  assert_eq!(json["include"][0]["kind"], "path");
+  assert!(json["exclude"][0]["value"]
+    .as_str()
+    .unwrap()
+    .ends_with("gen"));
+}
+
+#[test]
+fn lint_rules_hash() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let output = context
+    .new_command()
+    .args("lint --rules-hash")
+    .split_output()
+    .run();
+  output.assert_exit_code(0);
+  let hash = output.stdout().trim().to_string();
+  assert_eq!(hash.len(), 64);
+
+  let output = context
+    .new_command()
+    .args(format!("lint --rules-hash={}", hash))
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text(format!("{}\n", hash));
+
+  let output = context.new_command().args("lint --rules-hash=abc").run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "but abc was expected");
+}
+
+#[test]
+fn lint_fail_on_regressions() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;");
+  temp_dir.join("other.ts").write("const b = 1;");
+
+  // caches main.ts as clean
+  let output = context.new_command().args("lint main.ts other.ts").run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-unused-vars]: `b` is never used
+ --> [WILDCARD]other.ts:1:7
+[WILDCARD]
+Found 1 problem
+Checked 2 files
+",
+  );
+
+  temp_dir.join("main.ts").write("const a = 1;");
+  let output = context
+    .new_command()
+    .args("lint --fail-on-regressions main.ts other.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "Found problems in 1 previously clean file:");
+  assert_contains!(output, "main.ts");
+  assert_not_contains!(output, "  other.ts");
+}
+
+#[test]
+fn lint_verbose_rule_count() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;");
+
+  let output = context.new_command().args("lint --verbose main.ts").run();
+  output.assert_exit_code(0);
+  let output = output.combined_output();
+  assert_contains!(output, "Linting with ");
+  assert_contains!(output, " rules (tags: recommended)");
+  assert_contains!(output, "Linted 1 line (19B) across 1 file in ");
+
+  let output = context.new_command().args("lint main.ts").run();
+  output.assert_exit_code(0);
+  assert_not_contains!(output.combined_output(), "Linting with");
+}
+
+#[test]
+fn lint_max_warnings_delta() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("const a = 1;");
+
+  // the first run records the baseline
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=0 main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "recorded by");
+
+  temp_dir.join("main.ts").write("const a = 1;\nconst b = 2;");
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=1 main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "recorded by");
+
+  // growing within the delta didn't raise the baseline
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=0 main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Found 2 problems, 1 more than the 1 recorded by a previous run."
+  );
+
+  // until it's explicitly recorded
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=0 --update-problem-count main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "recorded by");
+  temp_dir
+    .join("main.ts")
+    .write("const a = 1;\nconst b = 2;\nconst c = 3;\nconst d = 4;");
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=1 main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Found 4 problems, 2 more than the 2 recorded by a previous run."
+  );
+
+  // fewer problems lower the baseline
+  temp_dir.join("main.ts").write("const a = 1;");
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=0 main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "recorded by");
+  temp_dir.join("main.ts").write("const a = 1;\nconst b = 2;");
+  let output = context
+    .new_command()
+    .args("lint --max-warnings-delta=0 main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(
+    output.combined_output(),
+    "Found 2 problems, 1 more than the 1 recorded by a previous run."
+  );
+}
+
+#[test]
+fn lint_sidecar() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("const a = 1;");
+  temp_dir.join("clean.ts").write("export const b = 1;");
+
+  let output = context
+    .new_command()
+    .args("lint --sidecar main.ts clean.ts")
+    .run();
+  output.assert_exit_code(1);
+  output.assert_matches_text(
+    "error[no-unused-vars]: `a` is never used
+ --> [WILDCARD]main.ts:1:7
+[WILDCARD]
+Found 1 problem
+Checked 2 files
+",
+  );
+
+  let sidecar: serde_json::Value =
+    serde_json::from_str(&temp_dir.join("main.ts.lint.json").read_to_string())
+      .unwrap();
+  let diagnostics = sidecar.as_array().unwrap();
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0]["code"], "no-unused-vars");
+  let sidecar = temp_dir.join("clean.ts.lint.json").read_to_string();
+  assert_eq!(sidecar, "[]");
+}
+
+#[test]
+fn lint_cache_namespace() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("export const a = 1;");
+
+  // caches main.ts as clean in the "a" namespace only
+  let output = context
+    .new_command()
+    .args("lint --cache-namespace=a main.ts")
+    .run();
+  output.assert_exit_code(0);
+  output.assert_matches_text("Checked 1 file\n");
+
+  temp_dir.join("main.ts").write("const a = 1;");
+  let output = context
+    .new_command()
+    .args("lint --fail-on-regressions --cache-namespace=b main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_not_contains!(output.combined_output(), "previously clean");
+  let output = context
+    .new_command()
+    .args("lint --fail-on-regressions --cache-namespace=a main.ts")
+    .run();
+  output.assert_exit_code(1);
+  assert_contains!(output.combined_output(), "previously clean");
+}
+
+#[test]
+fn lint_enclosing_symbol() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write(
+    "const top = 1;\nexport class Foo {\n  bar() {\n    const a = 1;\n  }\n}\n",
+  );
+
+  let output = context
+    .new_command()
+    .args("lint --enclosing-symbol main.ts")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "`a` is never used (in Foo.bar)");
+  assert_contains!(output, "`top` is never used\n");
+
+  let output = context
+    .new_command()
+    .args("lint --json --enclosing-symbol main.ts")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  let diagnostics = json["diagnostics"].as_array().unwrap();
+  assert_eq!(diagnostics[0]["enclosingSymbol"], serde_json::Value::Null);
+  assert_eq!(diagnostics[1]["enclosingSymbol"], "Foo.bar");
+}
+
+#[test]
+fn lint_interactive_without_terminal() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("main.ts").write("console.log(window.value);");
+
+  let output = context
+    .new_command()
+    .args("lint --interactive main.ts")
+    .stdin_text("y\n")
+    .run();
+  output.assert_exit_code(1);
+  let output = output.combined_output();
+  assert_contains!(output, "--interactive was ignored");
+  assert_contains!(output, "no-window");
+  // nothing was fixed
+  assert_eq!(
+    temp_dir.join("main.ts").read_to_string(),
+    "console.log(window.value);"
+  );
+}
+
+#[test]
+fn lint_json_workspace() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("deno.json").write_json(&json!({
+    "workspaces": ["a", "b"]
+  }));
+  for name in ["a", "b"] {
+    temp_dir.join(name).create_dir_all();
+    temp_dir.join(name).join("deno.json").write_json(&json!({
+      "name": format!("@scope/{}", name),
+      "version": "1.0.0",
+      "exports": "./mod.ts"
+    }));
+    temp_dir
+      .join(name)
+      .join("mod.ts")
+      .write("const unused = 1;\nexport const a: number = 1;\n");
+  }
+  temp_dir.join("root.ts").write("const unused = 1;");
+
+  let output = context
+    .new_command()
+    .args("lint --json-workspace")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  let members = json["members"].as_array().unwrap();
+  assert_eq!(members.len(), 2);
+  for member in members {
+    assert_eq!(member["problemCount"], 1);
+    assert_eq!(member["diagnostics"][0]["code"], "no-unused-vars");
+  }
+  let names = members
+    .iter()
+    .map(|member| member["name"].as_str().unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(names, vec!["@scope/a", "@scope/b"]);
+  // root.ts isn't in a member
+  assert_eq!(json["diagnostics"].as_array().unwrap().len(), 1);
+  let root_filename = json["diagnostics"][0]["filename"].as_str().unwrap();
+  assert_eq!(json["fileByteLengths"][root_filename], 17);
+  assert_eq!(json["fileByteLengths"].as_object().unwrap().len(), 3);
+
+  // also picked by the environment variable
+  let output = context
+    .new_command()
+    .args("lint")
+    .env("DENO_LINT_REPORTER", "json-workspace")
+    .split_output()
+    .run();
+  output.assert_exit_code(1);
+  let json: serde_json::Value = serde_json::from_str(output.stdout()).unwrap();
+  assert_eq!(json["members"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn lint_no_tests_and_tests_only() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir().path();
+  temp_dir.join("mod.ts").write("export const a = 1;");
+  temp_dir.join("mod_test.ts").write("export const a = 1;");
+  temp_dir.join("mod.spec.ts").write("export const a = 1;");
+
+  let output = context.new_command().args("lint --no-tests").run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Checked 1 file");
+
+  let output = context.new_command().args("lint --tests-only").run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Checked 2 files");
+}
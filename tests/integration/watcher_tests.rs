@@ -305,6 +305,75 @@ async fn lint_watch_without_args_test() {
   drop(t);
 }
 
+#[tokio::test]
+async fn lint_watch_no_fail_test() {
+  let t = TempDir::new();
+  // there's nothing to lint, which errors the iterations
+  t.write("notes.txt", "");
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("lint")
+    .arg("--watch")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (_stdout_lines, mut stderr_lines) = child_lines(&mut child);
+  wait_contains("No target files found.", &mut stderr_lines).await;
+  wait_contains(
+    "Lint failed. Restarting on file change...",
+    &mut stderr_lines,
+  )
+  .await;
+  check_alive_then_kill(child);
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("lint")
+    .arg("--watch")
+    .arg("--watch-no-fail")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (_stdout_lines, mut stderr_lines) = child_lines(&mut child);
+  wait_contains("No target files found.", &mut stderr_lines).await;
+  wait_contains(
+    "Lint finished. Restarting on file change...",
+    &mut stderr_lines,
+  )
+  .await;
+  check_alive_then_kill(child);
+}
+
+#[tokio::test]
+async fn lint_inbox_test() {
+  let t = TempDir::new();
+  t.create_dir_all("incoming");
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("lint")
+    .arg("--inbox=incoming")
+    .arg("--inbox-done=delete")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (_stdout_lines, mut stderr_lines) = child_lines(&mut child);
+  wait_contains("Watching", &mut stderr_lines).await;
+
+  t.write("incoming/main.ts", "debugger;\n");
+  wait_contains("error[no-debugger]", &mut stderr_lines).await;
+  wait_contains("Checked 1 file", &mut stderr_lines).await;
+  // moved to the directory named "delete" with its result
+  assert!(!t.path().join("incoming/main.ts").exists());
+  assert_eq!(t.read_to_string("delete/main.ts"), "debugger;\n");
+  assert_contains!(
+    t.read_to_string("delete/main.ts.lint.json"),
+    "\"no-debugger\""
+  );
+  check_alive_then_kill(child);
+}
+
 #[tokio::test]
 async fn lint_all_files_on_each_change_test() {
   let t = TempDir::new();
@@ -0,0 +1,5 @@
+console.log(1);
+debugger;
+export function f() {
+  debugger; debugger;
+}
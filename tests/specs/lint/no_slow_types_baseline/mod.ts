@@ -0,0 +1,6 @@
+export function f() {
+  return 1;
+}
+export function g() {
+  return 2;
+}
@@ -0,0 +1,5 @@
+import "lodash/fp";
+import "./mod.ts";
+import "lodash/debounce";
+await import("lodash");
+export const fp = require("lodash/fp");
@@ -776,6 +776,10 @@ impl TestCommandBuilder {
     if !envs.contains_key("DENO_NO_UPDATE_CHECK") {
       envs.insert("DENO_NO_UPDATE_CHECK".to_string(), "1".to_string());
     }
+    if !envs.contains_key("DENO_LINT_REPORTER") {
+      // the lint output shouldn't depend on the CI system running the tests
+      envs.insert("DENO_LINT_REPORTER".to_string(), "pretty".to_string());
+    }
     if !envs.contains_key("JSR_URL") {
       envs.insert("JSR_URL".to_string(), jsr_registry_unset_url());
     }